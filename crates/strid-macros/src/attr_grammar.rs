@@ -2,10 +2,12 @@
 
 // Import unsynn macros and types
 use unsynn::{
-    CommaDelimitedVec, Cons, Ident, Literal, ParenthesisGroupContaining, TokenTree, keyword,
-    operator, unsynn,
+    Cons, IParse, Ident, Literal, ParenthesisGroupContaining, ToTokenIter, TokenStream, TokenTree,
+    keyword, operator, unsynn,
 };
 
+use crate::error::{Error, Errors, Result};
+
 keyword! {
     KBool = "bool";
     KOption = "Option";
@@ -25,11 +27,46 @@ unsynn! {
         /// Path-only attribute: `validator`
         Path(Ident),
     }
+}
+
+/// A comma-delimited list of attribute arguments
+pub struct AttrArgs {
+    /// The arguments
+    pub args: Vec<AttrArg>,
+}
+
+impl AttrArgs {
+    /// Parses each comma-separated argument on its own, so that a malformed argument is reported
+    /// at its own span without hiding problems with the arguments around it.
+    pub fn parse(tokens: TokenStream, errors: &mut Errors) -> Self {
+        let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        let args = tokens
+            .split(|tt| matches!(tt, TokenTree::Punct(p) if p.as_char() == ','))
+            .filter(|arg| !arg.is_empty())
+            .filter_map(|arg| errors.handle(parse_arg(arg)))
+            .collect();
+
+        Self { args }
+    }
+}
 
-    /// A comma-delimited list of attribute arguments
-    pub struct AttrArgs {
-        /// The arguments
-        pub args: CommaDelimitedVec<AttrArg>,
+fn parse_arg(tokens: &[TokenTree]) -> Result<AttrArg> {
+    let stream: TokenStream = tokens.iter().cloned().collect();
+    let mut iter = stream.to_token_iter();
+    let arg = iter
+        .parse::<AttrArg>()
+        .map_err(|_| Error::new(tokens[0].span(), "expected an argument name"))?;
+
+    match iter.next() {
+        None => Ok(arg),
+        Some(TokenTree::Punct(p)) if p.as_char() == '=' => {
+            let span = iter.next().map_or_else(|| p.span(), |tt| tt.span());
+            Err(Error::new(
+                span,
+                format!("expected a string literal value for `{}`", arg.name()),
+            ))
+        }
+        Some(tt) => Err(Error::new(tt.span(), "unexpected token in argument")),
     }
 }
 
@@ -61,7 +98,7 @@ impl AttrArg {
 }
 
 impl quote::ToTokens for AttrArg {
-    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
         use unsynn::ToTokens as _;
         match self {
             AttrArg::Path(ident) => quote::ToTokens::to_tokens(ident, tokens),
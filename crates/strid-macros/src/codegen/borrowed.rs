@@ -1,42 +1,239 @@
 use quote::{TokenStreamExt, quote};
-use unsynn::{Ident, ToTokens as UnsynnToTokens};
+use unsynn::{Ident, ToTokens as UnsynnToTokens, TokenTree};
 
-use super::{AttrList, CheckMode, Field, FieldName, Impls, StdLib, impls::ToImpl};
+use super::{
+    AttrList, Case, CheckMode, Field, FieldName, Generics, Impls, Slice, StdLib,
+    impls::{ImplFacet, ToImpl},
+};
 
 pub struct RefCodeGen<'a> {
+    pub slice: Slice,
     pub doc: &'a [proc_macro2::Literal],
     pub common_attrs: &'a [crate::grammar::Attribute],
     pub attrs: &'a AttrList,
     pub vis: Option<&'a crate::grammar::Vis>,
     pub ty: &'a crate::grammar::Type,
     pub ident: Ident,
+    pub generics: &'a Generics,
     pub field: Field,
     pub check_mode: &'a CheckMode,
+    pub const_validator: Option<&'a crate::grammar::Type>,
+    pub constraints: Option<&'a crate::grammar::Type>,
     pub owned_ty: Option<&'a Ident>,
     pub std_lib: &'a StdLib,
+    pub case: Case,
     pub impls: &'a Impls,
 }
 
 impl<'a> RefCodeGen<'a> {
+    /// The borrowed type along with its generic parameters, e.g. `IdRef<T>`.
+    pub fn ref_type(&self) -> proc_macro2::TokenStream {
+        let ty = self.ty;
+        let ty_generics = self.generics.ty_generics();
+        quote! { #ty #ty_generics }
+    }
+
+    /// The owned type along with its generic parameters, e.g. `Id<T>`, if there is one.
+    pub fn owned_type(&self) -> Option<proc_macro2::TokenStream> {
+        let owned_ty = self.owned_ty?;
+        let ty_generics = self.generics.ty_generics();
+        Some(quote! { #owned_ty #ty_generics })
+    }
+
     fn inherent(&self) -> proc_macro2::TokenStream {
-        let ty = &self.ty;
+        let ty = self.ref_type();
+        let impl_generics = self.generics.impl_generics();
+        let where_clause = self.generics.where_clause();
         let field_name = &self.field.name;
+        let unsized_ty = self.slice.unsized_ty();
+        let as_raw = self.slice.method("as_", "");
+        let as_raw_doc = format!(
+            "Provides access to the underlying value as {}.",
+            match self.slice {
+                Slice::Str => "a string slice",
+                Slice::Bytes => "a byte slice",
+                Slice::OsStr => "an [`OsStr`][std::ffi::OsStr]",
+                Slice::Path => "a [`Path`][std::path::Path]",
+                Slice::CStr => "a [`CStr`][core::ffi::CStr]",
+            },
+        );
         let inherent = self.check_inherent();
+        let facet_invariants = self
+            .impls
+            .facet
+            .has_borrowed_impl()
+            .then(|| ImplFacet::invariants_method(self.slice, self.check_mode))
+            .flatten();
+        let path_helpers = self.path_helpers();
+        let c_str_helpers = self.c_str_helpers();
 
         quote! {
             #[automatically_derived]
-            impl #ty {
+            impl #impl_generics #ty #where_clause {
                 #inherent
+                #facet_invariants
 
-                /// Provides access to the underlying value as a string slice.
+                #[doc = #as_raw_doc]
                 #[inline]
-                pub const fn as_str(&self) -> &str {
+                pub const fn #as_raw(&self) -> &#unsized_ty {
                     &self.#field_name
                 }
+
+                #path_helpers
+                #c_str_helpers
             }
         }
     }
 
+    /// Helpers that build new paths from the value, returning the raw `PathBuf` since the result
+    /// isn't necessarily valid for the braid
+    fn path_helpers(&self) -> Option<proc_macro2::TokenStream> {
+        let field_name = &self.field.name;
+        let as_path =
+            quote! { ::std::convert::AsRef::<::std::path::Path>::as_ref(&self.#field_name) };
+
+        let join = quote! {
+            /// Creates an owned [`PathBuf`][std::path::PathBuf] with `path` adjoined to this value
+            ///
+            /// See [`Path::join`][std::path::Path::join] for how the paths are combined.
+            #[inline]
+            #[must_use]
+            pub fn join(&self, path: impl ::std::convert::AsRef<::std::path::Path>) -> ::std::path::PathBuf {
+                ::std::path::Path::join(#as_path, path)
+            }
+        };
+
+        match self.slice {
+            Slice::Str | Slice::Bytes | Slice::CStr => None,
+            Slice::OsStr => Some(join),
+            Slice::Path => Some(quote! {
+                #join
+
+                /// Creates an owned [`PathBuf`][std::path::PathBuf] like this value, but with the
+                /// given file name
+                ///
+                /// See [`Path::with_file_name`][std::path::Path::with_file_name] for more details.
+                #[inline]
+                #[must_use]
+                pub fn with_file_name(&self, file_name: impl ::std::convert::AsRef<::std::ffi::OsStr>) -> ::std::path::PathBuf {
+                    ::std::path::Path::with_file_name(#as_path, file_name)
+                }
+
+                /// Creates an owned [`PathBuf`][std::path::PathBuf] like this value, but with the
+                /// given extension
+                ///
+                /// See [`Path::with_extension`][std::path::Path::with_extension] for more details.
+                #[inline]
+                #[must_use]
+                pub fn with_extension(&self, extension: impl ::std::convert::AsRef<::std::ffi::OsStr>) -> ::std::path::PathBuf {
+                    ::std::path::Path::with_extension(#as_path, extension)
+                }
+            }),
+        }
+    }
+
+    /// Conversions between the value and a raw pointer to a nul-terminated C string, where taking
+    /// the value from a pointer checks it like any other constructor would
+    fn c_str_helpers(&self) -> Option<proc_macro2::TokenStream> {
+        if self.slice != Slice::CStr {
+            return None;
+        }
+
+        let core = self.std_lib.core();
+        let alloc = self.std_lib.alloc();
+        let field_name = &self.field.name;
+        let from_c_str = self.slice.method("from_", "");
+        let from_c_str_unchecked = self.slice.method("from_", "_unchecked");
+        let ptr_safety = "The pointer must uphold the safety requirements of \
+                          [`CStr::from_ptr`][core::ffi::CStr::from_ptr], and the returned \
+                          reference must not outlive the C string it points to.";
+
+        let as_ptr = quote! {
+            /// Returns the inner pointer to this C string
+            ///
+            /// See [`CStr::as_ptr`][core::ffi::CStr::as_ptr] for how the pointer may be used.
+            #[inline]
+            #[must_use]
+            pub const fn as_ptr(&self) -> *const ::#core::ffi::c_char {
+                ::#core::ffi::CStr::as_ptr(&self.#field_name)
+            }
+        };
+
+        let checker = match &self.check_mode {
+            CheckMode::None => {
+                let doc_comment = format!(
+                    "Wraps a raw C string as a strongly-typed {}\n\n# Safety\n\n{}",
+                    self.ident, ptr_safety,
+                );
+
+                return Some(quote! {
+                    #as_ptr
+
+                    #[allow(unsafe_code)]
+                    #[inline]
+                    #[doc = #doc_comment]
+                    pub const unsafe fn from_ptr<'p>(ptr: *const ::#core::ffi::c_char) -> &'p Self {
+                        Self::#from_c_str(unsafe { ::#core::ffi::CStr::from_ptr(ptr) })
+                    }
+                });
+            }
+            CheckMode::Validate(checker) | CheckMode::Normalize(checker) => checker,
+        };
+
+        let is_normalized = matches!(self.check_mode, CheckMode::Normalize(_));
+        let checker_tokens = checker.to_token_stream();
+        let validator = self.slice.validator(checker);
+        let (from_ptr_doc, from_ptr_ty) = if is_normalized {
+            (
+                format!(
+                    "Wraps a raw C string as a strongly-typed {} if it conforms to [`{}`], \
+                     normalizing if necessary\n\n# Safety\n\n{}",
+                    self.ident, checker_tokens, ptr_safety,
+                ),
+                quote! { ::#alloc::borrow::Cow<'p, Self> },
+            )
+        } else {
+            (
+                format!(
+                    "Wraps a raw C string as a strongly-typed {} if it conforms to [`{}`]\n\n# \
+                     Safety\n\n{}",
+                    self.ident, checker_tokens, ptr_safety,
+                ),
+                quote! { &'p Self },
+            )
+        };
+        let from_ptr_unchecked_doc = format!(
+            "Wraps a raw C string as a strongly-typed `{}` without validating\n\n# Safety\n\n{} \
+             The C string must also conform to [`{}`]{}.",
+            self.ident,
+            ptr_safety,
+            checker_tokens,
+            if is_normalized {
+                " and already be in normalized form"
+            } else {
+                ""
+            },
+        );
+
+        Some(quote! {
+            #as_ptr
+
+            #[allow(unsafe_code)]
+            #[inline]
+            #[doc = #from_ptr_doc]
+            pub unsafe fn from_ptr<'p>(ptr: *const ::#core::ffi::c_char) -> ::#core::result::Result<#from_ptr_ty, #validator::Error> {
+                Self::#from_c_str(unsafe { ::#core::ffi::CStr::from_ptr(ptr) })
+            }
+
+            #[allow(unsafe_code)]
+            #[inline]
+            #[doc = #from_ptr_unchecked_doc]
+            pub const unsafe fn from_ptr_unchecked<'p>(ptr: *const ::#core::ffi::c_char) -> &'p Self {
+                unsafe { Self::#from_c_str_unchecked(::#core::ffi::CStr::from_ptr(ptr)) }
+            }
+        })
+    }
+
     fn check_inherent(&self) -> proc_macro2::TokenStream {
         match self.check_mode {
             CheckMode::None => self.infallible_inherent(),
@@ -47,9 +244,10 @@ impl<'a> RefCodeGen<'a> {
 
     fn pointer_reinterpret_safety_comment(&self, is_mut: bool) -> proc_macro2::TokenStream {
         let doc = format!(
-            "SAFETY: `{ty}` is `#[repr(transparent)]` around a single `str` field, so a `*{ptr} \
-             str` can be safely reinterpreted as a `*{ptr} {ty}`",
+            "SAFETY: `{ty}` is `#[repr(transparent)]` and its only non-zero-sized field is \
+             `{raw}`, so a `*{ptr} {raw}` can be safely reinterpreted as a `*{ptr} {ty}`",
             ty = self.ident,
+            raw = self.slice.unsized_name(),
             ptr = if is_mut { "mut" } else { "const" },
         );
 
@@ -76,51 +274,78 @@ impl<'a> RefCodeGen<'a> {
         }
     }
 
-    fn infallible_inherent(&self) -> proc_macro2::TokenStream {
-        let ty = &self.ty;
+    /// `into_owned`, which takes the allocation of a boxed value, along with the owned type's
+    /// constructor that trusts it to already be valid.
+    fn owned_conversion(
+        &self,
+        is_checked: bool,
+        is_normalized: bool,
+    ) -> Option<proc_macro2::TokenStream> {
+        let owned_ty = self.owned_type()?;
+        let ty = self.ref_type();
         let core = self.std_lib.core();
         let alloc = self.std_lib.alloc();
+        let unsized_ty = self.slice.unsized_ty();
+        let into_owned_doc = format!(
+            "Converts a [`Box<{}>`] into a [`{}`] without copying or allocating",
+            self.ident,
+            self.owned_ty.map(ToString::to_string).unwrap_or_default(),
+        );
+        let box_pointer_reinterpret_safety_comment = self.pointer_reinterpret_safety_comment(true);
+
+        let create = if is_checked {
+            let unchecked_safety_comment = Self::unchecked_safety_comment(is_normalized);
+            quote! {
+                let s = ::#core::convert::From::from(boxed);
+                #unchecked_safety_comment
+                unsafe { <#owned_ty>::new_unchecked(s) }
+            }
+        } else {
+            quote! { <#owned_ty>::new(::#core::convert::From::from(boxed)) }
+        };
+
+        Some(quote! {
+            #[allow(unsafe_code)]
+            #[inline]
+            #[doc = #into_owned_doc]
+            pub fn into_owned(self: ::#alloc::boxed::Box<#ty>) -> #owned_ty {
+                #box_pointer_reinterpret_safety_comment
+                let raw = ::#alloc::boxed::Box::into_raw(self);
+                let boxed = unsafe { ::#alloc::boxed::Box::from_raw(raw as *mut #unsized_ty) };
+                #create
+            }
+        })
+    }
+
+    fn infallible_inherent(&self) -> proc_macro2::TokenStream {
+        let unsized_ty = self.slice.unsized_ty();
+        let static_ty = self.slice.static_ty();
+        let from_static_raw = self.slice.static_raw();
+        let from_raw = self.slice.method("from_", "");
 
         let doc_comment = format!(
-            "Transparently reinterprets the string slice as a strongly-typed {}",
+            "Transparently reinterprets the {} as a strongly-typed {}",
+            self.slice.description(),
             self.ident
         );
 
         let static_doc_comment = format!(
-            "Transparently reinterprets the static string slice as a strongly-typed {}",
+            "Transparently reinterprets the static {} as a strongly-typed {}",
+            self.slice.static_description(),
             self.ident
         );
 
         let pointer_reinterpret_safety_comment = self.pointer_reinterpret_safety_comment(false);
-
-        let into_owned = self.owned_ty.map(|owned_ty| {
-            let into_owned_doc = format!(
-                "Converts a [`Box<{}>`] into a [`{}`] without copying or allocating",
-                self.ident, owned_ty,
-            );
-
-            let box_pointer_reinterpret_safety_comment =
-                self.pointer_reinterpret_safety_comment(true);
-
-            quote! {
-                #[allow(unsafe_code)]
-                #[inline]
-                #[doc = #into_owned_doc]
-                pub fn into_owned(self: ::#alloc::boxed::Box<#ty>) -> #owned_ty {
-                    #box_pointer_reinterpret_safety_comment
-                    let raw = ::#alloc::boxed::Box::into_raw(self);
-                    let boxed = unsafe { ::#alloc::boxed::Box::from_raw(raw as *mut str) };
-                    #owned_ty::new(::#core::convert::From::from(boxed))
-                }
-            }
-        });
+        let into_owned = self.owned_conversion(false, false);
+        // `Path::new` and `OsStr::new` aren't `const`
+        let constness = self.slice.has_const_static().then(|| quote! { const });
 
         quote! {
             #[allow(unsafe_code)]
             #[inline]
             #[doc = #doc_comment]
-            pub const fn from_str(raw: &str) -> &Self {
-                let ptr: *const str = raw;
+            pub const fn #from_raw(raw: &#unsized_ty) -> &Self {
+                let ptr: *const #unsized_ty = raw;
                 #pointer_reinterpret_safety_comment
                 unsafe {
                     &*(ptr as *const Self)
@@ -130,8 +355,8 @@ impl<'a> RefCodeGen<'a> {
             #[inline]
             #[doc = #static_doc_comment]
             #[track_caller]
-            pub const fn from_static(raw: &'static str) -> &'static Self {
-                Self::from_str(raw)
+            pub #constness fn from_static(raw: &'static #static_ty) -> &'static Self {
+                Self::#from_raw(#from_static_raw)
             }
 
             #into_owned
@@ -139,185 +364,167 @@ impl<'a> RefCodeGen<'a> {
     }
 
     fn fallible_inherent(&self, validator: &crate::grammar::Type) -> proc_macro2::TokenStream {
+        let description = self.slice.description();
         let doc_comment = format!(
-            "Transparently reinterprets the string slice as a strongly-typed {} if it conforms to \
-             [`{}`]",
+            "Transparently reinterprets the {} as a strongly-typed {} if it conforms to [`{}`]",
+            description,
             self.ident,
             validator.to_token_stream(),
         );
 
         let static_doc_comment = format!(
-            "Transparently reinterprets the static string slice as a strongly-typed {} if it \
-             conforms to [`{}`]",
+            "Transparently reinterprets the static {} as a strongly-typed {} if it conforms to \
+             [`{}`]",
+            self.slice.static_description(),
             self.ident,
             validator.to_token_stream(),
         );
 
         let doc_comment_unsafe = format!(
-            "Transparently reinterprets the string slice as a strongly-typed {} without validating",
-            self.ident,
+            "Transparently reinterprets the {} as a strongly-typed {} without validating",
+            description, self.ident,
         );
 
-        let ty = &self.ty;
+        let ty = self.ref_type();
         let core = self.std_lib.core();
-        let alloc = self.std_lib.alloc();
+        let unsized_ty = self.slice.unsized_ty();
+        let from_static_raw = self.slice.static_raw();
+        let from_raw = self.slice.method("from_", "");
+        let from_raw_unchecked = self.slice.method("from_", "_unchecked");
         let unchecked_safety_comment = Self::unchecked_safety_comment(false);
         let pointer_reinterpret_safety_comment = self.pointer_reinterpret_safety_comment(false);
-        let into_owned = self.owned_ty.map(|owned_ty| {
-            let into_owned_doc = format!(
-                "Converts a [`Box<{}>`] into a [`{}`] without copying or allocating",
-                self.ident, owned_ty,
-            );
-
-            let box_pointer_reinterpret_safety_comment =
-                self.pointer_reinterpret_safety_comment(true);
+        let into_owned = self.owned_conversion(true, false);
 
-            quote! {
-                #[allow(unsafe_code)]
-                #[inline]
-                #[doc = #into_owned_doc]
-                pub fn into_owned(self: ::#alloc::boxed::Box<#ty>) -> #owned_ty {
-                    #box_pointer_reinterpret_safety_comment
-                    let raw = ::#alloc::boxed::Box::into_raw(self);
-                    let boxed = unsafe { ::#alloc::boxed::Box::from_raw(raw as *mut str) };
-                    let s = ::#core::convert::From::from(boxed);
-                    #unchecked_safety_comment
-                    unsafe { #owned_ty::new_unchecked(s) }
-                }
-            }
-        });
-
-        let validator = crate::as_validator(validator);
+        let validator = self.slice.validator(validator);
+        let from_static = self.static_constructor(
+            &static_doc_comment,
+            quote! { Self::#from_raw(#from_static_raw).expect(concat!("invalid ", stringify!(#ty))) },
+            false,
+        );
 
         quote! {
             #[allow(unsafe_code)]
             #[inline]
             #[doc = #doc_comment]
-            pub fn from_str(raw: &str) -> ::#core::result::Result<&Self, #validator::Error> {
+            pub fn #from_raw(raw: &#unsized_ty) -> ::#core::result::Result<&Self, #validator::Error> {
                 #validator::validate(raw)?;
                 #unchecked_safety_comment
-                ::#core::result::Result::Ok(unsafe { Self::from_str_unchecked(raw) })
+                ::#core::result::Result::Ok(unsafe { Self::#from_raw_unchecked(raw) })
             }
 
             #[allow(unsafe_code)]
             #[inline]
             #[doc = #doc_comment_unsafe]
-            pub const unsafe fn from_str_unchecked(raw: &str) -> &Self {
+            pub const unsafe fn #from_raw_unchecked(raw: &#unsized_ty) -> &Self {
                 #pointer_reinterpret_safety_comment
-                &*(raw as *const str as *const Self)
+                &*(raw as *const #unsized_ty as *const Self)
             }
 
-            #[inline]
-            #[doc = #static_doc_comment]
-            #[doc = ""]
-            #[doc = "# Panics"]
-            #[doc = ""]
-            #[doc = "This function will panic if the provided raw string is not valid."]
-            #[track_caller]
-            pub fn from_static(raw: &'static str) -> &'static Self {
-                Self::from_str(raw).expect(concat!("invalid ", stringify!(#ty)))
-            }
+            #from_static
 
             #into_owned
         }
     }
 
     fn normalized_inherent(&self, normalizer: &crate::grammar::Type) -> proc_macro2::TokenStream {
+        let description = self.slice.description();
         let doc_comment = format!(
-            "Transparently reinterprets the string slice as a strongly-typed {} if it conforms to \
-             [`{}`], normalizing if necessary",
+            "Transparently reinterprets the {} as a strongly-typed {} if it conforms to [`{}`], \
+             normalizing if necessary",
+            description,
             self.ident,
             normalizer.to_token_stream(),
         );
 
         let static_doc_comment = format!(
-            "Transparently reinterprets a static string slice as a strongly-typed {} if it \
-             conforms to [`{}`], normalizing if necessary",
+            "Transparently reinterprets a static {} as a strongly-typed {} if it conforms to \
+             [`{}`], normalizing if necessary",
+            self.slice.static_description(),
             self.ident,
             normalizer.to_token_stream(),
         );
 
         let doc_comment_norm = format!(
-            "Transparently reinterprets the string slice as a strongly-typed `{}` if it conforms \
-             to [`{}`], producing an error if normalization is necessary",
+            "Transparently reinterprets the {} as a strongly-typed `{}` if it conforms to [`{}`], \
+             producing an error if normalization is necessary",
+            description,
             self.ident,
             normalizer.to_token_stream(),
         );
 
         let doc_comment_unsafe = format!(
-            "Transparently reinterprets the string slice as a strongly-typed `{}` without \
-             validating\n\n# Safety\n\nCalls to this function must ensure that the value being \
-             passed conforms to [`{}`] and is already in normalized form. Failure to do this may \
-             result in undefined behavior if other code relies on this invariant.",
+            "Transparently reinterprets the {} as a strongly-typed `{}` without validating\n\n# \
+             Safety\n\nCalls to this function must ensure that the value being passed conforms to \
+             [`{}`] and is already in normalized form. Failure to do this may result in undefined \
+             behavior if other code relies on this invariant.",
+            description,
             self.ident,
             normalizer.to_token_stream(),
         );
 
         let doc_comment_cow_unsafe = format!(
-            "Transparently reinterprets the [`Cow<str>`][std::borrow::Cow] as a strongly-typed \
+            "Transparently reinterprets the [`Cow<{}>`][std::borrow::Cow] as a strongly-typed \
              [`Cow`][std::borrow::Cow]`<{}>` without validating\n\n# Safety\n\nCalls to this \
              function must ensure that the value being passed conforms to [`{}`] and is already \
              in normalized form. Failure to do this may result in undefined behavior if other \
              code relies on this invariant.",
+            self.slice.unsized_name(),
             self.ident,
             normalizer.to_token_stream(),
         );
 
-        let ty = &self.ty;
+        let ty = self.ref_type();
         let core = self.std_lib.core();
         let alloc = self.std_lib.alloc();
+        let unsized_ty = self.slice.unsized_ty();
+        let from_static_raw = self.slice.static_raw();
+        let from_raw = self.slice.method("from_", "");
+        let from_normalized_raw = self.slice.method("from_normalized_", "");
+        let from_raw_unchecked = self.slice.method("from_", "_unchecked");
+        let from_cow_raw_unchecked = self.slice.method("from_cow_", "_unchecked");
         let unchecked_safety_comment = Self::unchecked_safety_comment(true);
         let pointer_reinterpret_safety_comment = self.pointer_reinterpret_safety_comment(false);
 
-        let validator = crate::as_validator(normalizer);
-        let normalizer = crate::as_normalizer(normalizer);
-
-        let into_owned = self.owned_ty.map(|owned_ty| {
-            let into_owned_doc = format!(
-                "Converts a [`Box<{}>`] into a [`{}`] without copying or allocating",
-                self.ident,
-                owned_ty,
-            );
+        let validator = self.slice.validator(normalizer);
+        let normalizer = self.slice.normalizer(normalizer);
+        let from_static = self.static_constructor(
+            &static_doc_comment,
+            quote! {
+                Self::#from_normalized_raw(#from_static_raw).expect(concat!("non-normalized ", stringify!(#ty)))
+            },
+            true,
+        );
 
-            let box_pointer_reinterpret_safety_comment = self.pointer_reinterpret_safety_comment(true);
+        let into_owned = self.owned_type().map(|owned_ty| {
+            let into_owned = self.owned_conversion(true, true);
 
             quote! {
                 #[allow(unsafe_code)]
                 #[inline]
                 #[doc = #doc_comment]
-                pub fn from_str(raw: &str) -> ::#core::result::Result<::#alloc::borrow::Cow<Self>, #validator::Error> {
+                pub fn #from_raw(raw: &#unsized_ty) -> ::#core::result::Result<::#alloc::borrow::Cow<Self>, #validator::Error> {
                     let cow = #normalizer::normalize(raw)?;
                     #unchecked_safety_comment
-                    ::#core::result::Result::Ok(unsafe { Self::from_cow_str_unchecked(cow) })
+                    ::#core::result::Result::Ok(unsafe { Self::#from_cow_raw_unchecked(cow) })
                 }
 
                 #[allow(unsafe_code)]
                 #[inline]
                 #[doc = #doc_comment_cow_unsafe]
-                unsafe fn from_cow_str_unchecked(cow: ::#alloc::borrow::Cow<str>) -> ::#alloc::borrow::Cow<Self> {
+                unsafe fn #from_cow_raw_unchecked(cow: ::#alloc::borrow::Cow<#unsized_ty>) -> ::#alloc::borrow::Cow<Self> {
                     match cow {
                         ::#alloc::borrow::Cow::Borrowed(raw) => {
-                            let value = unsafe { Self::from_str_unchecked(raw) };
+                            let value = unsafe { Self::#from_raw_unchecked(raw) };
                             ::#alloc::borrow::Cow::Borrowed(value)
                         }
                         ::#alloc::borrow::Cow::Owned(normalized) => {
-                            let value = unsafe { #owned_ty::new_unchecked(::#core::convert::From::from(normalized)) };
+                            let value = unsafe { <#owned_ty>::new_unchecked(::#core::convert::From::from(normalized)) };
                             ::#alloc::borrow::Cow::Owned(value)
                         }
                     }
                 }
 
-                #[allow(unsafe_code)]
-                #[inline]
-                #[doc = #into_owned_doc]
-                pub fn into_owned(self: ::#alloc::boxed::Box<#ty>) -> #owned_ty {
-                    #box_pointer_reinterpret_safety_comment
-                    let raw = ::#alloc::boxed::Box::into_raw(self);
-                    let boxed = unsafe { ::#alloc::boxed::Box::from_raw(raw as *mut str) };
-                    let s = ::#core::convert::From::from(boxed);
-                    #unchecked_safety_comment
-                    unsafe { #owned_ty::new_unchecked(s) }
-                }
+                #into_owned
             }
         });
 
@@ -325,51 +532,114 @@ impl<'a> RefCodeGen<'a> {
             #[allow(unsafe_code)]
             #[inline]
             #[doc = #doc_comment_norm]
-            pub fn from_normalized_str(raw: &str) -> ::#core::result::Result<&Self, #validator::Error> {
+            pub fn #from_normalized_raw(raw: &#unsized_ty) -> ::#core::result::Result<&Self, #validator::Error> {
                 #validator::validate(raw)?;
                 #unchecked_safety_comment
-                ::#core::result::Result::Ok(unsafe { Self::from_str_unchecked(raw) })
+                ::#core::result::Result::Ok(unsafe { Self::#from_raw_unchecked(raw) })
             }
 
             #[allow(unsafe_code)]
             #[inline]
             #[doc = #doc_comment_unsafe]
-            pub const unsafe fn from_str_unchecked(raw: &str) -> &Self {
+            pub const unsafe fn #from_raw_unchecked(raw: &#unsized_ty) -> &Self {
                 #pointer_reinterpret_safety_comment
-                &*(raw as *const str as *const Self)
+                &*(raw as *const #unsized_ty as *const Self)
             }
 
+            #from_static
+
+            #into_owned
+        }
+    }
+
+    /// Generates `from_static` for checked braids, which is a `const fn` when a
+    /// `const_validator` is available and otherwise falls back to the runtime `fallback` check.
+    fn static_constructor(
+        &self,
+        static_doc_comment: &str,
+        fallback: proc_macro2::TokenStream,
+        is_normalized: bool,
+    ) -> proc_macro2::TokenStream {
+        let ty = &self.ty;
+        let static_ty = self.slice.static_ty();
+        let from_raw_unchecked = self.slice.method("from_", "_unchecked");
+        let (panic_doc, panic_msg) = if is_normalized {
+            (
+                self.slice.static_panic_doc("not normalized"),
+                "non-normalized ",
+            )
+        } else {
+            (self.slice.static_panic_doc("not valid"), "invalid ")
+        };
+
+        let Some(const_validator) = self.const_validator else {
+            return quote! {
+                #[inline]
+                #[doc = #static_doc_comment]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = #panic_doc]
+                #[track_caller]
+                pub fn from_static(raw: &'static #static_ty) -> &'static Self {
+                    #fallback
+                }
+            };
+        };
+
+        let const_doc = format!(
+            "Checked by [`{}`], so in a `const` context, such as [`strid::lit!`], an invalid \
+             value is reported when compiling instead.",
+            const_validator.to_token_stream(),
+        );
+        let unchecked_safety_comment = Self::unchecked_safety_comment(is_normalized);
+
+        quote! {
+            #[allow(unsafe_code)]
             #[inline]
             #[doc = #static_doc_comment]
             #[doc = ""]
             #[doc = "# Panics"]
             #[doc = ""]
-            #[doc = "This function will panic if the provided raw string is not normalized."]
+            #[doc = #panic_doc]
+            #[doc = ""]
+            #[doc = #const_doc]
             #[track_caller]
-            pub fn from_static(raw: &'static str) -> &'static Self {
-                Self::from_normalized_str(raw).expect(concat!("non-normalized ", stringify!(#ty)))
+            pub const fn from_static(raw: &'static #static_ty) -> &'static Self {
+                if !#const_validator(raw) {
+                    panic!(concat!(#panic_msg, stringify!(#ty)));
+                }
+                #unchecked_safety_comment
+                unsafe { Self::#from_raw_unchecked(raw) }
             }
-
-            #into_owned
         }
     }
 
     fn comparison(&self) -> Option<proc_macro2::TokenStream> {
-        self.owned_ty.map(|owned_ty| {
-            let ty = &self.ty;
+        let ty_generics = self.generics.ty_generics();
+        self.owned_ty.map(|owned_ident| {
+            let ty = self.ref_type();
+            let owned_ty = quote! { #owned_ident #ty_generics };
             let core = self.std_lib.core();
             let alloc = self.std_lib.alloc();
-
-            let create = match &self.field.name {
-                FieldName::Unnamed => quote! { #owned_ty(self.0.into()) },
-                FieldName::Named(field_name) => {
-                    quote! { #owned_ty { #field_name: self.#field_name.into() } }
-                }
-            };
+            let impl_generics = self.generics.impl_generics();
+            let where_clause = self.generics.where_clause();
+
+            let field_name = &self.field.name;
+            let turbofish = self.generics.turbofish();
+            let as_raw = self.slice.method("as_", "");
+            let eq = self
+                .case
+                .eq(quote! { self.#as_raw() }, quote! { other.#as_raw() });
+            let create = self.field.for_owned().construct(
+                quote! { #owned_ident #turbofish },
+                self.slice
+                    .to_field(core, alloc, quote! { &self.#field_name }),
+            );
 
             quote! {
                 #[automatically_derived]
-                impl ::#alloc::borrow::ToOwned for #ty {
+                impl #impl_generics ::#alloc::borrow::ToOwned for #ty #where_clause {
                     type Owned = #owned_ty;
 
                     #[inline]
@@ -379,34 +649,34 @@ impl<'a> RefCodeGen<'a> {
                 }
 
                 #[automatically_derived]
-                impl ::#core::cmp::PartialEq<#ty> for #owned_ty {
+                impl #impl_generics ::#core::cmp::PartialEq<#ty> for #owned_ty #where_clause {
                     #[inline]
                     fn eq(&self, other: &#ty) -> bool {
-                        self.as_str() == other.as_str()
+                        #eq
                     }
                 }
 
                 #[automatically_derived]
-                impl ::#core::cmp::PartialEq<#owned_ty> for #ty {
+                impl #impl_generics ::#core::cmp::PartialEq<#owned_ty> for #ty #where_clause {
                     #[inline]
                     fn eq(&self, other: &#owned_ty) -> bool {
-                        self.as_str() == other.as_str()
+                        #eq
                     }
                 }
 
                 #[automatically_derived]
-                impl ::#core::cmp::PartialEq<&'_ #ty> for #owned_ty {
+                impl #impl_generics ::#core::cmp::PartialEq<&'_ #ty> for #owned_ty #where_clause {
                     #[inline]
                     fn eq(&self, other: &&#ty) -> bool {
-                        self.as_str() == other.as_str()
+                        #eq
                     }
                 }
 
                 #[automatically_derived]
-                impl ::#core::cmp::PartialEq<#owned_ty> for &'_ #ty {
+                impl #impl_generics ::#core::cmp::PartialEq<#owned_ty> for &'_ #ty #where_clause {
                     #[inline]
                     fn eq(&self, other: &#owned_ty) -> bool {
-                        self.as_str() == other.as_str()
+                        #eq
                     }
                 }
             }
@@ -414,72 +684,88 @@ impl<'a> RefCodeGen<'a> {
     }
 
     fn conversion(&self) -> proc_macro2::TokenStream {
-        let ty = &self.ty;
+        let ty = self.ref_type();
+        let impl_generics = self.generics.impl_generics();
+        let impl_generics_a = self.generics.impl_generics_with(quote!('a));
+        let impl_generics_ab = self.generics.impl_generics_with(quote!('a, 'b: 'a));
+        let where_clause = self.generics.where_clause();
         let field_name = &self.field.name;
         let core = self.std_lib.core();
         let alloc = self.std_lib.alloc();
+        let unsized_ty = self.slice.unsized_ty();
+        let as_raw = self.slice.method("as_", "");
+        let from_raw = self.slice.method("from_", "");
         let pointer_reinterpret_safety_comment = self.pointer_reinterpret_safety_comment(false);
 
-        let from_str = match &self.check_mode {
+        let borrow_raw = self.case.borrow_raw(
+            core,
+            self.generics,
+            &ty,
+            &unsized_ty,
+            quote! { &self.#field_name },
+        );
+
+        let from_raw_conversion = match &self.check_mode {
             CheckMode::None => quote! {
                 #[automatically_derived]
-                impl<'a> ::#core::convert::From<&'a str> for &'a #ty {
+                impl #impl_generics_a ::#core::convert::From<&'a #unsized_ty> for &'a #ty #where_clause {
                     #[inline]
-                    fn from(s: &'a str) -> &'a #ty {
-                        #ty::from_str(s)
+                    fn from(s: &'a #unsized_ty) -> &'a #ty {
+                        <#ty>::#from_raw(s)
                     }
                 }
 
-                #[automatically_derived]
-                impl ::#core::borrow::Borrow<str> for #ty {
-                    #[inline]
-                    fn borrow(&self) -> &str {
-                        &self.#field_name
-                    }
-                }
+                #borrow_raw
             },
             CheckMode::Validate(validator) => {
-                let validator = crate::as_validator(validator);
+                let validator = self.slice.validator(validator);
                 quote! {
                     #[automatically_derived]
-                    impl<'a> ::#core::convert::TryFrom<&'a str> for &'a #ty {
+                    impl #impl_generics_a ::#core::convert::TryFrom<&'a #unsized_ty> for &'a #ty #where_clause {
                         type Error = #validator::Error;
 
                         #[inline]
-                        fn try_from(s: &'a str) -> ::#core::result::Result<&'a #ty, Self::Error> {
-                            #ty::from_str(s)
+                        fn try_from(s: &'a #unsized_ty) -> ::#core::result::Result<&'a #ty, Self::Error> {
+                            <#ty>::#from_raw(s)
                         }
                     }
 
-                    #[automatically_derived]
-                    impl ::#core::borrow::Borrow<str> for #ty {
-                        #[inline]
-                        fn borrow(&self) -> &str {
-                            &self.#field_name
-                        }
-                    }
+                    #borrow_raw
                 }
             }
             CheckMode::Normalize(normalizer) => {
-                let validator = crate::as_validator(normalizer);
+                let validator = self.slice.validator(normalizer);
+                let from_normalized_raw = self.slice.method("from_normalized_", "");
                 quote! {
                     #[automatically_derived]
-                    impl<'a> ::#core::convert::TryFrom<&'a str> for &'a #ty {
+                    impl #impl_generics_a ::#core::convert::TryFrom<&'a #unsized_ty> for &'a #ty #where_clause {
                         type Error = #validator::Error;
 
                         #[inline]
-                        fn try_from(s: &'a str) -> ::#core::result::Result<&'a #ty, Self::Error> {
-                            #ty::from_normalized_str(s)
+                        fn try_from(s: &'a #unsized_ty) -> ::#core::result::Result<&'a #ty, Self::Error> {
+                            <#ty>::#from_normalized_raw(s)
                         }
                     }
                 }
             }
         };
 
+        let as_ref_raw = self.slice.as_ref_tys().into_iter().map(|target| {
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics ::#core::convert::AsRef<#target> for #ty #where_clause {
+                    #[inline]
+                    fn as_ref(&self) -> &#target {
+                        ::#core::convert::AsRef::<#target>::as_ref(&self.#field_name)
+                    }
+                }
+            }
+        });
+
         let alloc_from = self.owned_ty.is_some().then(|| {
             quote!{
                 #[automatically_derived]
-                impl<'a> ::#core::convert::From<&'a #ty> for ::#alloc::borrow::Cow<'a, #ty> {
+                impl #impl_generics_a ::#core::convert::From<&'a #ty> for ::#alloc::borrow::Cow<'a, #ty> #where_clause {
                     #[inline]
                     fn from(r: &'a #ty) -> Self {
                         ::#alloc::borrow::Cow::Borrowed(r)
@@ -488,7 +774,7 @@ impl<'a> RefCodeGen<'a> {
 
 
                 #[automatically_derived]
-                impl<'a, 'b: 'a> ::#core::convert::From<&'a ::#alloc::borrow::Cow<'b, #ty>> for &'a #ty {
+                impl #impl_generics_ab ::#core::convert::From<&'a ::#alloc::borrow::Cow<'b, #ty>> for &'a #ty #where_clause {
                     #[inline]
                     fn from(r: &'a ::#alloc::borrow::Cow<'b, #ty>) -> &'a #ty {
                         ::#core::borrow::Borrow::borrow(r)
@@ -496,23 +782,23 @@ impl<'a> RefCodeGen<'a> {
                 }
 
                 #[automatically_derived]
-                impl ::#core::convert::From<&'_ #ty> for ::#alloc::rc::Rc<#ty> {
+                impl #impl_generics ::#core::convert::From<&'_ #ty> for ::#alloc::rc::Rc<#ty> #where_clause {
                     #[allow(unsafe_code)]
                     #[inline]
                     fn from(r: &'_ #ty) -> Self {
                         #pointer_reinterpret_safety_comment
-                        let rc = ::#alloc::rc::Rc::<str>::from(r.as_str());
+                        let rc = ::#alloc::rc::Rc::<#unsized_ty>::from(r.#as_raw());
                         unsafe { ::#alloc::rc::Rc::from_raw(::#alloc::rc::Rc::into_raw(rc) as *const #ty) }
                     }
                 }
 
                 #[automatically_derived]
-                impl ::#core::convert::From<&'_ #ty> for ::#alloc::sync::Arc<#ty> {
+                impl #impl_generics ::#core::convert::From<&'_ #ty> for ::#alloc::sync::Arc<#ty> #where_clause {
                     #[allow(unsafe_code)]
                     #[inline]
                     fn from(r: &'_ #ty) -> Self {
                         #pointer_reinterpret_safety_comment
-                        let arc = ::#alloc::sync::Arc::<str>::from(r.as_str());
+                        let arc = ::#alloc::sync::Arc::<#unsized_ty>::from(r.#as_raw());
                         unsafe { ::#alloc::sync::Arc::from_raw(::#alloc::sync::Arc::into_raw(arc) as *const #ty) }
                     }
                 }
@@ -520,17 +806,46 @@ impl<'a> RefCodeGen<'a> {
         });
 
         quote! {
-            #from_str
+            #from_raw_conversion
+
+            #(#as_ref_raw)*
+
+            #alloc_from
+        }
+    }
+
+    /// Manual equality and hashing impls for generic braids, which only look at the string, and
+    /// for braids that ignore case.
+    fn equality(&self) -> proc_macro2::TokenStream {
+        let ty = self.ref_type();
+        let field_name = &self.field.name;
+        let core = self.std_lib.core();
+        let impl_generics = self.generics.impl_generics();
+        let where_clause = self.generics.where_clause();
+        let hash = self.case.hash(core, quote! { &self.#field_name });
+        let eq = self
+            .case
+            .eq(quote! { &self.#field_name }, quote! { &other.#field_name });
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::#core::hash::Hash for #ty #where_clause {
+                #[inline]
+                fn hash<H: ::#core::hash::Hasher>(&self, state: &mut H) {
+                    #hash
+                }
+            }
 
             #[automatically_derived]
-            impl ::#core::convert::AsRef<str> for #ty {
+            impl #impl_generics ::#core::cmp::PartialEq for #ty #where_clause {
                 #[inline]
-                fn as_ref(&self) -> &str {
-                    &self.#field_name
+                fn eq(&self, other: &Self) -> bool {
+                    #eq
                 }
             }
 
-            #alloc_from
+            #[automatically_derived]
+            impl #impl_generics ::#core::cmp::Eq for #ty #where_clause {}
         }
     }
 
@@ -541,58 +856,101 @@ impl<'a> RefCodeGen<'a> {
         let debug = self.impls.debug.to_borrowed_impl(self);
         let display = self.impls.display.to_borrowed_impl(self);
         let ord = self.impls.ord.to_borrowed_impl(self);
+        let facet = self.impls.facet.to_borrowed_impl(self);
         let serde = self.impls.serde.to_borrowed_impl(self);
         let rusqlite = self.impls.rusqlite.to_borrowed_impl(self);
+        let diesel = self.impls.diesel.to_borrowed_impl(self);
+        let postgres = self.impls.postgres.to_borrowed_impl(self);
         let sailfish = self.impls.sailfish.to_borrowed_impl(self);
-
-        let ref_doc: proc_macro2::TokenStream =
-            self.doc.iter().map(|d| quote! { #[doc = #d] }).collect();
-        let ref_attrs: proc_macro2::TokenStream =
-            self.attrs.iter().map(|a| quote! {#[#a]}).collect();
+        let sqlx = self.impls.sqlx.to_borrowed_impl(self);
+        let schemars = self.impls.schemars.to_borrowed_impl(self);
+        let utoipa = self.impls.utoipa.to_borrowed_impl(self);
+        let ts_rs = self.impls.ts_rs.to_borrowed_impl(self);
+        let arbitrary = self.impls.arbitrary.to_borrowed_impl(self);
+
+        // Doc comments describe the owned form, so the borrowed form of a braid gets its own
+        // docs instead. A standalone `braid_ref` keeps the docs it was written with.
+        let ref_doc: proc_macro2::TokenStream = match self.owned_ty {
+            Some(owned_ty) if self.doc.is_empty() => {
+                let doc = format!("The borrowed form of [`{}`]", owned_ty);
+                quote! { #[doc = #doc] }
+            }
+            _ => self.doc.iter().map(|d| quote! { #[doc = #d] }).collect(),
+        };
+        let ref_attrs = self.attrs;
         let common_attrs = {
             let mut attrs = proc_macro2::TokenStream::new();
-            if self.doc.is_empty() {
-                attrs.append_all(self.common_attrs);
-            } else {
+            if self.owned_ty.is_some() {
                 attrs.append_all(self.common_attrs.iter().filter(|a| !is_doc_attribute(a)));
+            } else {
+                attrs.append_all(self.common_attrs);
             }
             attrs
         };
         let vis = self.vis.map(|v| v.to_token_stream()).unwrap_or_default();
         let ty = &self.ty;
+        let decl_generics = self.generics.decl_generics();
+        let where_clause = self.generics.where_clause();
         let field_attrs = {
             let mut attrs = proc_macro2::TokenStream::new();
             attrs.append_all(&self.field.attrs);
             attrs
         };
+        let unsized_ty = self.slice.unsized_ty();
+        // The unsized field has to be the last one, so any marker comes first
+        let marker = self.field.marker_decl().map(|m| quote! { #m, });
         let body = match &self.field.name {
-            FieldName::Named(name) => quote! ( { #field_attrs #name: str } ),
-            FieldName::Unnamed => quote! { ( #field_attrs str ); },
+            FieldName::Named(name) => {
+                quote! ( #where_clause { #marker #field_attrs #name: #unsized_ty } )
+            }
+            FieldName::Unnamed(_) => {
+                quote! { ( #marker #field_attrs #unsized_ty ) #where_clause; }
+            }
+        };
+
+        // Deriving these for a generic braid would needlessly bound the parameters
+        let (derives, equality) = if self.generics.is_empty() && self.case.is_sensitive() {
+            (Some(quote! { #[derive(Hash, PartialEq, Eq)] }), None)
+        } else {
+            (None, Some(self.equality()))
         };
 
         quote! {
             #[repr(transparent)]
-            #[derive(Hash, PartialEq, Eq)]
-            #ord
+            #derives
+            #facet
             #ref_doc
-            #ref_attrs
+            #(#ref_attrs)*
             #common_attrs
-            #vis struct #ty #body
+            #vis struct #ty #decl_generics #body
 
+            #equality
             #inherent
             #comparison
             #conversion
             #debug
             #display
+            #ord
             #serde
             #rusqlite
+            #diesel
+            #postgres
             #sailfish
+            #sqlx
+            #schemars
+            #utoipa
+            #ts_rs
+            #arbitrary
         }
     }
 }
 
-fn is_doc_attribute(_attr: &crate::grammar::Attribute) -> bool {
-    // TODO: Implement doc attribute detection for unsynn Attribute
-    // For now, we'll return false as doc detection requires parsing the attribute body
-    false
+/// Whether the attribute is a doc comment, either written as `///` or as `#[doc = "..."]`.
+///
+/// Other `doc` attributes, such as `#[doc(hidden)]`, are not doc comments and are kept.
+pub fn is_doc_attribute(attr: &crate::grammar::Attribute) -> bool {
+    matches!(
+        attr.body.content.as_slice(),
+        [TokenTree::Ident(name), TokenTree::Punct(eq), ..] if name == "doc" && eq.as_char() == '='
+    )
 }
@@ -0,0 +1,90 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{ToTokens, quote};
+
+use super::Generics;
+
+/// How a braid's values are compared by the generated `PartialEq`, `Eq`, `Hash`, `PartialOrd`,
+/// and `Ord` implementations
+///
+/// Braids that ignore case don't implement `Borrow` of their raw type, such as `Borrow<str>`, which
+/// requires hashing and equality to agree with the raw type. Otherwise a `HashSet` of braids could
+/// be queried with a `str` that hashes differently from the braid it equals.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Case {
+    #[default]
+    Sensitive,
+    AsciiInsensitive,
+    Insensitive,
+}
+
+impl Case {
+    pub fn is_sensitive(self) -> bool {
+        self == Self::Sensitive
+    }
+
+    /// Whether the raw slices `a` and `b` are equal
+    pub fn eq(self, a: TokenStream, b: TokenStream) -> TokenStream {
+        match self {
+            Self::Sensitive => quote! { #a == #b },
+            Self::AsciiInsensitive => quote! { ::strid::__private::ascii_case_eq(#a, #b) },
+            Self::Insensitive => quote! { ::strid::__private::case_fold_eq(#a, #b) },
+        }
+    }
+
+    /// The ordering of the raw slices `a` and `b`
+    pub fn cmp(self, core: &Ident, a: TokenStream, b: TokenStream) -> TokenStream {
+        match self {
+            Self::Sensitive => quote! { ::#core::cmp::Ord::cmp(#a, #b) },
+            Self::AsciiInsensitive => quote! { ::strid::__private::ascii_case_cmp(#a, #b) },
+            Self::Insensitive => quote! { ::strid::__private::case_fold_cmp(#a, #b) },
+        }
+    }
+
+    /// The `Borrow` implementation of the raw type for `ty`, which exists only when values are
+    /// compared like the raw type
+    pub fn borrow_raw(
+        self,
+        core: &Ident,
+        generics: &Generics,
+        ty: impl ToTokens,
+        unsized_ty: &TokenStream,
+        raw: TokenStream,
+    ) -> Option<TokenStream> {
+        let impl_generics = generics.impl_generics();
+        let where_clause = generics.where_clause();
+
+        self.is_sensitive().then(|| {
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics ::#core::borrow::Borrow<#unsized_ty> for #ty #where_clause {
+                    #[inline]
+                    fn borrow(&self) -> &#unsized_ty {
+                        #raw
+                    }
+                }
+            }
+        })
+    }
+
+    /// Feeds the raw slice `s` into the hasher `state`
+    pub fn hash(self, core: &Ident, s: TokenStream) -> TokenStream {
+        match self {
+            Self::Sensitive => quote! { ::#core::hash::Hash::hash(#s, state) },
+            Self::AsciiInsensitive => quote! { ::strid::__private::ascii_case_hash(#s, state) },
+            Self::Insensitive => quote! { ::strid::__private::case_fold_hash(#s, state) },
+        }
+    }
+}
+
+impl std::str::FromStr for Case {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sensitive" => Ok(Self::Sensitive),
+            "ascii_insensitive" => Ok(Self::AsciiInsensitive),
+            "insensitive" => Ok(Self::Insensitive),
+            _ => Err("valid values are: `sensitive`, `ascii_insensitive`, or `insensitive`"),
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use quote::ToTokens;
 use unsynn::{IParse, ToTokenIter};
 
+use super::Generics;
+
 pub const VALIDATOR: &str = "validator";
 pub const NORMALIZER: &str = "normalizer";
 
@@ -16,7 +18,41 @@ impl CheckMode {
     pub fn serde_err_handler(&self) -> Option<proc_macro2::TokenStream> {
         match self {
             Self::None => None,
-            _ => Some(quote::quote! {.map_err(<D::Error as ::serde::de::Error>::custom)?}),
+            _ => Some(
+                quote::quote! {.map_err(<D::Error as ::strid::__private::serde::de::Error>::custom)?},
+            ),
+        }
+    }
+
+    pub fn sqlx_err_handler(&self) -> Option<proc_macro2::TokenStream> {
+        match self {
+            Self::None => None,
+            _ => Some(quote::quote! {
+                .map_err(|e| {
+                    ::std::boxed::Box::new(e) as ::strid::__private::sqlx::error::BoxDynError
+                })?
+            }),
+        }
+    }
+
+    /// Boxes the error as the `Box<dyn Error + Send + Sync>` that diesel and postgres-types expect
+    pub fn boxed_err_handler(&self) -> Option<proc_macro2::TokenStream> {
+        match self {
+            Self::None => None,
+            _ => Some(quote::quote! {
+                .map_err(|e| -> ::std::boxed::Box<
+                    dyn ::std::error::Error + ::core::marker::Send + ::core::marker::Sync,
+                > { ::std::boxed::Box::new(e) })?
+            }),
+        }
+    }
+
+    pub fn arbitrary_err_handler(&self) -> Option<proc_macro2::TokenStream> {
+        match self {
+            Self::None => None,
+            _ => Some(quote::quote! {
+                .map_err(|_| ::strid::__private::arbitrary::Error::IncorrectFormat)?
+            }),
         }
     }
 
@@ -24,7 +60,7 @@ impl CheckMode {
         match self {
             Self::None => None,
             _ => Some(quote::quote! {
-                .map_err(|e| ::rusqlite::types::FromSqlError::Other(
+                .map_err(|e| ::strid::__private::rusqlite::types::FromSqlError::Other(
                     ::std::boxed::Box::new(e)
                 ))?
             }),
@@ -83,19 +119,40 @@ impl IndefiniteCheckMode {
         Err(err_desc)
     }
 
-    pub fn infer_validator_if_missing(self, default: &unsynn::Ident) -> CheckMode {
+    pub fn infer_validator_if_missing(
+        self,
+        default: &unsynn::Ident,
+        generics: &Generics,
+    ) -> CheckMode {
         match self {
             Self::None => CheckMode::None,
             Self::Validate(Some(validator)) => CheckMode::Validate(validator),
-            Self::Validate(None) => CheckMode::Validate(ident_to_type(default)),
+            Self::Validate(None) => CheckMode::Validate(ident_to_type(default, generics)),
             Self::Normalize(Some(normalizer)) => CheckMode::Normalize(normalizer),
-            Self::Normalize(None) => CheckMode::Normalize(ident_to_type(default)),
+            Self::Normalize(None) => CheckMode::Normalize(ident_to_type(default, generics)),
         }
     }
 }
 
-pub fn ident_to_type(ident: &unsynn::Ident) -> crate::grammar::Type {
-    let tokens = ident.to_token_stream();
+/// Resolves the type that implements `strid::Constraints` for a braid that opted in, which
+/// defaults to its validator or normalizer, or to the braid itself if it has neither.
+pub fn infer_constraints_if_missing(
+    constraints: Option<Option<crate::grammar::Type>>,
+    check_mode: &CheckMode,
+    default: &unsynn::Ident,
+    generics: &Generics,
+) -> Option<crate::grammar::Type> {
+    constraints.map(|constraints| {
+        constraints.unwrap_or_else(|| match check_mode {
+            CheckMode::None => ident_to_type(default, generics),
+            CheckMode::Validate(ty) | CheckMode::Normalize(ty) => ty.clone(),
+        })
+    })
+}
+
+pub fn ident_to_type(ident: &unsynn::Ident, generics: &Generics) -> crate::grammar::Type {
+    let mut tokens = ident.to_token_stream();
+    tokens.extend(generics.ty_generics());
     let mut iter = tokens.to_token_iter();
 
     // Parse the identifier as a type
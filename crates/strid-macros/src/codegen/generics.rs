@@ -0,0 +1,259 @@
+use proc_macro2::{Spacing, TokenStream, TokenTree};
+use quote::quote;
+use unsynn::ToTokens as _;
+
+use crate::error::{Error, Errors, Result};
+
+/// Lifetimes introduced by the generated impls, which a braid's own parameters may not shadow.
+const RESERVED_LIFETIMES: &[&str] = &["a", "b", "de", "q", "r", "s"];
+
+/// Type parameters introduced by the generated impls, which a braid's own parameters may not
+/// shadow.
+const RESERVED_TYPES: &[&str] = &["D", "DB", "H", "S", "ST"];
+
+/// The generic parameters of a braid, which are shared by the owned and borrowed types.
+///
+/// Braids are only generic over markers: the parameters are carried by a `PhantomData` field
+/// and never constrain the wrapped string, so the generated impls don't require anything of
+/// them beyond the braid's own bounds.
+#[derive(Clone, Default)]
+pub struct Generics {
+    params: Vec<GenericParam>,
+    predicates: TokenStream,
+}
+
+#[derive(Clone)]
+struct GenericParam {
+    is_lifetime: bool,
+    name: TokenStream,
+    bounds: TokenStream,
+    default: TokenStream,
+}
+
+impl Generics {
+    pub fn from_item(item: &crate::grammar::ItemStruct) -> Result<Self> {
+        let mut errors = Errors::default();
+
+        let mut params = Vec::new();
+        if let Some(generics) = &item.generics {
+            let tokens: Vec<TokenTree> = generics.params.to_token_stream().into_iter().collect();
+            for param in split_top_level(&tokens, ',') {
+                if let Some(param) = errors.handle(GenericParam::parse(param)) {
+                    params.push(param);
+                }
+            }
+        }
+
+        let predicates = item
+            .where_clause
+            .as_ref()
+            .or(item.trailing_where_clause.as_ref())
+            .map(|w| w.predicates.to_token_stream())
+            .unwrap_or_default();
+
+        errors.finish()?;
+        Ok(Self { params, predicates })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// The parameters as declared on the generated types, including any defaults.
+    pub fn decl_generics(&self) -> TokenStream {
+        if self.is_empty() {
+            return TokenStream::new();
+        }
+
+        let params = self.params.iter().map(|p| {
+            let GenericParam {
+                name,
+                bounds,
+                default,
+                ..
+            } = p;
+            quote! { #name #bounds #default }
+        });
+        quote! { <#(#params),*> }
+    }
+
+    /// The parameters to declare on an impl, e.g. `<'x, T: Bound>`.
+    pub fn impl_generics(&self) -> TokenStream {
+        if self.is_empty() {
+            return TokenStream::new();
+        }
+
+        let params = self.params.iter().map(GenericParam::declaration);
+        quote! { <#(#params),*> }
+    }
+
+    /// The parameters to declare on an impl that introduces its own lifetimes, which come first.
+    pub fn impl_generics_with(&self, lifetimes: TokenStream) -> TokenStream {
+        let params = self.params.iter().map(GenericParam::declaration);
+        quote! { <#lifetimes #(, #params)*> }
+    }
+
+    /// The parameters to declare on an impl that introduces its own lifetimes and type
+    /// parameters, which come before and after the braid's parameters respectively.
+    pub fn impl_generics_around(&self, lifetimes: TokenStream, types: TokenStream) -> TokenStream {
+        let params = self.params.iter().map(GenericParam::declaration);
+        let all = Some(lifetimes)
+            .into_iter()
+            .chain(params)
+            .chain(Some(types))
+            .filter(|p| !p.is_empty());
+        quote! { <#(#all),*> }
+    }
+
+    /// The parameters applied to the type, e.g. `<'x, T>`.
+    pub fn ty_generics(&self) -> TokenStream {
+        if self.is_empty() {
+            return TokenStream::new();
+        }
+
+        let names = self.params.iter().map(|p| &p.name);
+        quote! { <#(#names),*> }
+    }
+
+    /// The parameters applied to the type in expression position, e.g. `::<'x, T>`.
+    pub fn turbofish(&self) -> TokenStream {
+        if self.is_empty() {
+            return TokenStream::new();
+        }
+
+        let ty_generics = self.ty_generics();
+        quote! { ::#ty_generics }
+    }
+
+    pub fn where_clause(&self) -> TokenStream {
+        if self.predicates.is_empty() {
+            return TokenStream::new();
+        }
+
+        let predicates = &self.predicates;
+        quote! { where #predicates }
+    }
+
+    /// The braid's where clause, extended with predicates required by a generated impl.
+    pub fn where_clause_with(&self, extra: TokenStream) -> TokenStream {
+        let predicates = &self.predicates;
+        let separator = match predicates.clone().into_iter().last() {
+            None => None,
+            Some(TokenTree::Punct(p)) if p.as_char() == ',' => None,
+            Some(_) => Some(quote!(,)),
+        };
+        quote! { where #predicates #separator #extra }
+    }
+
+    /// The type of the marker field that carries the parameters.
+    ///
+    /// `fn() -> T` keeps the braid covariant and `Send + Sync` regardless of the markers, and
+    /// doesn't make the drop checker think that a `T` is owned. Each type is wrapped in its own
+    /// `PhantomData` so that unsized markers are allowed.
+    pub fn marker_ty(&self) -> Option<TokenStream> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let elems = self.params.iter().map(|p| {
+            let name = &p.name;
+            if p.is_lifetime {
+                quote! { &#name () }
+            } else {
+                quote! { ::core::marker::PhantomData<#name> }
+            }
+        });
+        Some(quote! { ::core::marker::PhantomData<fn() -> (#(#elems,)*)> })
+    }
+}
+
+impl GenericParam {
+    fn parse(tokens: &[TokenTree]) -> Result<Self> {
+        let (is_lifetime, name_len) = match tokens {
+            [TokenTree::Punct(p), TokenTree::Ident(ident), ..] if p.as_char() == '\'' => {
+                if RESERVED_LIFETIMES.iter().any(|r| ident == *r) {
+                    let lifetime: TokenStream = tokens[..2].iter().cloned().collect();
+                    return Err(Error::new_spanned(
+                        lifetime,
+                        format!("the lifetime `'{ident}` is reserved for use by the braid"),
+                    ));
+                }
+                (true, 2)
+            }
+            [TokenTree::Ident(ident), ..] if ident == "const" => {
+                return Err(Error::new(
+                    ident.span(),
+                    "braids can only be generic over lifetimes and marker types",
+                ));
+            }
+            [TokenTree::Ident(ident), ..] => {
+                if RESERVED_TYPES.iter().any(|r| ident == *r) {
+                    return Err(Error::new(
+                        ident.span(),
+                        format!("the type parameter `{ident}` is reserved for use by the braid"),
+                    ));
+                }
+                (false, 1)
+            }
+            _ => {
+                let tokens: TokenStream = tokens.iter().cloned().collect();
+                return Err(Error::new_spanned(tokens, "expected a generic parameter"));
+            }
+        };
+
+        let (name, rest) = tokens.split_at(name_len);
+        let default_at = top_level_positions(rest, '=')
+            .first()
+            .copied()
+            .unwrap_or(rest.len());
+        let (bounds, default) = rest.split_at(default_at);
+
+        Ok(Self {
+            is_lifetime,
+            name: name.iter().cloned().collect(),
+            bounds: bounds.iter().cloned().collect(),
+            default: default.iter().cloned().collect(),
+        })
+    }
+
+    fn declaration(&self) -> TokenStream {
+        let Self { name, bounds, .. } = self;
+        quote! { #name #bounds }
+    }
+}
+
+/// Splits `tokens` on `separator`, ignoring any separators nested within `<...>`.
+fn split_top_level(tokens: &[TokenTree], separator: char) -> impl Iterator<Item = &[TokenTree]> {
+    let mut start = 0;
+    top_level_positions(tokens, separator)
+        .into_iter()
+        .chain([tokens.len()])
+        .map(move |end| {
+            let part = &tokens[start..end];
+            start = end + 1;
+            part
+        })
+        .filter(|part| !part.is_empty())
+}
+
+/// Finds the positions of `separator` in `tokens` that are not nested within `<...>`.
+fn top_level_positions(tokens: &[TokenTree], separator: char) -> Vec<usize> {
+    let mut positions = Vec::new();
+    let mut depth = 0usize;
+    let mut prev_joint_dash = false;
+    for (i, tt) in tokens.iter().enumerate() {
+        let mut joint_dash = false;
+        if let TokenTree::Punct(p) = tt {
+            match p.as_char() {
+                '<' => depth += 1,
+                // `->` in a `Fn` bound doesn't close an angle bracket
+                '>' if !prev_joint_dash => depth = depth.saturating_sub(1),
+                '-' => joint_dash = p.spacing() == Spacing::Joint,
+                c if c == separator && depth == 0 => positions.push(i),
+                _ => {}
+            }
+        }
+        prev_joint_dash = joint_dash;
+    }
+    positions
+}
@@ -1,6 +1,6 @@
 use quote::{ToTokens, quote};
 
-use super::{OwnedCodeGen, RefCodeGen, check_mode::CheckMode};
+use super::{FieldName, Generics, OwnedCodeGen, RefCodeGen, Slice, check_mode::CheckMode};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ImplOption {
@@ -9,7 +9,7 @@ pub enum ImplOption {
 }
 
 impl ImplOption {
-    fn map<F>(self, f: F) -> Option<proc_macro2::TokenStream>
+    pub fn map<F>(self, f: F) -> Option<proc_macro2::TokenStream>
     where
         F: FnOnce() -> proc_macro2::TokenStream,
     {
@@ -40,7 +40,7 @@ pub enum DelegatingImplOption {
 }
 
 impl DelegatingImplOption {
-    fn map_owned<F>(self, f: F) -> Option<proc_macro2::TokenStream>
+    pub fn map_owned<F>(self, f: F) -> Option<proc_macro2::TokenStream>
     where
         F: FnOnce() -> proc_macro2::TokenStream,
     {
@@ -50,7 +50,7 @@ impl DelegatingImplOption {
         }
     }
 
-    fn map_ref<F>(self, f: F) -> Option<proc_macro2::TokenStream>
+    pub fn map_ref<F>(self, f: F) -> Option<proc_macro2::TokenStream>
     where
         F: FnOnce() -> proc_macro2::TokenStream,
     {
@@ -89,9 +89,34 @@ pub struct Impls {
     pub debug: ImplDebug,
     pub display: ImplDisplay,
     pub ord: ImplOrd,
+    pub facet: ImplFacet,
     pub serde: ImplSerde,
     pub rusqlite: ImplRusqlite,
+    pub diesel: ImplDiesel,
+    pub postgres: ImplPostgres,
     pub sailfish: ImplSailfish,
+    pub sqlx: ImplSqlx,
+    pub schemars: ImplSchemars,
+    pub utoipa: ImplUtoipa,
+    pub ts_rs: ImplTsRs,
+    pub proptest: ImplProptest,
+    pub arbitrary: ImplArbitrary,
+    pub quickcheck: ImplQuickcheck,
+}
+
+impl Impls {
+    /// The impls of a braid around `slice` that aren't opted into. Only strings can be
+    /// displayed, and facet only knows how to reflect strings and bytes.
+    pub fn new(slice: Slice) -> Self {
+        let mut impls = Self::default();
+        if slice != Slice::Str {
+            impls.display = DelegatingImplOption::Omit.into();
+        }
+        if !matches!(slice, Slice::Str | Slice::Bytes) {
+            impls.facet = DelegatingImplOption::Omit.into();
+        }
+        impls
+    }
 }
 
 pub(crate) trait ToImpl {
@@ -120,8 +145,33 @@ impl From<ImplOption> for ImplClone {
 }
 
 impl ToImpl for ImplClone {
-    fn to_owned_impl(&self, _cg: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
-        self.0.map(|| quote! { #[derive(Clone)] })
+    fn to_owned_impl(&self, cg: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        if cg.generics.is_empty() {
+            return self.0.map(|| quote! { #[derive(Clone)] });
+        }
+
+        // Deriving `Clone` would require the marker parameters to be `Clone` too
+        self.0.map(|| {
+            let ty = cg.owned_type();
+            let field_name = &cg.field.name;
+            let core = cg.std_lib.core();
+            let create = cg.field.construct(
+                quote! { Self },
+                quote! { ::#core::clone::Clone::clone(&self.#field_name) },
+            );
+            let impl_generics = cg.generics.impl_generics();
+            let where_clause = cg.generics.where_clause();
+
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics ::#core::clone::Clone for #ty #where_clause {
+                    #[inline]
+                    fn clone(&self) -> Self {
+                        #create
+                    }
+                }
+            }
+        })
     }
 }
 
@@ -141,13 +191,15 @@ impl From<DelegatingImplOption> for ImplDisplay {
 }
 impl ToImpl for ImplDisplay {
     fn to_owned_impl(&self, cg: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
-        let ty = cg.ty;
-        let ref_ty = cg.ref_ty;
+        let ty = cg.owned_type();
+        let impl_generics = cg.generics.impl_generics();
+        let where_clause = cg.generics.where_clause();
+        let ref_ty = cg.ref_type();
         let core = cg.std_lib.core();
         self.0.map_owned(|| {
             quote! {
                 #[automatically_derived]
-                impl ::#core::fmt::Display for #ty {
+                impl #impl_generics ::#core::fmt::Display for #ty #where_clause {
                     #[inline]
                     fn fmt(&self, f: &mut ::#core::fmt::Formatter) -> ::#core::fmt::Result {
                         <#ref_ty as ::#core::fmt::Display>::fmt(::#core::ops::Deref::deref(self), f)
@@ -158,13 +210,15 @@ impl ToImpl for ImplDisplay {
     }
 
     fn to_borrowed_impl(&self, cg: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
-        let ty = &cg.ty;
+        let ty = cg.ref_type();
+        let impl_generics = cg.generics.impl_generics();
+        let where_clause = cg.generics.where_clause();
         let field_name = &cg.field.name;
         let core = cg.std_lib.core();
         self.0.map_ref(|| {
             quote! {
                 #[automatically_derived]
-                impl ::#core::fmt::Display for #ty {
+                impl #impl_generics ::#core::fmt::Display for #ty #where_clause {
                     #[inline]
                     fn fmt(&self, f: &mut ::#core::fmt::Formatter) -> ::#core::fmt::Result {
                         <str as ::#core::fmt::Display>::fmt(&self.#field_name, f)
@@ -192,13 +246,15 @@ impl From<DelegatingImplOption> for ImplDebug {
 
 impl ToImpl for ImplDebug {
     fn to_owned_impl(&self, cg: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
-        let ty = cg.ty;
-        let ref_ty = cg.ref_ty;
+        let ty = cg.owned_type();
+        let impl_generics = cg.generics.impl_generics();
+        let where_clause = cg.generics.where_clause();
+        let ref_ty = cg.ref_type();
         let core = cg.std_lib.core();
         self.0.map_owned(|| {
             quote! {
                 #[automatically_derived]
-                impl ::#core::fmt::Debug for #ty {
+                impl #impl_generics ::#core::fmt::Debug for #ty #where_clause {
                     #[inline]
                     fn fmt(&self, f: &mut ::#core::fmt::Formatter) -> ::#core::fmt::Result {
                         <#ref_ty as ::#core::fmt::Debug>::fmt(::#core::ops::Deref::deref(self), f)
@@ -209,16 +265,28 @@ impl ToImpl for ImplDebug {
     }
 
     fn to_borrowed_impl(&self, cg: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
-        let ty = &cg.ty;
+        let ty = cg.ref_type();
+        let impl_generics = cg.generics.impl_generics();
+        let where_clause = cg.generics.where_clause();
         let field_name = &cg.field.name;
         let core = cg.std_lib.core();
+        let fmt = match cg.slice {
+            // Formatted like a byte string literal, so printable ASCII stays readable
+            Slice::Bytes => quote! {
+                ::#core::write!(f, "b\"{}\"", <[u8]>::escape_ascii(&self.#field_name))
+            },
+            _ => {
+                let unsized_ty = cg.slice.unsized_ty();
+                quote! { <#unsized_ty as ::#core::fmt::Debug>::fmt(&self.#field_name, f) }
+            }
+        };
         self.0.map_ref(|| {
             quote! {
                 #[automatically_derived]
-                impl ::#core::fmt::Debug for #ty {
+                impl #impl_generics ::#core::fmt::Debug for #ty #where_clause {
                     #[inline]
                     fn fmt(&self, f: &mut ::#core::fmt::Formatter) -> ::#core::fmt::Result {
-                        <str as ::#core::fmt::Debug>::fmt(&self.#field_name, f)
+                        #fmt
                     }
                 }
             }
@@ -243,30 +311,228 @@ impl From<DelegatingImplOption> for ImplOrd {
 
 impl ToImpl for ImplOrd {
     fn to_owned_impl(&self, cg: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
-        let ty = &cg.ty;
-        let field_name = &cg.field.name;
+        let ty = cg.owned_type();
+        let impl_generics = cg.generics.impl_generics();
+        let where_clause = cg.generics.where_clause();
         let core = cg.std_lib.core();
+        let (this, that) = cg.comparands();
+        let cmp = cg.case.cmp(core, this, that);
         self.0.map_owned(|| quote! {
             #[automatically_derived]
-            impl ::#core::cmp::Ord for #ty {
+            impl #impl_generics ::#core::cmp::Ord for #ty #where_clause {
                 #[inline]
                 fn cmp(&self, other: &Self) -> ::#core::cmp::Ordering {
-                    ::#core::cmp::Ord::cmp(&self.#field_name, &other.#field_name)
+                    #cmp
                 }
             }
 
             #[automatically_derived]
-            impl ::#core::cmp::PartialOrd for #ty {
+            impl #impl_generics ::#core::cmp::PartialOrd for #ty #where_clause {
                 #[inline]
                 fn partial_cmp(&self, other: &Self) -> ::#core::option::Option<::#core::cmp::Ordering> {
-                    ::#core::cmp::PartialOrd::partial_cmp(&self.#field_name, &other.#field_name)
+                    ::#core::option::Option::Some(::#core::cmp::Ord::cmp(self, other))
                 }
             }
         })
     }
 
-    fn to_borrowed_impl(&self, _cg: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
-        self.0.map_ref(|| quote! { #[derive(PartialOrd, Ord)] })
+    fn to_borrowed_impl(&self, cg: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
+        let ty = cg.ref_type();
+        let impl_generics = cg.generics.impl_generics();
+        let where_clause = cg.generics.where_clause();
+        let field_name = &cg.field.name;
+        let core = cg.std_lib.core();
+        let cmp = cg.case.cmp(
+            core,
+            quote! { &self.#field_name },
+            quote! { &other.#field_name },
+        );
+        self.0.map_ref(|| quote! {
+            #[automatically_derived]
+            impl #impl_generics ::#core::cmp::Ord for #ty #where_clause {
+                #[inline]
+                fn cmp(&self, other: &Self) -> ::#core::cmp::Ordering {
+                    #cmp
+                }
+            }
+
+            #[automatically_derived]
+            impl #impl_generics ::#core::cmp::PartialOrd for #ty #where_clause {
+                #[inline]
+                fn partial_cmp(&self, other: &Self) -> ::#core::option::Option<::#core::cmp::Ordering> {
+                    ::#core::option::Option::Some(::#core::cmp::Ord::cmp(self, other))
+                }
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ImplFacet(DelegatingImplOption);
+
+impl Default for ImplFacet {
+    fn default() -> Self {
+        Self(DelegatingImplOption::Implement)
+    }
+}
+
+impl From<DelegatingImplOption> for ImplFacet {
+    fn from(opt: DelegatingImplOption) -> Self {
+        Self(opt)
+    }
+}
+
+impl ImplFacet {
+    /// Whether the owned type gets a `Facet` implementation.
+    pub fn has_owned_impl(&self) -> bool {
+        self.0 != DelegatingImplOption::Omit
+    }
+
+    /// Whether the borrowed type gets a `Facet` implementation.
+    pub fn has_borrowed_impl(&self) -> bool {
+        self.0 == DelegatingImplOption::Implement
+    }
+
+    pub fn invariants_fn() -> proc_macro2::Ident {
+        proc_macro2::Ident::new("__strid_facet_invariants", proc_macro2::Span::call_site())
+    }
+
+    /// Describes a braid's error for facet, through its `Display` impl if it has one, so that
+    /// facet reports why a value was rejected as serde does.
+    fn describe_error(error: &proc_macro2::Ident) -> proc_macro2::TokenStream {
+        quote! {{
+            use ::strid::__private::{FacetDisplay as _, FacetFallback as _};
+            (&::strid::__private::FacetError(&#error)).describe()
+        }}
+    }
+
+    /// The hidden method that facet calls to check the invariants of a borrowed value it has
+    /// built, for braids that have any.
+    ///
+    /// Facet builds borrowed values in place, so there is no chance to normalize them. Instead,
+    /// a normalized braid's validator rejects any value that isn't already in normalized form.
+    pub fn invariants_method(
+        slice: Slice,
+        check_mode: &CheckMode,
+    ) -> Option<proc_macro2::TokenStream> {
+        let checker = match check_mode {
+            CheckMode::None => return None,
+            CheckMode::Validate(checker) | CheckMode::Normalize(checker) => checker,
+        };
+
+        let validator = slice.validator(checker);
+        let as_raw = slice.method("as_", "");
+        let invariants_fn = Self::invariants_fn();
+        let error = proc_macro2::Ident::new("error", proc_macro2::Span::call_site());
+        let describe = Self::describe_error(&error);
+
+        Some(quote! {
+            #[doc(hidden)]
+            #[inline]
+            fn #invariants_fn(&self) -> ::core::result::Result<(), ::strid::__private::FacetMessage> {
+                #validator::validate(self.#as_raw()).map_err(|#error| #describe)
+            }
+        })
+    }
+
+    /// Only a tuple struct around the string alone can be transparent, which rules out named
+    /// fields and the marker field of generic braids.
+    fn transparent(field: &FieldName, is_generic: bool) -> Option<proc_macro2::TokenStream> {
+        (matches!(field, FieldName::Unnamed(_)) && !is_generic).then(|| quote! { , transparent })
+    }
+
+    /// A braid with a check goes through a proxy of its buffer type, so that facet builds it
+    /// with the same conversion as any other value and normalizes it along the way.
+    pub fn owned_attrs(
+        field: &FieldName,
+        is_generic: bool,
+        proxy: Option<&proc_macro2::TokenStream>,
+    ) -> proc_macro2::TokenStream {
+        let repr = match proxy {
+            Some(buf) => quote! { , proxy = ::strid::__private::FacetProxy<#buf> },
+            None => Self::transparent(field, is_generic).unwrap_or_default(),
+        };
+
+        quote! {
+            #[derive(::strid::facet::Facet)]
+            #[facet(crate = ::strid::facet #repr)]
+        }
+    }
+
+    pub fn borrowed_attrs(
+        field: &FieldName,
+        is_generic: bool,
+        invariants: Option<&proc_macro2::Ident>,
+    ) -> proc_macro2::TokenStream {
+        let transparent = Self::transparent(field, is_generic);
+        let invariants = invariants.map(|ty| {
+            let invariants_fn = Self::invariants_fn();
+            quote! { , invariants = #ty::#invariants_fn }
+        });
+
+        quote! {
+            #[derive(::strid::facet::Facet)]
+            #[facet(crate = ::strid::facet #transparent #invariants)]
+        }
+    }
+
+    /// The conversions between an owned braid and the proxy that facet builds it from.
+    pub fn proxy_conversion(
+        ty: &proc_macro2::TokenStream,
+        generics: &Generics,
+        core: &proc_macro2::Ident,
+        buf: &proc_macro2::TokenStream,
+        as_raw: &proc_macro2::Ident,
+    ) -> proc_macro2::TokenStream {
+        let impl_generics = generics.impl_generics();
+        let where_clause = generics.where_clause();
+        let proxy = quote! { ::strid::__private::FacetProxy<#buf> };
+        let error = proc_macro2::Ident::new("error", proc_macro2::Span::call_site());
+        let describe = Self::describe_error(&error);
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::#core::convert::TryFrom<#proxy> for #ty #where_clause {
+                type Error = ::strid::__private::FacetInvalid;
+
+                #[inline]
+                fn try_from(proxy: #proxy) -> ::#core::result::Result<Self, Self::Error> {
+                    <Self as ::#core::convert::TryFrom<#buf>>::try_from(proxy.0)
+                        .map_err(|#error| ::strid::__private::FacetInvalid(#describe))
+                }
+            }
+
+            #[automatically_derived]
+            impl #impl_generics ::#core::convert::From<&#ty> for #proxy #where_clause {
+                #[inline]
+                fn from(value: &#ty) -> Self {
+                    ::strid::__private::FacetProxy(::#core::convert::From::from(value.#as_raw()))
+                }
+            }
+        }
+    }
+}
+
+impl ToImpl for ImplFacet {
+    fn to_owned_impl(&self, cg: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        let proxy = (!matches!(cg.check_mode, CheckMode::None))
+            .then(|| cg.slice.buf_ty(cg.std_lib.alloc()));
+        self.0.map_owned(|| {
+            Self::owned_attrs(&cg.field.name, !cg.generics.is_empty(), proxy.as_ref())
+        })
+    }
+
+    fn to_borrowed_impl(&self, cg: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
+        if !self.has_borrowed_impl() {
+            return None;
+        }
+
+        let invariants = (!matches!(cg.check_mode, CheckMode::None)).then_some(&cg.ident);
+        Some(Self::borrowed_attrs(
+            &cg.field.name,
+            !cg.generics.is_empty(),
+            invariants,
+        ))
     }
 }
 
@@ -288,24 +554,336 @@ impl From<ImplOption> for ImplRusqlite {
 impl ToImpl for ImplRusqlite {
     fn to_owned_impl(&self, cg: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
         self.0.map(|| {
-            let name = cg.ty;
+            let name = cg.owned_type();
+            let impl_generics = cg.generics.impl_generics();
+            let where_clause = cg.generics.where_clause();
             let field_name = &cg.field.name;
+            let buf_ty = cg.slice.buf_ty(cg.std_lib.alloc());
             let handle_failure = cg.check_mode.rusqlite_err_handler();
 
             quote! {
                 #[automatically_derived]
-                impl ::rusqlite::types::ToSql for #name {
-                    fn to_sql(&self) -> ::rusqlite::Result<::rusqlite::types::ToSqlOutput<'_>> {
-                        self.#field_name.to_sql()
+                impl #impl_generics ::strid::__private::rusqlite::types::ToSql for #name #where_clause {
+                    fn to_sql(&self) -> ::strid::__private::rusqlite::Result<::strid::__private::rusqlite::types::ToSqlOutput<'_>> {
+                        ::strid::__private::rusqlite::types::ToSql::to_sql(&self.#field_name)
+                    }
+                }
+
+                #[automatically_derived]
+                impl #impl_generics ::strid::__private::rusqlite::types::FromSql for #name #where_clause {
+                    #[allow(clippy::needless_question_mark)]
+                    fn column_result(value: ::strid::__private::rusqlite::types::ValueRef<'_>) -> ::strid::__private::rusqlite::types::FromSqlResult<Self> {
+                        let s = <#buf_ty as ::strid::__private::rusqlite::types::FromSql>::column_result(value)?;
+                        ::std::result::Result::Ok(Self::new(::std::convert::From::from(s))#handle_failure)
+                    }
+                }
+            }
+        })
+    }
+
+    fn to_borrowed_impl(&self, cg: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            let ty = cg.ref_type();
+            let impl_generics = cg.generics.impl_generics();
+            let where_clause = cg.generics.where_clause();
+            let as_raw = cg.slice.method("as_", "");
+
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics ::strid::__private::rusqlite::types::ToSql for #ty #where_clause {
+                    fn to_sql(&self) -> ::strid::__private::rusqlite::Result<::strid::__private::rusqlite::types::ToSqlOutput<'_>> {
+                        ::strid::__private::rusqlite::types::ToSql::to_sql(self.#as_raw())
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ImplDiesel(ImplOption);
+
+impl Default for ImplDiesel {
+    fn default() -> Self {
+        Self(ImplOption::Omit)
+    }
+}
+
+impl From<ImplOption> for ImplDiesel {
+    fn from(opt: ImplOption) -> Self {
+        Self(opt)
+    }
+}
+
+impl ImplDiesel {
+    /// The SQL type that values are stored as, `Text` for strings and `Binary` for bytes
+    fn sql_type(slice: Slice) -> proc_macro2::TokenStream {
+        match slice {
+            Slice::Bytes => quote! { ::strid::__private::diesel::sql_types::Binary },
+            _ => quote! { ::strid::__private::diesel::sql_types::Text },
+        }
+    }
+
+    /// `AsExpression` impls for the type and references to it, which delegate to those of the
+    /// buffer and unsized types, like `String` and `&str`. By-value impls are only possible for
+    /// the owned type, which passes its buffer type as `by_value`.
+    fn as_expression(
+        ty: &proc_macro2::TokenStream,
+        generics: &Generics,
+        slice: Slice,
+        by_value: Option<&proc_macro2::TokenStream>,
+    ) -> proc_macro2::TokenStream {
+        let diesel = quote! { ::strid::__private::diesel };
+        let impl_generics = generics.impl_generics();
+        let impl_generics_a = generics.impl_generics_with(quote!('a));
+        let impl_generics_ab = generics.impl_generics_with(quote!('a, 'b));
+        let where_clause = generics.where_clause();
+        let sql_type = Self::sql_type(slice);
+        let unsized_ty = slice.unsized_ty();
+        let as_raw = slice.method("as_", "");
+
+        let sql_types = [
+            quote! { #sql_type },
+            quote! { #diesel::sql_types::Nullable<#sql_type> },
+        ];
+        let impls = sql_types.iter().map(|sql_type| {
+            let as_expression = quote! { #diesel::expression::AsExpression<#sql_type> };
+            let by_value = by_value.map(|buf_ty| {
+                quote! {
+                    #[automatically_derived]
+                    impl #impl_generics #as_expression for #ty #where_clause {
+                        type Expression = <#buf_ty as #as_expression>::Expression;
+
+                        fn as_expression(self) -> Self::Expression {
+                            <#buf_ty as #as_expression>::as_expression(::std::convert::From::from(self))
+                        }
+                    }
+                }
+            });
+
+            quote! {
+                #by_value
+
+                #[automatically_derived]
+                impl #impl_generics_a #as_expression for &'a #ty #where_clause {
+                    type Expression = <&'a #unsized_ty as #as_expression>::Expression;
+
+                    fn as_expression(self) -> Self::Expression {
+                        <&'a #unsized_ty as #as_expression>::as_expression(self.#as_raw())
+                    }
+                }
+
+                #[automatically_derived]
+                impl #impl_generics_ab #as_expression for &'b &'a #ty #where_clause {
+                    type Expression = <&'a #unsized_ty as #as_expression>::Expression;
+
+                    fn as_expression(self) -> Self::Expression {
+                        <&'a #unsized_ty as #as_expression>::as_expression((*self).#as_raw())
+                    }
+                }
+            }
+        });
+
+        quote! { #(#impls)* }
+    }
+
+    fn to_sql(
+        ty: &proc_macro2::TokenStream,
+        generics: &Generics,
+        slice: Slice,
+    ) -> proc_macro2::TokenStream {
+        let diesel = quote! { ::strid::__private::diesel };
+        let sql_type = Self::sql_type(slice);
+        let unsized_ty = slice.unsized_ty();
+        let as_raw = slice.method("as_", "");
+        let impl_generics = generics.impl_generics_around(quote!(), quote!(DB));
+        let where_clause = generics.where_clause_with(quote! {
+            DB: #diesel::backend::Backend,
+            #unsized_ty: #diesel::serialize::ToSql<#sql_type, DB>,
+        });
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #diesel::serialize::ToSql<#sql_type, DB> for #ty #where_clause {
+                fn to_sql<'b>(&'b self, out: &mut #diesel::serialize::Output<'b, '_, DB>) -> #diesel::serialize::Result {
+                    <#unsized_ty as #diesel::serialize::ToSql<#sql_type, DB>>::to_sql(self.#as_raw(), out)
+                }
+            }
+
+            #[automatically_derived]
+            impl #impl_generics #diesel::serialize::ToSql<#diesel::sql_types::Nullable<#sql_type>, DB> for #ty #where_clause {
+                fn to_sql<'b>(&'b self, out: &mut #diesel::serialize::Output<'b, '_, DB>) -> #diesel::serialize::Result {
+                    <#unsized_ty as #diesel::serialize::ToSql<#sql_type, DB>>::to_sql(self.#as_raw(), out)
+                }
+            }
+        }
+    }
+}
+
+impl ToImpl for ImplDiesel {
+    fn to_owned_impl(&self, cg: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            let diesel = quote! { ::strid::__private::diesel };
+            let name = cg.owned_type();
+            let wrapped_type = &cg.field.ty;
+            let sql_type = Self::sql_type(cg.slice);
+            let handle_failure = cg.check_mode.boxed_err_handler();
+
+            let buf_ty = cg.slice.buf_ty(cg.std_lib.alloc());
+            let as_expression = Self::as_expression(&name, cg.generics, cg.slice, Some(&buf_ty));
+            let to_sql = Self::to_sql(&name, cg.generics, cg.slice);
+
+            let from_sql_generics = cg.generics.impl_generics_around(quote!(), quote!(DB));
+            let from_sql_where = cg.generics.where_clause_with(quote! {
+                DB: #diesel::backend::Backend,
+                #wrapped_type: #diesel::deserialize::FromSql<#sql_type, DB>,
+            });
+            let queryable_generics = cg.generics.impl_generics_around(quote!(), quote!(DB, ST));
+            let queryable_where = cg.generics.where_clause_with(quote! {
+                DB: #diesel::backend::Backend,
+                ST: #diesel::sql_types::SingleValue,
+                Self: #diesel::deserialize::FromSql<ST, DB>,
+            });
+
+            quote! {
+                #as_expression
+                #to_sql
+
+                #[allow(clippy::needless_question_mark)]
+                #[automatically_derived]
+                impl #from_sql_generics #diesel::deserialize::FromSql<#sql_type, DB> for #name #from_sql_where {
+                    fn from_sql(bytes: <DB as #diesel::backend::Backend>::RawValue<'_>) -> #diesel::deserialize::Result<Self> {
+                        let raw = <#wrapped_type as #diesel::deserialize::FromSql<#sql_type, DB>>::from_sql(bytes)?;
+                        ::std::result::Result::Ok(Self::new(raw)#handle_failure)
                     }
                 }
 
                 #[automatically_derived]
-                impl ::rusqlite::types::FromSql for #name {
-                    fn column_result(value: ::rusqlite::types::ValueRef<'_>) -> ::rusqlite::types::FromSqlResult<Self> {
-                        let s = <::std::string::String as ::rusqlite::types::FromSql>::column_result(value)?;
-                        ::std::result::Result::Ok(Self::new(s)#handle_failure)
+                impl #queryable_generics #diesel::deserialize::Queryable<ST, DB> for #name #queryable_where {
+                    type Row = Self;
+
+                    fn build(row: Self) -> #diesel::deserialize::Result<Self> {
+                        ::std::result::Result::Ok(row)
+                    }
+                }
+            }
+        })
+    }
+
+    fn to_borrowed_impl(&self, cg: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            let ty = cg.ref_type();
+            let as_expression = Self::as_expression(&ty, cg.generics, cg.slice, None);
+            let to_sql = Self::to_sql(&ty, cg.generics, cg.slice);
+
+            quote! {
+                #as_expression
+                #to_sql
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ImplPostgres(ImplOption);
+
+impl Default for ImplPostgres {
+    fn default() -> Self {
+        Self(ImplOption::Omit)
+    }
+}
+
+impl From<ImplOption> for ImplPostgres {
+    fn from(opt: ImplOption) -> Self {
+        Self(opt)
+    }
+}
+
+impl ImplPostgres {
+    fn accepts(slice: Slice) -> proc_macro2::TokenStream {
+        let types = match slice {
+            Slice::Bytes => quote! { ::strid::__private::postgres_types::Type::BYTEA },
+            _ => quote! {
+                ::strid::__private::postgres_types::Type::TEXT
+                    | ::strid::__private::postgres_types::Type::VARCHAR
+                    | ::strid::__private::postgres_types::Type::BPCHAR
+                    | ::strid::__private::postgres_types::Type::NAME
+            },
+        };
+
+        quote! {
+            fn accepts(ty: &::strid::__private::postgres_types::Type) -> bool {
+                ::std::matches!(*ty, #types)
+            }
+        }
+    }
+
+    fn to_sql(
+        self_ty: &proc_macro2::TokenStream,
+        impl_generics: &proc_macro2::TokenStream,
+        where_clause: &proc_macro2::TokenStream,
+        slice: Slice,
+    ) -> proc_macro2::TokenStream {
+        let accepts = Self::accepts(slice);
+        let unsized_ty = slice.unsized_ty();
+        let as_raw = slice.method("as_", "");
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::strid::__private::postgres_types::ToSql for #self_ty #where_clause {
+                fn to_sql(
+                    &self,
+                    ty: &::strid::__private::postgres_types::Type,
+                    out: &mut ::strid::__private::bytes::BytesMut,
+                ) -> ::std::result::Result<
+                    ::strid::__private::postgres_types::IsNull,
+                    ::std::boxed::Box<dyn ::std::error::Error + ::core::marker::Sync + ::core::marker::Send>,
+                > {
+                    <&#unsized_ty as ::strid::__private::postgres_types::ToSql>::to_sql(&self.#as_raw(), ty, out)
+                }
+
+                #accepts
+
+                ::strid::__private::postgres_types::to_sql_checked!();
+            }
+        }
+    }
+}
+
+impl ToImpl for ImplPostgres {
+    fn to_owned_impl(&self, cg: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            let name = cg.owned_type();
+            let impl_generics = cg.generics.impl_generics();
+            let impl_generics_a = cg.generics.impl_generics_with(quote!('a));
+            let where_clause = cg.generics.where_clause();
+            let handle_failure = cg.check_mode.boxed_err_handler();
+            let unsized_ty = cg.slice.unsized_ty();
+            let to_field =
+                cg.slice
+                    .to_field(cg.std_lib.core(), cg.std_lib.alloc(), quote! { raw });
+
+            let to_sql = Self::to_sql(&name, &impl_generics, &where_clause, cg.slice);
+            let accepts = Self::accepts(cg.slice);
+
+            quote! {
+                #to_sql
+
+                #[allow(clippy::needless_question_mark)]
+                #[automatically_derived]
+                impl #impl_generics_a ::strid::__private::postgres_types::FromSql<'a> for #name #where_clause {
+                    fn from_sql(
+                        ty: &::strid::__private::postgres_types::Type,
+                        raw: &'a [u8],
+                    ) -> ::std::result::Result<
+                        Self,
+                        ::std::boxed::Box<dyn ::std::error::Error + ::core::marker::Sync + ::core::marker::Send>,
+                    > {
+                        let raw = <&'a #unsized_ty as ::strid::__private::postgres_types::FromSql<'a>>::from_sql(ty, raw)?;
+                        ::std::result::Result::Ok(Self::new(#to_field)#handle_failure)
                     }
+
+                    #accepts
                 }
             }
         })
@@ -313,14 +891,44 @@ impl ToImpl for ImplRusqlite {
 
     fn to_borrowed_impl(&self, cg: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
         self.0.map(|| {
-            let ty = &cg.ty;
+            let ty = cg.ref_type();
+            let impl_generics_a = cg.generics.impl_generics_with(quote!('a));
+            let where_clause = cg.generics.where_clause();
+            let check_mode = cg.check_mode;
+            let handle_failure = check_mode.boxed_err_handler();
+
+            let unsized_ty = cg.slice.unsized_ty();
+
+            // `to_sql_checked!` needs a sized type, so the impl is on the reference, like `&str`
+            let to_sql =
+                Self::to_sql(&quote! { &'a #ty }, &impl_generics_a, &where_clause, cg.slice);
+            let accepts = Self::accepts(cg.slice);
+
+            // A borrowed value can't be normalized, so it must already be in normalized form
+            let from_raw = if matches!(check_mode, CheckMode::Normalize(_)) {
+                cg.slice.method("from_normalized_", "")
+            } else {
+                cg.slice.method("from_", "")
+            };
 
             quote! {
+                #to_sql
+
+                #[allow(clippy::needless_question_mark)]
                 #[automatically_derived]
-                impl ::rusqlite::types::ToSql for #ty {
-                    fn to_sql(&self) -> ::rusqlite::Result<::rusqlite::types::ToSqlOutput<'_>> {
-                        self.as_str().to_sql()
+                impl #impl_generics_a ::strid::__private::postgres_types::FromSql<'a> for &'a #ty #where_clause {
+                    fn from_sql(
+                        ty: &::strid::__private::postgres_types::Type,
+                        raw: &'a [u8],
+                    ) -> ::std::result::Result<
+                        Self,
+                        ::std::boxed::Box<dyn ::std::error::Error + ::core::marker::Sync + ::core::marker::Send>,
+                    > {
+                        let raw = <&'a #unsized_ty as ::strid::__private::postgres_types::FromSql<'a>>::from_sql(ty, raw)?;
+                        ::std::result::Result::Ok(<#ty>::#from_raw(raw)#handle_failure)
                     }
+
+                    #accepts
                 }
             }
         })
@@ -345,13 +953,15 @@ impl From<ImplOption> for ImplSailfish {
 impl ToImpl for ImplSailfish {
     fn to_owned_impl(&self, cg: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
         self.0.map(|| {
-            let name = cg.ty;
+            let name = cg.owned_type();
+            let impl_generics = cg.generics.impl_generics();
+            let where_clause = cg.generics.where_clause();
 
             quote! {
                 #[automatically_derived]
-                impl ::sailfish::runtime::Render for #name {
+                impl #impl_generics ::strid::__private::sailfish::runtime::Render for #name #where_clause {
                     #[inline]
-                    fn render(&self, b: &mut ::sailfish::runtime::Buffer) -> ::std::result::Result<(), ::sailfish::runtime::RenderError> {
+                    fn render(&self, b: &mut ::strid::__private::sailfish::runtime::Buffer) -> ::std::result::Result<(), ::strid::__private::sailfish::runtime::RenderError> {
                         b.push_str(self.as_str());
                         ::std::result::Result::Ok(())
                     }
@@ -362,13 +972,15 @@ impl ToImpl for ImplSailfish {
 
     fn to_borrowed_impl(&self, cg: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
         self.0.map(|| {
-            let ty = &cg.ty;
+            let ty = cg.ref_type();
+            let impl_generics = cg.generics.impl_generics();
+            let where_clause = cg.generics.where_clause();
 
             quote! {
                 #[automatically_derived]
-                impl ::sailfish::runtime::Render for #ty {
+                impl #impl_generics ::strid::__private::sailfish::runtime::Render for #ty #where_clause {
                     #[inline]
-                    fn render(&self, b: &mut ::sailfish::runtime::Buffer) -> ::std::result::Result<(), ::sailfish::runtime::RenderError> {
+                    fn render(&self, b: &mut ::strid::__private::sailfish::runtime::Buffer) -> ::std::result::Result<(), ::strid::__private::sailfish::runtime::RenderError> {
                         b.push_str(self.as_str());
                         ::std::result::Result::Ok(())
                     }
@@ -379,85 +991,779 @@ impl ToImpl for ImplSailfish {
 }
 
 #[derive(Debug)]
-pub struct ImplSerde(ImplOption);
+pub struct ImplSqlx(ImplOption);
 
-impl Default for ImplSerde {
+impl Default for ImplSqlx {
     fn default() -> Self {
         Self(ImplOption::Omit)
     }
 }
 
-impl From<ImplOption> for ImplSerde {
+impl From<ImplOption> for ImplSqlx {
     fn from(opt: ImplOption) -> Self {
         Self(opt)
     }
 }
 
-impl ToImpl for ImplSerde {
+impl ToImpl for ImplSqlx {
     fn to_owned_impl(&self, cg: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
         self.0.map(|| {
-            let handle_failure = cg.check_mode.serde_err_handler();
-
-            let name = cg.ty;
+            let name = cg.owned_type();
             let field_name = &cg.field.name;
             let wrapped_type = &cg.field.ty;
+            let handle_failure = cg.check_mode.sqlx_err_handler();
+            let db = quote! { DB: ::strid::__private::sqlx::Database };
+
+            let type_generics = cg.generics.impl_generics_around(quote!(), db.clone());
+            let type_where = cg.generics.where_clause_with(
+                quote! { #wrapped_type: ::strid::__private::sqlx::Type<DB> },
+            );
+            let encode_generics = cg.generics.impl_generics_around(quote!('q), db.clone());
+            let encode_where = cg.generics.where_clause_with(
+                quote! { #wrapped_type: ::strid::__private::sqlx::Encode<'q, DB> },
+            );
+            let decode_generics = cg.generics.impl_generics_around(quote!('r), db);
+            let decode_where = cg.generics.where_clause_with(
+                quote! { #wrapped_type: ::strid::__private::sqlx::Decode<'r, DB> },
+            );
 
             quote! {
                 #[automatically_derived]
-                impl ::serde::Serialize for #name {
-                    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-                        <#wrapped_type as ::serde::Serialize>::serialize(&self.#field_name, serializer)
+                impl #type_generics ::strid::__private::sqlx::Type<DB> for #name #type_where {
+                    fn type_info() -> <DB as ::strid::__private::sqlx::Database>::TypeInfo {
+                        <#wrapped_type as ::strid::__private::sqlx::Type<DB>>::type_info()
+                    }
+
+                    fn compatible(ty: &<DB as ::strid::__private::sqlx::Database>::TypeInfo) -> bool {
+                        <#wrapped_type as ::strid::__private::sqlx::Type<DB>>::compatible(ty)
                     }
                 }
 
-                #[allow(clippy::needless_question_mark, clippy::unsafe_derive_deserialize)]
                 #[automatically_derived]
-                impl<'de> ::serde::Deserialize<'de> for #name {
-                    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-                        let raw = <#wrapped_type as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
-                        Ok(Self::new(raw)#handle_failure)
+                impl #encode_generics ::strid::__private::sqlx::Encode<'q, DB> for #name #encode_where {
+                    fn encode_by_ref(
+                        &self,
+                        buf: &mut <DB as ::strid::__private::sqlx::Database>::ArgumentBuffer,
+                    ) -> ::std::result::Result<::strid::__private::sqlx::encode::IsNull, ::strid::__private::sqlx::error::BoxDynError> {
+                        <#wrapped_type as ::strid::__private::sqlx::Encode<'q, DB>>::encode_by_ref(&self.#field_name, buf)
                     }
-                }
-            }
+
+                    fn produces(&self) -> ::std::option::Option<<DB as ::strid::__private::sqlx::Database>::TypeInfo> {
+                        <#wrapped_type as ::strid::__private::sqlx::Encode<'q, DB>>::produces(&self.#field_name)
+                    }
+
+                    fn size_hint(&self) -> usize {
+                        <#wrapped_type as ::strid::__private::sqlx::Encode<'q, DB>>::size_hint(&self.#field_name)
+                    }
+                }
+
+                #[allow(clippy::needless_question_mark)]
+                #[automatically_derived]
+                impl #decode_generics ::strid::__private::sqlx::Decode<'r, DB> for #name #decode_where {
+                    fn decode(
+                        value: <DB as ::strid::__private::sqlx::Database>::ValueRef<'r>,
+                    ) -> ::std::result::Result<Self, ::strid::__private::sqlx::error::BoxDynError> {
+                        let raw = <#wrapped_type as ::strid::__private::sqlx::Decode<'r, DB>>::decode(value)?;
+                        ::std::result::Result::Ok(Self::new(raw)#handle_failure)
+                    }
+                }
+            }
+        })
+    }
+
+    fn to_borrowed_impl(&self, cg: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            let ty = cg.ref_type();
+            let unsized_ty = cg.slice.unsized_ty();
+            let as_raw = cg.slice.method("as_", "");
+            let check_mode = cg.check_mode;
+            let handle_failure = check_mode.sqlx_err_handler();
+            let db = quote! { DB: ::strid::__private::sqlx::Database };
+
+            let type_generics = cg.generics.impl_generics_around(quote!(), db.clone());
+            let type_where = cg
+                .generics
+                .where_clause_with(quote! { #unsized_ty: ::strid::__private::sqlx::Type<DB> });
+            let encode_generics = cg.generics.impl_generics_around(quote!('q), db.clone());
+            let encode_where = cg.generics.where_clause_with(
+                quote! { for<'s> &'s #unsized_ty: ::strid::__private::sqlx::Encode<'q, DB> },
+            );
+            let decode_generics = cg.generics.impl_generics_around(quote!('r), db);
+            let decode_where = cg.generics.where_clause_with(
+                quote! { &'r #unsized_ty: ::strid::__private::sqlx::Decode<'r, DB> },
+            );
+
+            // A borrowed value can't be normalized, so it must already be in normalized form
+            let from_raw = if matches!(check_mode, CheckMode::Normalize(_)) {
+                cg.slice.method("from_normalized_", "")
+            } else {
+                cg.slice.method("from_", "")
+            };
+
+            quote! {
+                #[automatically_derived]
+                impl #type_generics ::strid::__private::sqlx::Type<DB> for #ty #type_where {
+                    fn type_info() -> <DB as ::strid::__private::sqlx::Database>::TypeInfo {
+                        <#unsized_ty as ::strid::__private::sqlx::Type<DB>>::type_info()
+                    }
+
+                    fn compatible(ty: &<DB as ::strid::__private::sqlx::Database>::TypeInfo) -> bool {
+                        <#unsized_ty as ::strid::__private::sqlx::Type<DB>>::compatible(ty)
+                    }
+                }
+
+                #[automatically_derived]
+                impl #encode_generics ::strid::__private::sqlx::Encode<'q, DB> for &#ty #encode_where {
+                    fn encode_by_ref(
+                        &self,
+                        buf: &mut <DB as ::strid::__private::sqlx::Database>::ArgumentBuffer,
+                    ) -> ::std::result::Result<::strid::__private::sqlx::encode::IsNull, ::strid::__private::sqlx::error::BoxDynError> {
+                        <&#unsized_ty as ::strid::__private::sqlx::Encode<'q, DB>>::encode_by_ref(&self.#as_raw(), buf)
+                    }
+
+                    fn produces(&self) -> ::std::option::Option<<DB as ::strid::__private::sqlx::Database>::TypeInfo> {
+                        <&#unsized_ty as ::strid::__private::sqlx::Encode<'q, DB>>::produces(&self.#as_raw())
+                    }
+
+                    fn size_hint(&self) -> usize {
+                        <&#unsized_ty as ::strid::__private::sqlx::Encode<'q, DB>>::size_hint(&self.#as_raw())
+                    }
+                }
+
+                #[allow(clippy::needless_question_mark)]
+                #[automatically_derived]
+                impl #decode_generics ::strid::__private::sqlx::Decode<'r, DB> for &'r #ty #decode_where {
+                    fn decode(
+                        value: <DB as ::strid::__private::sqlx::Database>::ValueRef<'r>,
+                    ) -> ::std::result::Result<Self, ::strid::__private::sqlx::error::BoxDynError> {
+                        let raw = <&'r #unsized_ty as ::strid::__private::sqlx::Decode<'r, DB>>::decode(value)?;
+                        ::std::result::Result::Ok(<#ty>::#from_raw(raw)#handle_failure)
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ImplSchemars(ImplOption);
+
+impl Default for ImplSchemars {
+    fn default() -> Self {
+        Self(ImplOption::Omit)
+    }
+}
+
+impl From<ImplOption> for ImplSchemars {
+    fn from(opt: ImplOption) -> Self {
+        Self(opt)
+    }
+}
+
+impl ImplSchemars {
+    /// The schema is named after the braid alone, so every instantiation of a generic braid
+    /// shares a single definition. Its parameters are only markers, which don't change the
+    /// values that the schema describes.
+    fn json_schema(
+        ty: &proc_macro2::TokenStream,
+        name: &str,
+        doc: &[proc_macro2::TokenTree],
+        constraints: Option<&crate::grammar::Type>,
+        generics: &Generics,
+    ) -> proc_macro2::TokenStream {
+        let impl_generics = generics.impl_generics();
+        let where_clause = generics.where_clause();
+        let constraints = constraints
+            .map(|c| c.to_token_stream())
+            .unwrap_or_else(|| quote! { ::strid::__private::Unconstrained });
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::strid::__private::schemars::JsonSchema for #ty #where_clause {
+                fn schema_name() -> ::std::borrow::Cow<'static, str> {
+                    ::std::borrow::Cow::Borrowed(#name)
+                }
+
+                fn schema_id() -> ::std::borrow::Cow<'static, str> {
+                    ::std::borrow::Cow::Borrowed(::core::concat!(::core::module_path!(), "::", #name))
+                }
+
+                fn json_schema(
+                    _: &mut ::strid::__private::schemars::SchemaGenerator,
+                ) -> ::strid::__private::schemars::Schema {
+                    ::strid::__private::string_schema::<#constraints>(::core::concat!(#(#doc, "\n",)*))
+                }
+            }
+        }
+    }
+}
+
+impl ToImpl for ImplSchemars {
+    fn to_owned_impl(&self, cg: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            let name = cg.ty.to_string();
+            let doc = doc_comments(cg.common_attrs);
+            Self::json_schema(&cg.owned_type(), &name, &doc, cg.constraints, cg.generics)
+        })
+    }
+
+    fn to_borrowed_impl(&self, cg: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            let name = cg.ident.to_string();
+            let doc = borrowed_doc_comments(cg);
+            Self::json_schema(&cg.ref_type(), &name, &doc, cg.constraints, cg.generics)
+        })
+    }
+}
+
+/// The string literals of the doc comments among `attrs`.
+fn doc_comments(attrs: &[crate::grammar::Attribute]) -> Vec<proc_macro2::TokenTree> {
+    attrs
+        .iter()
+        .filter_map(|attr| match attr.body.content.as_slice() {
+            [
+                proc_macro2::TokenTree::Ident(name),
+                proc_macro2::TokenTree::Punct(eq),
+                lit @ proc_macro2::TokenTree::Literal(_),
+            ] if name == "doc" && eq.as_char() == '=' => Some(lit.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The doc comments describing the borrowed type in a schema.
+///
+/// Without a `ref_doc`, the borrowed form is described the same way as the owned form.
+fn borrowed_doc_comments(cg: &RefCodeGen) -> Vec<proc_macro2::TokenTree> {
+    if cg.doc.is_empty() {
+        doc_comments(cg.common_attrs)
+    } else {
+        cg.doc
+            .iter()
+            .cloned()
+            .map(proc_macro2::TokenTree::Literal)
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct ImplUtoipa(ImplOption);
+
+impl Default for ImplUtoipa {
+    fn default() -> Self {
+        Self(ImplOption::Omit)
+    }
+}
+
+impl From<ImplOption> for ImplUtoipa {
+    fn from(opt: ImplOption) -> Self {
+        Self(opt)
+    }
+}
+
+impl ImplUtoipa {
+    fn to_schema(
+        ty: &proc_macro2::TokenStream,
+        name: &str,
+        doc: &[proc_macro2::TokenTree],
+        constraints: Option<&crate::grammar::Type>,
+        generics: &Generics,
+    ) -> proc_macro2::TokenStream {
+        let impl_generics = generics.impl_generics();
+        let where_clause = generics.where_clause();
+        let constraints = constraints
+            .map(|c| c.to_token_stream())
+            .unwrap_or_else(|| quote! { ::strid::__private::Unconstrained });
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::strid::__private::utoipa::PartialSchema for #ty #where_clause {
+                fn schema() -> ::strid::__private::utoipa::openapi::RefOr<::strid::__private::utoipa::openapi::schema::Schema> {
+                    ::strid::__private::openapi_schema::<#constraints>(::core::concat!(#(#doc, "\n",)*))
+                }
+            }
+
+            #[automatically_derived]
+            impl #impl_generics ::strid::__private::utoipa::ToSchema for #ty #where_clause {
+                fn name() -> ::std::borrow::Cow<'static, str> {
+                    ::std::borrow::Cow::Borrowed(#name)
+                }
+            }
+        }
+    }
+}
+
+impl ToImpl for ImplUtoipa {
+    fn to_owned_impl(&self, cg: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            let name = cg.ty.to_string();
+            let doc = doc_comments(cg.common_attrs);
+            Self::to_schema(&cg.owned_type(), &name, &doc, cg.constraints, cg.generics)
+        })
+    }
+
+    fn to_borrowed_impl(&self, cg: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            let name = cg.ident.to_string();
+            let doc = borrowed_doc_comments(cg);
+            Self::to_schema(&cg.ref_type(), &name, &doc, cg.constraints, cg.generics)
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ImplTsRs(ImplOption);
+
+impl Default for ImplTsRs {
+    fn default() -> Self {
+        Self(ImplOption::Omit)
+    }
+}
+
+impl From<ImplOption> for ImplTsRs {
+    fn from(opt: ImplOption) -> Self {
+        Self(opt)
+    }
+}
+
+impl ImplTsRs {
+    /// Declares `ty` as a TypeScript string branded with `name`, so that the owned and borrowed
+    /// forms of a braid share a single declaration
+    fn to_ts(
+        ty: &proc_macro2::TokenStream,
+        name: &str,
+        doc: &[proc_macro2::TokenTree],
+        generics: &Generics,
+    ) -> proc_macro2::TokenStream {
+        let impl_generics = generics.impl_generics();
+        let where_clause = generics.where_clause();
+        let inline = format!("string & {{ readonly __brand: {name:?} }}");
+        let decl = format!("type {name} = {inline};");
+        let path = format!("{name}.ts");
+        let docs = (!doc.is_empty()).then(|| {
+            quote! {
+                fn docs() -> ::core::option::Option<::std::string::String> {
+                    ::core::option::Option::Some(::strid::__private::ts_rs::format_docs(&[#(#doc),*]))
+                }
+            }
+        });
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::strid::__private::ts_rs::TS for #ty #where_clause {
+                type WithoutGenerics = Self;
+                type OptionInnerType = Self;
+
+                #docs
+
+                fn ident() -> ::std::string::String {
+                    ::std::string::String::from(#name)
+                }
+
+                fn name() -> ::std::string::String {
+                    ::std::string::String::from(#name)
+                }
+
+                fn inline() -> ::std::string::String {
+                    ::std::string::String::from(#inline)
+                }
+
+                fn inline_flattened() -> ::std::string::String {
+                    ::core::panic!("{} cannot be flattened", #name)
+                }
+
+                fn decl() -> ::std::string::String {
+                    ::std::string::String::from(#decl)
+                }
+
+                fn decl_concrete() -> ::std::string::String {
+                    ::std::string::String::from(#decl)
+                }
+
+                fn output_path() -> ::core::option::Option<::std::path::PathBuf> {
+                    ::core::option::Option::Some(::std::path::PathBuf::from(#path))
+                }
+            }
+        }
+    }
+}
+
+impl ToImpl for ImplTsRs {
+    fn to_owned_impl(&self, cg: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            let name = cg.ty.to_string();
+            let doc = doc_comments(cg.common_attrs);
+            Self::to_ts(&cg.owned_type(), &name, &doc, cg.generics)
+        })
+    }
+
+    fn to_borrowed_impl(&self, cg: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| match cg.owned_ty {
+            // The borrowed form of a braid is declared as the same type as the owned form
+            Some(owned_ty) => {
+                let name = owned_ty.to_string();
+                let doc = doc_comments(cg.common_attrs);
+                Self::to_ts(&cg.ref_type(), &name, &doc, cg.generics)
+            }
+            None => {
+                let name = cg.ident.to_string();
+                let doc = borrowed_doc_comments(cg);
+                Self::to_ts(&cg.ref_type(), &name, &doc, cg.generics)
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ImplProptest {
+    pub option: ImplOption,
+    pub strategy: Option<proc_macro2::TokenStream>,
+}
+
+impl Default for ImplProptest {
+    fn default() -> Self {
+        Self {
+            option: ImplOption::Omit,
+            strategy: None,
+        }
+    }
+}
+
+impl ToImpl for ImplProptest {
+    fn to_owned_impl(&self, cg: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.option.map(|| {
+            let ty = cg.owned_type();
+            let alloc = cg.std_lib.alloc();
+            let core = cg.std_lib.core();
+            let impl_generics = cg.generics.impl_generics();
+            // Boxing the strategy requires the braid to outlive it
+            let where_clause = cg.generics.where_clause_with(quote! { Self: 'static });
+            let rejected = format!("not a valid `{}`", cg.ty);
+            let buf_ty = cg.slice.buf_ty(alloc);
+            let raw = match (&self.strategy, &cg.check_mode) {
+                (Some(strategy), _) => quote! { #strategy() },
+                (None, CheckMode::None) => quote! {
+                    ::strid::__private::proptest::arbitrary::any::<#buf_ty>()
+                },
+                (None, CheckMode::Validate(checker) | CheckMode::Normalize(checker)) => quote! {{
+                    use ::strid::__private::{ProptestAny as _, ProptestValidator as _};
+                    (&::strid::__private::ProptestRaw::<#checker, #buf_ty>(
+                        ::#core::marker::PhantomData,
+                    ))
+                    .raw_strategy()
+                }},
+            };
+            let parse = match cg.slice {
+                Slice::Str => quote! { <Self as ::#core::str::FromStr>::from_str(&raw).ok() },
+                _ => quote! { <Self as ::#core::convert::TryFrom<#buf_ty>>::try_from(raw).ok() },
+            };
+
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics ::strid::__private::proptest::arbitrary::Arbitrary for #ty #where_clause {
+                    type Parameters = ();
+                    type Strategy = ::strid::__private::proptest::strategy::BoxedStrategy<Self>;
+
+                    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+                        ::strid::__private::proptest::strategy::Strategy::boxed(
+                            ::strid::__private::proptest::strategy::Strategy::prop_filter_map(
+                                #raw,
+                                #rejected,
+                                |raw: #buf_ty| #parse,
+                            ),
+                        )
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ImplQuickcheck {
+    pub option: ImplOption,
+    pub generator: Option<proc_macro2::TokenStream>,
+}
+
+impl Default for ImplQuickcheck {
+    fn default() -> Self {
+        Self {
+            option: ImplOption::Omit,
+            generator: None,
+        }
+    }
+}
+
+impl ToImpl for ImplQuickcheck {
+    fn to_owned_impl(&self, cg: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.option.map(|| {
+            let ty = cg.owned_type();
+            let alloc = cg.std_lib.alloc();
+            let core = cg.std_lib.core();
+            let impl_generics = cg.generics.impl_generics();
+            // `Arbitrary` requires the braid to be `'static`
+            let where_clause = cg.generics.where_clause_with(quote! { Self: 'static });
+            let generator = match &self.generator {
+                Some(generator) => quote! { #generator },
+                None => quote! { <::#alloc::string::String as ::strid::__private::quickcheck::Arbitrary>::arbitrary },
+            };
+
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics ::strid::__private::quickcheck::Arbitrary for #ty #where_clause {
+                    fn arbitrary(g: &mut ::strid::__private::quickcheck::Gen) -> Self {
+                        ::strid::__private::quickcheck_arbitrary(g, #generator)
+                    }
+
+                    fn shrink(&self) -> ::#alloc::boxed::Box<dyn ::#core::iter::Iterator<Item = Self>> {
+                        ::strid::__private::quickcheck_shrink(self.as_str())
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ImplArbitrary(ImplOption);
+
+impl Default for ImplArbitrary {
+    fn default() -> Self {
+        Self(ImplOption::Omit)
+    }
+}
+
+impl From<ImplOption> for ImplArbitrary {
+    fn from(opt: ImplOption) -> Self {
+        Self(opt)
+    }
+}
+
+impl ToImpl for ImplArbitrary {
+    fn to_owned_impl(&self, cg: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            let ty = cg.owned_type();
+            let core = cg.std_lib.core();
+            let impl_generics = cg.generics.impl_generics_with(quote!('a));
+            let where_clause = cg.generics.where_clause();
+            let unsized_ty = cg.slice.unsized_ty();
+            let parse = match cg.slice {
+                Slice::Str => quote! { <Self as ::#core::str::FromStr>::from_str(raw) },
+                _ => quote! { <Self as ::#core::convert::TryFrom<&'a #unsized_ty>>::try_from(raw) },
+            };
+
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics ::strid::__private::arbitrary::Arbitrary<'a> for #ty #where_clause {
+                    fn arbitrary(
+                        u: &mut ::strid::__private::arbitrary::Unstructured<'a>,
+                    ) -> ::strid::__private::arbitrary::Result<Self> {
+                        let raw = <&'a #unsized_ty as ::strid::__private::arbitrary::Arbitrary<'a>>::arbitrary(u)?;
+                        #parse
+                            .map_err(|_| ::strid::__private::arbitrary::Error::IncorrectFormat)
+                    }
+
+                    fn arbitrary_take_rest(
+                        u: ::strid::__private::arbitrary::Unstructured<'a>,
+                    ) -> ::strid::__private::arbitrary::Result<Self> {
+                        let raw = <&'a #unsized_ty as ::strid::__private::arbitrary::Arbitrary<'a>>::arbitrary_take_rest(u)?;
+                        #parse
+                            .map_err(|_| ::strid::__private::arbitrary::Error::IncorrectFormat)
+                    }
+
+                    #[inline]
+                    fn size_hint(depth: usize) -> (usize, ::#core::option::Option<usize>) {
+                        <&'a #unsized_ty as ::strid::__private::arbitrary::Arbitrary<'a>>::size_hint(depth)
+                    }
+                }
+            }
         })
     }
 
     fn to_borrowed_impl(&self, cg: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
         self.0.map(|| {
-            let ty = &cg.ty;
+            let ty = cg.ref_type();
+            let core = cg.std_lib.core();
+            let check_mode = cg.check_mode;
+            let handle_failure = check_mode.arbitrary_err_handler();
+            let impl_generics = cg.generics.impl_generics_with(quote!('a));
+            let where_clause = cg.generics.where_clause();
+            let unsized_ty = cg.slice.unsized_ty();
+
+            // A borrowed value can't be normalized, so it must already be in normalized form
+            let from_raw = if matches!(check_mode, CheckMode::Normalize(_)) {
+                cg.slice.method("from_normalized_", "")
+            } else {
+                cg.slice.method("from_", "")
+            };
+
+            quote! {
+                #[allow(clippy::needless_question_mark)]
+                #[automatically_derived]
+                impl #impl_generics ::strid::__private::arbitrary::Arbitrary<'a> for &'a #ty #where_clause {
+                    fn arbitrary(
+                        u: &mut ::strid::__private::arbitrary::Unstructured<'a>,
+                    ) -> ::strid::__private::arbitrary::Result<Self> {
+                        let raw = <&'a #unsized_ty as ::strid::__private::arbitrary::Arbitrary<'a>>::arbitrary(u)?;
+                        ::#core::result::Result::Ok(<#ty>::#from_raw(raw)#handle_failure)
+                    }
+
+                    fn arbitrary_take_rest(
+                        u: ::strid::__private::arbitrary::Unstructured<'a>,
+                    ) -> ::strid::__private::arbitrary::Result<Self> {
+                        let raw = <&'a #unsized_ty as ::strid::__private::arbitrary::Arbitrary<'a>>::arbitrary_take_rest(u)?;
+                        ::#core::result::Result::Ok(<#ty>::#from_raw(raw)#handle_failure)
+                    }
+
+                    #[inline]
+                    fn size_hint(depth: usize) -> (usize, ::#core::option::Option<usize>) {
+                        <&'a #unsized_ty as ::strid::__private::arbitrary::Arbitrary<'a>>::size_hint(depth)
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ImplSerde(ImplOption);
+
+impl Default for ImplSerde {
+    fn default() -> Self {
+        Self(ImplOption::Omit)
+    }
+}
+
+impl From<ImplOption> for ImplSerde {
+    fn from(opt: ImplOption) -> Self {
+        Self(opt)
+    }
+}
+
+impl ImplSerde {
+    /// Serializes `self` by way of its raw value, which uses the serde data model's bytes for
+    /// byte slices, and serde's own representation of everything else
+    fn serialize_raw(slice: Slice) -> proc_macro2::TokenStream {
+        let as_raw = slice.method("as_", "");
+        let unsized_ty = slice.unsized_ty();
+
+        match slice {
+            Slice::Bytes => quote! {
+                ::strid::__private::serde::Serializer::serialize_bytes(serializer, self.#as_raw())
+            },
+            _ => quote! {
+                <#unsized_ty as ::strid::__private::serde::Serialize>::serialize(self.#as_raw(), serializer)
+            },
+        }
+    }
+}
+
+impl ToImpl for ImplSerde {
+    fn to_owned_impl(&self, cg: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            let handle_failure = cg.check_mode.serde_err_handler();
+
+            let name = cg.owned_type();
+            let impl_generics = cg.generics.impl_generics();
+            let impl_generics_de = cg.generics.impl_generics_with(quote!('de));
+            let where_clause = cg.generics.where_clause();
+            let field_name = &cg.field.name;
+            let wrapped_type = &cg.field.ty;
+
+            let core = cg.std_lib.core();
+            let buf_ty = cg.slice.buf_ty(cg.std_lib.alloc());
+
+            // Serde only implements its traits for `Arc` and `Rc` with its `rc` feature, so
+            // shared strings go through `str` and `String` instead. Other kinds of braid go
+            // through their raw value too.
+            let (serialize, deserialize) = match (cg.slice, cg.field.shared()) {
+                (Slice::Str, None) => (
+                    quote! { <#wrapped_type as ::strid::__private::serde::Serialize>::serialize(&self.#field_name, serializer) },
+                    quote! { let raw = <#wrapped_type as ::strid::__private::serde::Deserialize<'de>>::deserialize(deserializer)?; },
+                ),
+                (Slice::Bytes, _) => (
+                    Self::serialize_raw(cg.slice),
+                    quote! {
+                        let raw = ::strid::__private::deserialize_byte_buf(deserializer)?;
+                        let raw = ::#core::convert::From::from(raw);
+                    },
+                ),
+                _ => (
+                    Self::serialize_raw(cg.slice),
+                    quote! {
+                        let raw = <#buf_ty as ::strid::__private::serde::Deserialize<'de>>::deserialize(deserializer)?;
+                        let raw = ::#core::convert::From::from(raw);
+                    },
+                ),
+            };
+
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics ::strid::__private::serde::Serialize for #name #where_clause {
+                    fn serialize<S: ::strid::__private::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        #serialize
+                    }
+                }
+
+                #[allow(clippy::needless_question_mark, clippy::unsafe_derive_deserialize)]
+                #[automatically_derived]
+                impl #impl_generics_de ::strid::__private::serde::Deserialize<'de> for #name #where_clause {
+                    fn deserialize<D: ::strid::__private::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        #deserialize
+                        Ok(Self::new(raw)#handle_failure)
+                    }
+                }
+            }
+        })
+    }
+
+    fn to_borrowed_impl(&self, cg: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            let ty = cg.ref_type();
+            let impl_generics = cg.generics.impl_generics();
+            let impl_generics_de = cg.generics.impl_generics_with(quote!('de));
+            let impl_generics_de_a = cg.generics.impl_generics_with(quote!('de: 'a, 'a));
+            let where_clause = cg.generics.where_clause();
             let check_mode = cg.check_mode;
             let core = cg.std_lib.core();
             let alloc = cg.std_lib.alloc();
+            let unsized_ty = cg.slice.unsized_ty();
+            let serialize = Self::serialize_raw(cg.slice);
 
             let handle_failure = check_mode.serde_err_handler();
 
-            let deserialize_boxed = cg.owned_ty.map(|owned_ty| {
+            let deserialize_boxed = cg.owned_type().map(|owned_ty| {
                 quote! {
                     #[automatically_derived]
-                    impl<'de> ::serde::Deserialize<'de> for ::#alloc::boxed::Box<#ty> {
-                        fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> ::#core::result::Result<Self, D::Error> {
-                            let owned = <#owned_ty as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
+                    impl #impl_generics_de ::strid::__private::serde::Deserialize<'de> for ::#alloc::boxed::Box<#ty> #where_clause {
+                        fn deserialize<D: ::strid::__private::serde::Deserializer<'de>>(deserializer: D) -> ::#core::result::Result<Self, D::Error> {
+                            let owned = <#owned_ty as ::strid::__private::serde::Deserialize<'de>>::deserialize(deserializer)?;
                             ::#core::result::Result::Ok(owned.into_boxed_ref())
                         }
                     }
                 }
             });
 
-            let deserialize = if matches!(check_mode, CheckMode::Normalize(_)) {
+            let deserialize = if matches!(cg.slice, Slice::OsStr | Slice::CStr) {
+                // Serde can't borrow an `OsStr`, which is serialized in a platform-specific form,
+                // or a `CStr`, which would have to be borrowed along with its nul terminator
+                None
+            } else if matches!(check_mode, CheckMode::Normalize(_)) {
                 let deserialize_doc = format!(
                     "Deserializes a `{ty}` in normalized form\n\
                     \n\
                     This deserializer _requires_ that the value already be in normalized form. \
                     If values may require normalization, then deserialized as [`{owned}`] or \
                     [`Cow<{ty}>`][{alloc}::borrow::Cow] instead.",
-                    ty = ty.to_token_stream(),
+                    ty = cg.ty.to_token_stream(),
                     owned = cg.owned_ty.expect("normalize not available if no owned").to_token_stream(),
                 );
+                let from_normalized_raw = cg.slice.method("from_normalized_", "");
 
-                quote! {
-                    // impl<'de: 'a, 'a> ::serde::Deserialize<'de> for ::#alloc::borrow::Cow<'a, #name> {
-                    //     fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> ::#core::result::Result<Self, D::Error> {
-                    //         let raw = <&str as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
+                Some(quote! {
+                    // impl<'de: 'a, 'a> ::strid::__private::serde::Deserialize<'de> for ::#alloc::borrow::Cow<'a, #name> {
+                    //     fn deserialize<D: ::strid::__private::serde::Deserializer<'de>>(deserializer: D) -> ::#core::result::Result<Self, D::Error> {
+                    //         let raw = <&str as ::strid::__private::serde::Deserialize<'de>>::deserialize(deserializer)?;
                     //         ::#core::result::Result::Ok(#name::from_str(raw)#handle_failure)
                     //     }
                     // }
@@ -465,31 +1771,32 @@ impl ToImpl for ImplSerde {
                     #[doc = #deserialize_doc]
                     #[allow(clippy::needless_question_mark, clippy::unsafe_derive_deserialize)]
                     #[automatically_derived]
-                    impl<'de: 'a, 'a> ::serde::Deserialize<'de> for &'a #ty {
-                        fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> ::#core::result::Result<Self, D::Error> {
-                            let raw = <&str as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
-                            ::#core::result::Result::Ok(#ty::from_normalized_str(raw)#handle_failure)
+                    impl #impl_generics_de_a ::strid::__private::serde::Deserialize<'de> for &'a #ty #where_clause {
+                        fn deserialize<D: ::strid::__private::serde::Deserializer<'de>>(deserializer: D) -> ::#core::result::Result<Self, D::Error> {
+                            let raw = <&#unsized_ty as ::strid::__private::serde::Deserialize<'de>>::deserialize(deserializer)?;
+                            ::#core::result::Result::Ok(<#ty>::#from_normalized_raw(raw)#handle_failure)
                         }
                     }
-                }
+                })
             } else {
-                quote! {
+                let from_raw = cg.slice.method("from_", "");
+                Some(quote! {
                     #[allow(clippy::needless_question_mark, clippy::unsafe_derive_deserialize)]
                     #[automatically_derived]
-                    impl<'de: 'a, 'a> ::serde::Deserialize<'de> for &'a #ty {
-                        fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> ::#core::result::Result<Self, D::Error> {
-                            let raw = <&str as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
-                            ::#core::result::Result::Ok(#ty::from_str(raw)#handle_failure)
+                    impl #impl_generics_de_a ::strid::__private::serde::Deserialize<'de> for &'a #ty #where_clause {
+                        fn deserialize<D: ::strid::__private::serde::Deserializer<'de>>(deserializer: D) -> ::#core::result::Result<Self, D::Error> {
+                            let raw = <&#unsized_ty as ::strid::__private::serde::Deserialize<'de>>::deserialize(deserializer)?;
+                            ::#core::result::Result::Ok(<#ty>::#from_raw(raw)#handle_failure)
                         }
                     }
-                }
+                })
             };
 
             quote! {
                 #[automatically_derived]
-                impl ::serde::Serialize for #ty {
-                    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> ::#core::result::Result<S::Ok, S::Error> {
-                        <str as ::serde::Serialize>::serialize(self.as_str(), serializer)
+                impl #impl_generics ::strid::__private::serde::Serialize for #ty #where_clause {
+                    fn serialize<S: ::strid::__private::serde::Serializer>(&self, serializer: S) -> ::#core::result::Result<S::Ok, S::Error> {
+                        #serialize
                     }
                 }
 
@@ -1,21 +1,34 @@
 use proc_macro2::Literal;
 use quote::{ToTokens, TokenStreamExt};
-use symbol::{parse_lit_into_string, parse_lit_into_type};
+use symbol::{
+    parse_list_into_attrs, parse_lit_into_option, parse_lit_into_pattern, parse_lit_into_type,
+};
 use unsynn::{IParse, ToTokenIter};
 
-pub use self::{borrowed::RefCodeGen, owned::OwnedCodeGen};
+pub use self::{borrowed::RefCodeGen, owned::OwnedCodeGen, slice::Slice};
 use self::{
-    check_mode::{CheckMode, IndefiniteCheckMode},
+    case::Case,
+    check_mode::{CheckMode, IndefiniteCheckMode, infer_constraints_if_missing},
+    generics::Generics,
     impls::{DelegatingImplOption, ImplOption, Impls},
+    pattern::Pattern,
+};
+use crate::{
+    attr_grammar::{AttrArg, AttrArgs},
+    error::{Error, Errors, Result},
 };
 
 mod borrowed;
+mod case;
 mod check_mode;
+mod generics;
 mod impls;
 mod owned;
+mod pattern;
+mod slice;
 mod symbol;
 
-pub type AttrList = Vec<crate::attr_grammar::AttrArg>;
+pub type AttrList = Vec<crate::grammar::Attribute>;
 
 #[derive(Clone, Debug)]
 pub struct StdLib {
@@ -50,184 +63,238 @@ impl Default for StdLib {
 }
 
 pub struct Params {
+    slice: Slice,
     ref_ty: Option<crate::grammar::Type>,
     ref_doc: Vec<Literal>,
     ref_attrs: AttrList,
     owned_attrs: AttrList,
     std_lib: StdLib,
     check_mode: IndefiniteCheckMode,
+    const_validator: Option<crate::grammar::Type>,
+    pattern: Option<Literal>,
+    constraints: Option<Option<crate::grammar::Type>>,
+    case: Case,
     expose_inner: bool,
     impls: Impls,
 }
 
-impl Default for Params {
-    fn default() -> Self {
+impl Params {
+    fn new(slice: Slice) -> Self {
         Self {
+            slice,
             ref_ty: None,
             ref_doc: Vec::new(),
             ref_attrs: AttrList::new(),
             owned_attrs: AttrList::new(),
             std_lib: StdLib::default(),
             check_mode: IndefiniteCheckMode::None,
+            const_validator: None,
+            pattern: None,
+            constraints: None,
+            case: Case::default(),
             expose_inner: true,
-            impls: Impls::default(),
+            impls: Impls::new(slice),
         }
     }
-}
 
-impl Params {
-    pub fn from_args(args: crate::attr_grammar::AttrArgs) -> Result<Self, String> {
-        let mut params = Self::default();
-
-        for delim in args.args.iter() {
-            let arg = &delim.value;
-            let name = arg.name();
-
-            if name == symbol::REF {
-                if let Some(lit) = arg.value() {
-                    let type_str = parse_lit_into_string(symbol::REF, lit)?;
-                    params.ref_ty = Some(parse_lit_into_type(symbol::REF, &type_str)?);
-                } else {
-                    return Err("expected ref_name = \"TypeName\"".to_string());
-                }
-            } else if name == symbol::VALIDATOR {
-                let validator = if let Some(lit) = arg.value() {
-                    let type_str = parse_lit_into_string(symbol::VALIDATOR, lit)?;
-                    Some(parse_lit_into_type(symbol::VALIDATOR, &type_str)?)
-                } else {
-                    None
-                };
-                params.check_mode.try_set_validator(validator)?;
-            } else if name == symbol::NORMALIZER {
-                let normalizer = if let Some(lit) = arg.value() {
-                    let type_str = parse_lit_into_string(symbol::NORMALIZER, lit)?;
-                    Some(parse_lit_into_type(symbol::NORMALIZER, &type_str)?)
-                } else {
-                    None
-                };
-                params.check_mode.try_set_normalizer(normalizer)?;
-            } else if name == symbol::REF_DOC {
-                if let Some(lit) = arg.value() {
-                    params.ref_doc.push(lit.clone());
-                } else {
-                    return Err("expected ref_doc = \"doc comment\"".to_string());
-                }
-            } else if name == symbol::REF_ATTR {
-                // Store the raw list contents for now
-                // In a real implementation, you'd parse these properly
-                if let Some(_contents) = arg.list_contents() {
-                    // For now, we'll skip storing ref_attrs
-                    // A full implementation would parse these
-                }
-            } else if name == symbol::OWNED_ATTR {
-                if let Some(_contents) = arg.list_contents() {
-                    // For now, we'll skip storing owned_attrs
-                }
-            } else if name == symbol::DEBUG {
-                if let Some(lit) = arg.value() {
-                    params.impls.debug = parse_lit_into_string(symbol::DEBUG, lit)?
-                        .parse::<DelegatingImplOption>()
-                        .map_err(|e| e.to_string())?
-                        .into();
-                } else {
-                    return Err("expected debug = \"impl|owned|omit\"".to_string());
-                }
-            } else if name == symbol::DISPLAY {
-                if let Some(lit) = arg.value() {
-                    params.impls.display = parse_lit_into_string(symbol::DISPLAY, lit)?
-                        .parse::<DelegatingImplOption>()
-                        .map_err(|e| e.to_string())?
-                        .into();
-                } else {
-                    return Err("expected display = \"impl|owned|omit\"".to_string());
-                }
-            } else if name == symbol::ORD {
-                if let Some(lit) = arg.value() {
-                    params.impls.ord = parse_lit_into_string(symbol::ORD, lit)?
-                        .parse::<DelegatingImplOption>()
-                        .map_err(|e| e.to_string())?
-                        .into();
-                } else {
-                    return Err("expected ord = \"impl|owned|omit\"".to_string());
-                }
-            } else if name == symbol::CLONE {
-                if let Some(lit) = arg.value() {
-                    params.impls.clone = parse_lit_into_string(symbol::CLONE, lit)?
-                        .parse::<ImplOption>()
-                        .map_err(|e| e.to_string())?
-                        .into();
-                } else {
-                    return Err("expected clone = \"impl|omit\"".to_string());
-                }
-            } else if name == symbol::SERDE {
-                if let Some(lit) = arg.value() {
-                    params.impls.serde = parse_lit_into_string(symbol::SERDE, lit)?
-                        .parse::<ImplOption>()
-                        .map_err(|e| e.to_string())?
-                        .into();
-                } else {
-                    params.impls.serde = ImplOption::Implement.into();
-                }
-            } else if name == symbol::RUSQLITE {
-                if let Some(lit) = arg.value() {
-                    params.impls.rusqlite = parse_lit_into_string(symbol::RUSQLITE, lit)?
-                        .parse::<ImplOption>()
-                        .map_err(|e| e.to_string())?
-                        .into();
-                } else {
-                    params.impls.rusqlite = ImplOption::Implement.into();
-                }
-            } else if name == symbol::SAILFISH {
-                if let Some(lit) = arg.value() {
-                    params.impls.sailfish = parse_lit_into_string(symbol::SAILFISH, lit)?
-                        .parse::<ImplOption>()
-                        .map_err(|e| e.to_string())?
-                        .into();
-                } else {
-                    params.impls.sailfish = ImplOption::Implement.into();
-                }
-            } else if name == symbol::NO_STD {
-                params.std_lib = StdLib::no_std(proc_macro2::Span::call_site());
-            } else if name == symbol::NO_EXPOSE {
-                params.expose_inner = false;
-            } else {
-                return Err(format!("unsupported argument `{}`", name));
-            }
+    pub fn from_args(args: AttrArgs, slice: Slice) -> Result<Self> {
+        let mut params = Self::new(slice);
+        let mut errors = Errors::default();
+
+        for arg in &args.args {
+            errors.handle(params.apply_arg(arg));
         }
+        errors.handle(apply_pattern(
+            &mut params.check_mode,
+            params.pattern.as_ref(),
+        ));
+        errors.handle(check_const_validator(
+            &params.check_mode,
+            params.const_validator.as_ref(),
+        ));
+        errors.handle(check_generator(
+            params.impls.proptest.strategy.as_ref(),
+            params.impls.proptest.option,
+            symbol::PROPTEST_STRATEGY,
+            symbol::PROPTEST,
+        ));
+        errors.handle(check_generator(
+            params.impls.quickcheck.generator.as_ref(),
+            params.impls.quickcheck.option,
+            symbol::QUICKCHECK_GENERATOR,
+            symbol::QUICKCHECK,
+        ));
 
+        errors.finish()?;
         Ok(params)
     }
+
+    fn apply_arg(&mut self, arg: &AttrArg) -> Result<()> {
+        let name = arg.name();
+
+        if !self.slice.supports(name) {
+            return Err(self.slice.unsupported(name));
+        }
+
+        if name == symbol::REF {
+            let lit = require_value(arg, "ref_name = \"TypeName\"")?;
+            self.ref_ty = Some(parse_lit_into_type(symbol::REF, lit)?);
+        } else if name == symbol::VALIDATOR {
+            let validator = arg
+                .value()
+                .map(|lit| parse_lit_into_type(symbol::VALIDATOR, lit))
+                .transpose()?;
+            self.check_mode
+                .try_set_validator(validator)
+                .map_err(|e| Error::new_spanned(arg, e))?;
+        } else if name == symbol::NORMALIZER {
+            let normalizer = arg
+                .value()
+                .map(|lit| parse_lit_into_type(symbol::NORMALIZER, lit))
+                .transpose()?;
+            self.check_mode
+                .try_set_normalizer(normalizer)
+                .map_err(|e| Error::new_spanned(arg, e))?;
+        } else if name == symbol::CONST_VALIDATOR {
+            let lit = require_value(arg, "const_validator = \"path::to::const_fn\"")?;
+            self.const_validator = Some(parse_lit_into_type(symbol::CONST_VALIDATOR, lit)?);
+        } else if name == symbol::PATTERN {
+            let lit = require_value(arg, "pattern = \"regex\"")?;
+            self.pattern = Some(parse_lit_into_pattern(symbol::PATTERN, lit)?);
+        } else if name == symbol::CONSTRAINTS {
+            self.constraints = Some(
+                arg.value()
+                    .map(|lit| parse_lit_into_type(symbol::CONSTRAINTS, lit))
+                    .transpose()?,
+            );
+        } else if name == symbol::REF_DOC {
+            let lit = require_value(arg, "ref_doc = \"doc comment\"")?;
+            self.ref_doc.push(lit.clone());
+        } else if name == symbol::REF_ATTR {
+            let contents = require_list(arg, "ref_attr(...)")?;
+            self.ref_attrs
+                .extend(parse_list_into_attrs(symbol::REF_ATTR, contents)?);
+        } else if name == symbol::OWNED_ATTR {
+            let contents = require_list(arg, "owned_attr(...)")?;
+            self.owned_attrs
+                .extend(parse_list_into_attrs(symbol::OWNED_ATTR, contents)?);
+        } else if name == symbol::DEBUG {
+            let lit = require_value(arg, "debug = \"impl|owned|omit\"")?;
+            self.impls.debug =
+                parse_lit_into_option::<DelegatingImplOption>(symbol::DEBUG, lit)?.into();
+        } else if name == symbol::DISPLAY {
+            let lit = require_value(arg, "display = \"impl|owned|omit\"")?;
+            self.impls.display =
+                parse_lit_into_option::<DelegatingImplOption>(symbol::DISPLAY, lit)?.into();
+        } else if name == symbol::ORD {
+            let lit = require_value(arg, "ord = \"impl|owned|omit\"")?;
+            self.impls.ord =
+                parse_lit_into_option::<DelegatingImplOption>(symbol::ORD, lit)?.into();
+        } else if name == symbol::CASE {
+            let lit = require_value(arg, "case = \"sensitive|ascii_insensitive|insensitive\"")?;
+            self.case = parse_case(self.slice, lit)?;
+        } else if name == symbol::FACET {
+            let lit = require_value(arg, "facet = \"impl|owned|omit\"")?;
+            self.impls.facet =
+                parse_lit_into_option::<DelegatingImplOption>(symbol::FACET, lit)?.into();
+        } else if name == symbol::CLONE {
+            let lit = require_value(arg, "clone = \"impl|omit\"")?;
+            self.impls.clone = parse_lit_into_option::<ImplOption>(symbol::CLONE, lit)?.into();
+        } else if name == symbol::SERDE {
+            self.impls.serde = parse_optional_impl_option(symbol::SERDE, arg)?.into();
+        } else if name == symbol::RUSQLITE {
+            self.impls.rusqlite = parse_optional_impl_option(symbol::RUSQLITE, arg)?.into();
+        } else if name == symbol::DIESEL {
+            self.impls.diesel = parse_optional_impl_option(symbol::DIESEL, arg)?.into();
+        } else if name == symbol::POSTGRES {
+            self.impls.postgres = parse_optional_impl_option(symbol::POSTGRES, arg)?.into();
+        } else if name == symbol::SAILFISH {
+            self.impls.sailfish = parse_optional_impl_option(symbol::SAILFISH, arg)?.into();
+        } else if name == symbol::SQLX {
+            self.impls.sqlx = parse_optional_impl_option(symbol::SQLX, arg)?.into();
+        } else if name == symbol::SCHEMARS {
+            self.impls.schemars = parse_optional_impl_option(symbol::SCHEMARS, arg)?.into();
+        } else if name == symbol::UTOIPA {
+            self.impls.utoipa = parse_optional_impl_option(symbol::UTOIPA, arg)?.into();
+        } else if name == symbol::TS_RS {
+            self.impls.ts_rs = parse_optional_impl_option(symbol::TS_RS, arg)?.into();
+        } else if name == symbol::ARBITRARY {
+            self.impls.arbitrary = parse_optional_impl_option(symbol::ARBITRARY, arg)?.into();
+        } else if name == symbol::PROPTEST {
+            self.impls.proptest.option = parse_optional_impl_option(symbol::PROPTEST, arg)?;
+        } else if name == symbol::PROPTEST_STRATEGY {
+            let lit = require_value(arg, "proptest_strategy = \"path::to::fn\"")?;
+            let strategy = parse_lit_into_type(symbol::PROPTEST_STRATEGY, lit)?;
+            self.impls.proptest.strategy = Some(strategy.to_token_stream());
+        } else if name == symbol::QUICKCHECK {
+            self.impls.quickcheck.option = parse_optional_impl_option(symbol::QUICKCHECK, arg)?;
+        } else if name == symbol::QUICKCHECK_GENERATOR {
+            let lit = require_value(arg, "quickcheck_generator = \"path::to::fn\"")?;
+            let generator = parse_lit_into_type(symbol::QUICKCHECK_GENERATOR, lit)?;
+            self.impls.quickcheck.generator = Some(generator.to_token_stream());
+        } else if name == symbol::NO_STD {
+            self.std_lib = StdLib::no_std(proc_macro2::Span::call_site());
+        } else if name == symbol::NO_EXPOSE {
+            self.expose_inner = false;
+        } else {
+            return Err(self.slice.unsupported(name));
+        }
+
+        Ok(())
+    }
 }
 
 impl Params {
-    pub fn build(self, mut body: crate::grammar::ItemStruct) -> Result<CodeGen, String> {
+    pub fn build(self, mut body: crate::grammar::ItemStruct) -> Result<CodeGen> {
         let Params {
+            slice,
             ref_ty,
             ref_doc,
             ref_attrs,
             owned_attrs,
             std_lib,
             check_mode,
+            const_validator,
+            pattern,
+            constraints,
+            case,
             expose_inner,
             impls,
         } = self;
 
-        create_field_if_none(&mut body.fields);
-        let (wrapped_type, field_ident, field_attrs) = get_field_info(&body.fields)?;
+        create_field_if_none(&mut body.fields, slice.default_field_ty());
+        let generics = Generics::from_item(&body)?;
+        let (wrapped_type, field_ident, field_attrs) = get_field_info(&body.ident, &body.fields)?;
         let owned_ty = &body.ident;
         let ref_ty = ref_ty.unwrap_or_else(|| infer_ref_type_from_owned_name(owned_ty));
-        let check_mode = check_mode.infer_validator_if_missing(owned_ty);
+        let (pattern, constraints) = resolve_pattern(pattern, constraints);
+        let check_mode = check_mode.infer_validator_if_missing(owned_ty, &generics);
+        check_facet_generics(
+            owned_ty,
+            &generics,
+            &check_mode,
+            &impls,
+            "`facet = \"owned\"` or `facet = \"omit\"`",
+        )?;
+        let constraints =
+            infer_constraints_if_missing(constraints, &check_mode, owned_ty, &generics);
         let field = Field {
             attrs: field_attrs.to_vec(),
             name: field_ident
                 .map(|i| FieldName::Named(i.clone()))
-                .unwrap_or(FieldName::Unnamed),
+                .unwrap_or(FieldName::Unnamed(0)),
             ty: wrapped_type.clone(),
+            marker: generics.marker_ty(),
         };
 
         Ok(CodeGen {
+            slice,
             check_mode,
+            const_validator,
+            pattern,
+            constraints,
             body,
+            generics,
             field,
 
             owned_attrs,
@@ -237,6 +304,7 @@ impl Params {
             ref_ty,
 
             std_lib,
+            case,
             expose_inner,
             impls,
         })
@@ -244,157 +312,363 @@ impl Params {
 }
 
 pub struct ParamsRef {
+    slice: Slice,
     std_lib: StdLib,
     check_mode: IndefiniteCheckMode,
+    const_validator: Option<crate::grammar::Type>,
+    pattern: Option<Literal>,
+    constraints: Option<Option<crate::grammar::Type>>,
+    case: Case,
     impls: Impls,
 }
 
-impl Default for ParamsRef {
-    fn default() -> Self {
+impl ParamsRef {
+    fn new(slice: Slice) -> Self {
         Self {
+            slice,
             std_lib: StdLib::default(),
             check_mode: IndefiniteCheckMode::None,
-            impls: Impls::default(),
+            const_validator: None,
+            pattern: None,
+            constraints: None,
+            case: Case::default(),
+            impls: Impls::new(slice),
         }
     }
-}
 
-impl ParamsRef {
-    pub fn from_args(args: crate::attr_grammar::AttrArgs) -> Result<Self, String> {
-        let mut params = Self::default();
-
-        for delim in args.args.iter() {
-            let arg = &delim.value;
-            let name = arg.name();
-
-            if name == symbol::VALIDATOR {
-                let validator = if let Some(lit) = arg.value() {
-                    let type_str = parse_lit_into_string(symbol::VALIDATOR, lit)?;
-                    Some(parse_lit_into_type(symbol::VALIDATOR, &type_str)?)
-                } else {
-                    None
-                };
-                params.check_mode.try_set_validator(validator)?;
-            } else if name == symbol::DEBUG {
-                if let Some(lit) = arg.value() {
-                    params.impls.debug = parse_lit_into_string(symbol::DEBUG, lit)?
-                        .parse::<ImplOption>()
-                        .map_err(|e| e.to_string())
-                        .map(DelegatingImplOption::from)?
-                        .into();
-                } else {
-                    return Err("expected debug = \"impl|omit\"".to_string());
-                }
-            } else if name == symbol::DISPLAY {
-                if let Some(lit) = arg.value() {
-                    params.impls.display = parse_lit_into_string(symbol::DISPLAY, lit)?
-                        .parse::<ImplOption>()
-                        .map_err(|e| e.to_string())
-                        .map(DelegatingImplOption::from)?
-                        .into();
-                } else {
-                    return Err("expected display = \"impl|omit\"".to_string());
-                }
-            } else if name == symbol::ORD {
-                if let Some(lit) = arg.value() {
-                    params.impls.ord = parse_lit_into_string(symbol::ORD, lit)?
-                        .parse::<ImplOption>()
-                        .map_err(|e| e.to_string())
-                        .map(DelegatingImplOption::from)?
-                        .into();
-                } else {
-                    return Err("expected ord = \"impl|omit\"".to_string());
-                }
-            } else if name == symbol::SERDE {
-                if let Some(lit) = arg.value() {
-                    params.impls.serde = parse_lit_into_string(symbol::SERDE, lit)?
-                        .parse::<ImplOption>()
-                        .map_err(|e| e.to_string())?
-                        .into();
-                } else {
-                    params.impls.serde = ImplOption::Implement.into();
-                }
-            } else if name == symbol::RUSQLITE {
-                if let Some(lit) = arg.value() {
-                    params.impls.rusqlite = parse_lit_into_string(symbol::RUSQLITE, lit)?
-                        .parse::<ImplOption>()
-                        .map_err(|e| e.to_string())?
-                        .into();
-                } else {
-                    params.impls.rusqlite = ImplOption::Implement.into();
-                }
-            } else if name == symbol::SAILFISH {
-                if let Some(lit) = arg.value() {
-                    params.impls.sailfish = parse_lit_into_string(symbol::SAILFISH, lit)?
-                        .parse::<ImplOption>()
-                        .map_err(|e| e.to_string())?
-                        .into();
-                } else {
-                    params.impls.sailfish = ImplOption::Implement.into();
-                }
-            } else if name == symbol::NO_STD {
-                params.std_lib = StdLib::no_std(proc_macro2::Span::call_site());
-            } else {
-                return Err(format!("unsupported argument `{}`", name));
-            }
+    pub fn from_args(args: AttrArgs, slice: Slice) -> Result<Self> {
+        let mut params = Self::new(slice);
+        let mut errors = Errors::default();
+
+        for arg in &args.args {
+            errors.handle(params.apply_arg(arg));
         }
+        errors.handle(apply_pattern(
+            &mut params.check_mode,
+            params.pattern.as_ref(),
+        ));
+        errors.handle(check_const_validator(
+            &params.check_mode,
+            params.const_validator.as_ref(),
+        ));
 
+        errors.finish()?;
         Ok(params)
     }
+
+    fn apply_arg(&mut self, arg: &AttrArg) -> Result<()> {
+        let name = arg.name();
+
+        if !self.slice.supports(name) {
+            return Err(self.slice.unsupported(name));
+        }
+
+        if name == symbol::VALIDATOR {
+            let validator = arg
+                .value()
+                .map(|lit| parse_lit_into_type(symbol::VALIDATOR, lit))
+                .transpose()?;
+            self.check_mode
+                .try_set_validator(validator)
+                .map_err(|e| Error::new_spanned(arg, e))?;
+        } else if name == symbol::CONST_VALIDATOR {
+            let lit = require_value(arg, "const_validator = \"path::to::const_fn\"")?;
+            self.const_validator = Some(parse_lit_into_type(symbol::CONST_VALIDATOR, lit)?);
+        } else if name == symbol::PATTERN {
+            let lit = require_value(arg, "pattern = \"regex\"")?;
+            self.pattern = Some(parse_lit_into_pattern(symbol::PATTERN, lit)?);
+        } else if name == symbol::CONSTRAINTS {
+            self.constraints = Some(
+                arg.value()
+                    .map(|lit| parse_lit_into_type(symbol::CONSTRAINTS, lit))
+                    .transpose()?,
+            );
+        } else if name == symbol::DEBUG {
+            let lit = require_value(arg, "debug = \"impl|omit\"")?;
+            self.impls.debug = DelegatingImplOption::from(parse_lit_into_option::<ImplOption>(
+                symbol::DEBUG,
+                lit,
+            )?)
+            .into();
+        } else if name == symbol::DISPLAY {
+            let lit = require_value(arg, "display = \"impl|omit\"")?;
+            self.impls.display = DelegatingImplOption::from(parse_lit_into_option::<ImplOption>(
+                symbol::DISPLAY,
+                lit,
+            )?)
+            .into();
+        } else if name == symbol::ORD {
+            let lit = require_value(arg, "ord = \"impl|omit\"")?;
+            self.impls.ord =
+                DelegatingImplOption::from(parse_lit_into_option::<ImplOption>(symbol::ORD, lit)?)
+                    .into();
+        } else if name == symbol::CASE {
+            let lit = require_value(arg, "case = \"sensitive|ascii_insensitive|insensitive\"")?;
+            self.case = parse_case(self.slice, lit)?;
+        } else if name == symbol::FACET {
+            let lit = require_value(arg, "facet = \"impl|omit\"")?;
+            self.impls.facet = DelegatingImplOption::from(parse_lit_into_option::<ImplOption>(
+                symbol::FACET,
+                lit,
+            )?)
+            .into();
+        } else if name == symbol::SERDE {
+            self.impls.serde = parse_optional_impl_option(symbol::SERDE, arg)?.into();
+        } else if name == symbol::RUSQLITE {
+            self.impls.rusqlite = parse_optional_impl_option(symbol::RUSQLITE, arg)?.into();
+        } else if name == symbol::DIESEL {
+            self.impls.diesel = parse_optional_impl_option(symbol::DIESEL, arg)?.into();
+        } else if name == symbol::POSTGRES {
+            self.impls.postgres = parse_optional_impl_option(symbol::POSTGRES, arg)?.into();
+        } else if name == symbol::SAILFISH {
+            self.impls.sailfish = parse_optional_impl_option(symbol::SAILFISH, arg)?.into();
+        } else if name == symbol::SQLX {
+            self.impls.sqlx = parse_optional_impl_option(symbol::SQLX, arg)?.into();
+        } else if name == symbol::SCHEMARS {
+            self.impls.schemars = parse_optional_impl_option(symbol::SCHEMARS, arg)?.into();
+        } else if name == symbol::UTOIPA {
+            self.impls.utoipa = parse_optional_impl_option(symbol::UTOIPA, arg)?.into();
+        } else if name == symbol::TS_RS {
+            self.impls.ts_rs = parse_optional_impl_option(symbol::TS_RS, arg)?.into();
+        } else if name == symbol::ARBITRARY {
+            self.impls.arbitrary = parse_optional_impl_option(symbol::ARBITRARY, arg)?.into();
+        } else if name == symbol::NO_STD {
+            self.std_lib = StdLib::no_std(proc_macro2::Span::call_site());
+        } else {
+            return Err(self.slice.unsupported(name));
+        }
+
+        Ok(())
+    }
+}
+
+/// A `pattern` provides the validator, so it can't be combined with another validator or
+/// normalizer.
+fn apply_pattern(check_mode: &mut IndefiniteCheckMode, pattern: Option<&Literal>) -> Result<()> {
+    match pattern {
+        Some(pattern) if !matches!(check_mode, IndefiniteCheckMode::None) => Err(Error::new(
+            pattern.span(),
+            format!(
+                "`{}` cannot be combined with `{}` or `{}`",
+                symbol::PATTERN,
+                symbol::VALIDATOR,
+                symbol::NORMALIZER,
+            ),
+        )),
+        Some(_) => {
+            *check_mode = IndefiniteCheckMode::Validate(None);
+            Ok(())
+        }
+        None => Ok(()),
+    }
+}
+
+/// A braid with a `pattern` describes itself through `strid::Constraints`, unless another type
+/// was named to do so.
+fn resolve_pattern(
+    pattern: Option<Literal>,
+    constraints: Option<Option<crate::grammar::Type>>,
+) -> (Option<Pattern>, Option<Option<crate::grammar::Type>>) {
+    match pattern {
+        Some(lit) => {
+            let pattern = Pattern {
+                lit,
+                constraints: matches!(constraints, None | Some(None)),
+            };
+            (Some(pattern), constraints.or(Some(None)))
+        }
+        None => (None, constraints),
+    }
+}
+
+/// A `const_validator` only stands in for the validator in `const` contexts, so one must exist.
+fn check_const_validator(
+    check_mode: &IndefiniteCheckMode,
+    const_validator: Option<&crate::grammar::Type>,
+) -> Result<()> {
+    match const_validator {
+        Some(const_validator) if matches!(check_mode, IndefiniteCheckMode::None) => {
+            Err(Error::new_spanned(
+                const_validator,
+                format!(
+                    "`{}` requires either `{}` or `{}` to be specified",
+                    symbol::CONST_VALIDATOR,
+                    symbol::VALIDATOR,
+                    symbol::NORMALIZER,
+                ),
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Facet checks the invariants of a borrowed type with a free function, which can't name the
+/// parameters of a generic braid, so a generic braid with a check can't derive `Facet` for its
+/// borrowed type.
+fn check_facet_generics(
+    ident: &proc_macro2::Ident,
+    generics: &Generics,
+    check_mode: &CheckMode,
+    impls: &Impls,
+    alternatives: &str,
+) -> Result<()> {
+    if impls.facet.has_borrowed_impl()
+        && !generics.is_empty()
+        && !matches!(check_mode, CheckMode::None)
+    {
+        return Err(Error::new(
+            ident.span(),
+            format!(
+                "a generic braid with a validator or normalizer can't derive `Facet` for its \
+                 borrowed type, use {}",
+                alternatives,
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// A `proptest_strategy` or `quickcheck_generator` only feeds the generated `Arbitrary`
+/// implementation, so one must exist.
+fn check_generator(
+    generator: Option<&proc_macro2::TokenStream>,
+    option: ImplOption,
+    name: symbol::Symbol,
+    required: symbol::Symbol,
+) -> Result<()> {
+    match generator {
+        Some(generator) if option == ImplOption::Omit => Err(Error::new_spanned(
+            generator,
+            format!("`{}` requires `{}` to be specified", name, required),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Only strings can be compared with Unicode case folding, so other braids can at most ignore
+/// the case of ASCII letters.
+fn parse_case(slice: Slice, lit: &Literal) -> Result<Case> {
+    let case: Case = parse_lit_into_option(symbol::CASE, lit)?;
+    if case == Case::Insensitive && slice != Slice::Str {
+        return Err(Error::new(
+            lit.span(),
+            format!(
+                "`{}` braids can't be `{} = \"insensitive\"`, only `ascii_insensitive`",
+                slice.unsized_name(),
+                symbol::CASE,
+            ),
+        ));
+    }
+
+    Ok(case)
+}
+
+/// Returns the value of a `name = "value"` argument, or an error showing the expected form.
+fn require_value<'a>(arg: &'a AttrArg, expected: &str) -> Result<&'a Literal> {
+    arg.value()
+        .ok_or_else(|| Error::new_spanned(arg, format!("expected `{}`", expected)))
+}
+
+/// Returns the contents of a `name(...)` argument, or an error showing the expected form.
+fn require_list<'a>(arg: &'a AttrArg, expected: &str) -> Result<&'a [unsynn::TokenTree]> {
+    arg.list_contents()
+        .ok_or_else(|| Error::new_spanned(arg, format!("expected `{}`", expected)))
+}
+
+/// Parses arguments like `serde` that may be given bare to opt in, or with an explicit value.
+fn parse_optional_impl_option(attr_name: symbol::Symbol, arg: &AttrArg) -> Result<ImplOption> {
+    match arg {
+        AttrArg::Path(_) => Ok(ImplOption::Implement),
+        AttrArg::NameValue(nv) => parse_lit_into_option(attr_name, &nv.third),
+        AttrArg::List(_) => Err(Error::new_spanned(
+            arg,
+            format!(
+                "expected `{}` or `{} = \"impl|omit\"`",
+                attr_name, attr_name
+            ),
+        )),
+    }
 }
 
 impl ParamsRef {
-    pub fn build(
-        self,
-        body: &mut crate::grammar::ItemStruct,
-    ) -> Result<proc_macro2::TokenStream, String> {
+    pub fn build(self, body: &mut crate::grammar::ItemStruct) -> Result<proc_macro2::TokenStream> {
         let ParamsRef {
+            slice,
             std_lib,
             check_mode,
+            const_validator,
+            pattern,
+            constraints,
+            case,
             impls,
         } = self;
 
         create_ref_field_if_none(&mut body.fields);
-        let (wrapped_type, field_ident, field_attrs) = get_field_info(&body.fields)?;
+        let generics = Generics::from_item(body)?;
+        let (wrapped_type, field_ident, field_attrs) = get_field_info(&body.ident, &body.fields)?;
         let ref_ty = &body.ident;
-        let check_mode = check_mode.infer_validator_if_missing(ref_ty);
+        let (pattern, constraints) = resolve_pattern(pattern, constraints);
+        let check_mode = check_mode.infer_validator_if_missing(ref_ty, &generics);
+        check_facet_generics(ref_ty, &generics, &check_mode, &impls, "`facet = \"omit\"`")?;
+        let constraints = infer_constraints_if_missing(constraints, &check_mode, ref_ty, &generics);
         let field = Field {
             attrs: field_attrs.to_vec(),
             name: field_ident
                 .map(|i| FieldName::Named(i.clone()))
-                .unwrap_or(FieldName::Unnamed),
+                .unwrap_or(FieldName::Unnamed(0)),
             ty: wrapped_type.clone(),
-        };
+            marker: generics.marker_ty(),
+        }
+        .for_ref();
 
         // Create a verbatim type from the ident
         let ty_tokens = body.ident.to_token_stream();
         let mut ty_iter = ty_tokens.to_token_iter();
         let ty = ty_iter
             .parse::<crate::grammar::Type>()
-            .map_err(|e| format!("failed to parse type: {}", e))?;
+            .map_err(|e| Error::new(body.ident.span(), format!("failed to parse type: {}", e)))?;
 
         let code_gen = RefCodeGen {
+            slice,
             doc: &[],
             common_attrs: &body.attrs,
             attrs: &vec![],
             vis: body.vis.as_ref(),
             ty: &ty,
             ident: body.ident.clone(),
+            generics: &generics,
             field,
             check_mode: &check_mode,
+            const_validator: const_validator.as_ref(),
+            constraints: constraints.as_ref(),
             owned_ty: None,
             std_lib: &std_lib,
+            case,
             impls: &impls,
         }
         .tokens();
+        let pattern = pattern.map(|pattern| {
+            let ty = &body.ident;
+            let ty_generics = generics.ty_generics();
+            pattern.tokens(&quote::quote! { #ty #ty_generics }, &generics, &std_lib)
+        });
 
-        Ok(code_gen)
+        Ok(quote::quote! {
+            #code_gen
+            #pattern
+        })
     }
 }
 
 pub struct CodeGen {
+    slice: Slice,
     check_mode: CheckMode,
+    const_validator: Option<crate::grammar::Type>,
+    pattern: Option<Pattern>,
+    constraints: Option<crate::grammar::Type>,
     body: crate::grammar::ItemStruct,
+    generics: Generics,
     field: Field,
 
     owned_attrs: AttrList,
@@ -404,6 +678,7 @@ pub struct CodeGen {
     ref_ty: crate::grammar::Type,
 
     std_lib: StdLib,
+    case: Case,
     expose_inner: bool,
     impls: Impls,
 }
@@ -412,23 +687,37 @@ impl CodeGen {
     pub fn generate(&self) -> proc_macro2::TokenStream {
         let owned = self.owned().tokens();
         let ref_ = self.borrowed().tokens();
+        let pattern = self.pattern.as_ref().map(|pattern| {
+            let ty = &self.body.ident;
+            let ty_generics = self.generics.ty_generics();
+            pattern.tokens(
+                &quote::quote! { #ty #ty_generics },
+                &self.generics,
+                &self.std_lib,
+            )
+        });
 
         quote::quote! {
             #owned
             #ref_
+            #pattern
         }
     }
 
     pub fn owned(&self) -> OwnedCodeGen<'_> {
         OwnedCodeGen {
+            slice: self.slice,
             common_attrs: &self.body.attrs,
             check_mode: &self.check_mode,
+            constraints: self.constraints.as_ref(),
             body: &self.body,
             field: &self.field,
             attrs: &self.owned_attrs,
             ty: &self.body.ident,
+            generics: &self.generics,
             ref_ty: &self.ref_ty,
             std_lib: &self.std_lib,
+            case: self.case,
             expose_inner: self.expose_inner,
             impls: &self.impls,
         }
@@ -436,11 +725,15 @@ impl CodeGen {
 
     pub fn borrowed(&self) -> RefCodeGen<'_> {
         RefCodeGen {
+            slice: self.slice,
             doc: &self.ref_doc,
             common_attrs: &self.body.attrs,
             check_mode: &self.check_mode,
+            const_validator: self.const_validator.as_ref(),
+            constraints: self.constraints.as_ref(),
             vis: self.body.vis.as_ref(),
-            field: self.field.clone(),
+            generics: &self.generics,
+            field: self.field.for_ref(),
             attrs: &self.ref_attrs,
             ty: &self.ref_ty,
             ident: {
@@ -452,6 +745,7 @@ impl CodeGen {
             },
             owned_ty: Some(&self.body.ident),
             std_lib: &self.std_lib,
+            case: self.case,
             impls: &self.impls,
         }
     }
@@ -472,13 +766,14 @@ fn infer_ref_type_from_owned_name(name: &unsynn::Ident) -> crate::grammar::Type
         .expect("failed to parse ref type")
 }
 
-fn create_field_if_none(fields: &mut crate::grammar::Fields) {
+fn create_field_if_none(fields: &mut crate::grammar::Fields, default_ty: &str) {
     use crate::grammar::Fields;
 
-    // If it's a unit struct, convert it to an unnamed tuple struct with String
+    // If it's a unit struct, convert it to an unnamed tuple struct with the default type
     if matches!(fields, Fields::Unit(_)) {
         // Parse a dummy struct to extract the fields structure
-        let dummy_struct: proc_macro2::TokenStream = "struct Dummy(String);".parse().unwrap();
+        let dummy_struct: proc_macro2::TokenStream =
+            format!("struct Dummy({});", default_ty).parse().unwrap();
         let mut iter = dummy_struct.to_token_iter();
         let parsed = iter
             .parse::<crate::grammar::ItemStruct>()
@@ -498,76 +793,218 @@ fn create_ref_field_if_none(fields: &mut crate::grammar::Fields) {
     let _ = fields; // Suppress unused warning
 }
 
-fn get_field_info(
-    fields: &crate::grammar::Fields,
-) -> Result<
-    (
-        &crate::grammar::Type,
-        Option<&unsynn::Ident>,
-        &[crate::grammar::Attribute],
-    ),
-    String,
-> {
+fn get_field_info<'a>(
+    ident: &unsynn::Ident,
+    fields: &'a crate::grammar::Fields,
+) -> Result<(
+    &'a crate::grammar::Type,
+    Option<&'a unsynn::Ident>,
+    &'a [crate::grammar::Attribute],
+)> {
     use crate::grammar::Fields;
 
     match fields {
         Fields::Named(f) => {
-            if f.content.is_empty() {
-                return Err("struct must have at least one field".to_string());
-            }
-            if f.content.len() > 1 {
-                return Err("typed string can only have one field".to_string());
-            }
+            check_single_field(ident, &f.content)?;
             let field = &f.content[0].value;
             Ok((&field.ty, Some(&field.ident), &field.attrs))
         }
         Fields::Unnamed(f) => {
-            if f.content.is_empty() {
-                return Err("struct must have at least one field".to_string());
-            }
-            if f.content.len() > 1 {
-                return Err("typed string can only have one field".to_string());
-            }
+            check_single_field(ident, &f.content)?;
             let field = &f.content[0].value;
             Ok((&field.ty, None, &field.attrs))
         }
-        Fields::Unit(_) => {
-            Err("unit structs are not supported - struct must have at least one field".to_string())
+        Fields::Unit(_) => Err(Error::new(
+            ident.span(),
+            "unit structs are not supported - struct must have at least one field",
+        )),
+    }
+}
+
+fn check_single_field<T: unsynn::ToTokens>(
+    ident: &unsynn::Ident,
+    fields: &[unsynn::Delimited<T, unsynn::Comma>],
+) -> Result<()> {
+    match fields {
+        [] => Err(Error::new(
+            ident.span(),
+            "struct must have at least one field",
+        )),
+        [_] => Ok(()),
+        [_, extra @ ..] => {
+            let tokens: proc_macro2::TokenStream = extra
+                .iter()
+                .map(|field| unsynn::ToTokens::to_token_stream(&field.value))
+                .collect();
+            Err(Error::new_spanned(
+                tokens,
+                "typed string can only have one field",
+            ))
         }
     }
 }
 
+/// The field that holds the braid's string, and the marker for any generic parameters that
+/// accompanies it.
 #[derive(Clone)]
 pub struct Field {
     pub attrs: Vec<crate::grammar::Attribute>,
     pub name: FieldName,
     pub ty: crate::grammar::Type,
+    pub marker: Option<proc_macro2::TokenStream>,
 }
 
+/// The name of the marker field on braids with named fields.
+const MARKER_FIELD: &str = "_marker";
+
 impl Field {
     fn self_constructor(&self) -> SelfConstructorImpl<'_> {
         SelfConstructorImpl(self)
     }
+
+    /// The same field on the borrowed type, where the marker comes first because the unsized
+    /// `str` must be the last field.
+    fn for_ref(&self) -> Self {
+        let name = match &self.name {
+            FieldName::Unnamed(_) if self.marker.is_some() => FieldName::Unnamed(1),
+            name => name.clone(),
+        };
+
+        Self {
+            name,
+            ..self.clone()
+        }
+    }
+
+    /// The same field on the owned type, where the string comes first.
+    fn for_owned(&self) -> Self {
+        let name = match &self.name {
+            FieldName::Unnamed(_) => FieldName::Unnamed(0),
+            name => name.clone(),
+        };
+
+        Self {
+            name,
+            ..self.clone()
+        }
+    }
+
+    /// The declaration of the marker field, if there is one.
+    fn marker_decl(&self) -> Option<proc_macro2::TokenStream> {
+        let marker = self.marker.as_ref()?;
+        Some(match &self.name {
+            FieldName::Named(_) => {
+                let name = proc_macro2::Ident::new(MARKER_FIELD, proc_macro2::Span::call_site());
+                quote::quote! { #name: #marker }
+            }
+            FieldName::Unnamed(_) => marker.clone(),
+        })
+    }
+
+    /// The value of the marker field in a struct expression, if there is one.
+    fn marker_init(&self) -> Option<proc_macro2::TokenStream> {
+        self.marker.as_ref()?;
+        Some(match &self.name {
+            FieldName::Named(_) => {
+                let name = proc_macro2::Ident::new(MARKER_FIELD, proc_macro2::Span::call_site());
+                quote::quote! { #name: ::core::marker::PhantomData }
+            }
+            FieldName::Unnamed(_) => quote::quote! { ::core::marker::PhantomData },
+        })
+    }
+
+    /// Whether the field is an `Arc<str>` or `Rc<str>`, under any path.
+    fn shared(&self) -> Option<Shared> {
+        use proc_macro2::TokenTree;
+
+        let tokens: Vec<_> = unsynn::ToTokens::to_token_stream(&self.ty)
+            .into_iter()
+            .collect();
+        let [
+            path @ ..,
+            TokenTree::Ident(ptr),
+            TokenTree::Punct(open),
+            TokenTree::Ident(inner),
+            TokenTree::Punct(close),
+        ] = tokens.as_slice()
+        else {
+            return None;
+        };
+
+        let is_path = path.iter().all(|tt| match tt {
+            TokenTree::Ident(_) => true,
+            TokenTree::Punct(p) => p.as_char() == ':',
+            _ => false,
+        });
+        if !is_path || open.as_char() != '<' || inner != "str" || close.as_char() != '>' {
+            return None;
+        }
+
+        if ptr == "Arc" {
+            Some(Shared::Arc)
+        } else if ptr == "Rc" {
+            Some(Shared::Rc)
+        } else {
+            None
+        }
+    }
+
+    /// A struct expression for the owned type at `path` (either `Self` or a turbofished type)
+    /// holding `value`.
+    fn construct(&self, path: impl ToTokens, value: impl ToTokens) -> proc_macro2::TokenStream {
+        let marker = self.marker_init().map(|m| quote::quote! { , #m });
+        match &self.name {
+            FieldName::Named(name) => quote::quote! { #path { #name: #value #marker } },
+            FieldName::Unnamed(_) => quote::quote! { #path(#value #marker) },
+        }
+    }
 }
 
-#[derive(Clone)]
-pub enum FieldName {
-    Named(unsynn::Ident),
-    Unnamed,
+/// A reference-counted `str` that backs an owned type, which can hand its allocation to a
+/// pointer to the borrowed type instead of copying it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shared {
+    Arc,
+    Rc,
 }
 
-impl FieldName {
-    fn constructor_delimiter(&self) -> proc_macro2::Delimiter {
+impl Shared {
+    /// The pointer type, e.g. `::alloc::sync::Arc`.
+    pub fn path(self, alloc: &proc_macro2::Ident) -> proc_macro2::TokenStream {
         match self {
-            FieldName::Named(_) => proc_macro2::Delimiter::Brace,
-            FieldName::Unnamed => proc_macro2::Delimiter::Parenthesis,
+            Self::Arc => quote::quote! { ::#alloc::sync::Arc },
+            Self::Rc => quote::quote! { ::#alloc::rc::Rc },
         }
     }
 
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Arc => "Arc",
+            Self::Rc => "Rc",
+        }
+    }
+
+    /// The method that converts the owned type into a pointer to the borrowed type.
+    pub fn into_ref_method(self) -> proc_macro2::Ident {
+        let name = match self {
+            Self::Arc => "into_arc_ref",
+            Self::Rc => "into_rc_ref",
+        };
+        proc_macro2::Ident::new(name, proc_macro2::Span::call_site())
+    }
+}
+
+#[derive(Clone)]
+pub enum FieldName {
+    Named(unsynn::Ident),
+    Unnamed(usize),
+}
+
+impl FieldName {
     fn input_name(&self) -> proc_macro2::Ident {
         match self {
             FieldName::Named(name) => name.clone(),
-            FieldName::Unnamed => proc_macro2::Ident::new("raw", proc_macro2::Span::call_site()),
+            FieldName::Unnamed(_) => proc_macro2::Ident::new("raw", proc_macro2::Span::call_site()),
         }
     }
 }
@@ -576,7 +1013,7 @@ impl ToTokens for FieldName {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         match self {
             Self::Named(ident) => ident.to_tokens(tokens),
-            Self::Unnamed => tokens.append(Literal::u8_unsuffixed(0)),
+            Self::Unnamed(index) => tokens.append(Literal::usize_unsuffixed(*index)),
         }
     }
 }
@@ -586,13 +1023,7 @@ struct SelfConstructorImpl<'a>(&'a Field);
 impl<'a> ToTokens for SelfConstructorImpl<'a> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let Self(field) = self;
-        tokens.append(proc_macro2::Ident::new(
-            "Self",
-            proc_macro2::Span::call_site(),
-        ));
-        tokens.append(proc_macro2::Group::new(
-            field.name.constructor_delimiter(),
-            field.name.input_name().into_token_stream(),
-        ));
+        let self_ = proc_macro2::Ident::new("Self", proc_macro2::Span::call_site());
+        tokens.extend(field.construct(self_, field.name.input_name()));
     }
 }
@@ -1,23 +1,43 @@
-use quote::quote;
+use quote::{ToTokens, quote};
 use unsynn::Ident;
 
-use super::{AttrList, CheckMode, Field, Impls, StdLib, impls::ToImpl};
+use super::{
+    AttrList, Case, CheckMode, Field, Generics, Impls, Slice, StdLib,
+    impls::{ImplFacet, ToImpl},
+};
 
 pub struct OwnedCodeGen<'a> {
-    #[allow(dead_code)]
+    pub slice: Slice,
     pub common_attrs: &'a [crate::grammar::Attribute],
     pub attrs: &'a AttrList,
     pub body: &'a crate::grammar::ItemStruct,
     pub ty: &'a Ident,
+    pub generics: &'a Generics,
     pub field: &'a Field,
     pub check_mode: &'a CheckMode,
+    pub constraints: Option<&'a crate::grammar::Type>,
     pub ref_ty: &'a crate::grammar::Type,
     pub std_lib: &'a StdLib,
+    pub case: Case,
     pub expose_inner: bool,
     pub impls: &'a Impls,
 }
 
 impl<'a> OwnedCodeGen<'a> {
+    /// The owned type along with its generic parameters, e.g. `Id<T>`.
+    pub fn owned_type(&self) -> proc_macro2::TokenStream {
+        let ty = self.ty;
+        let ty_generics = self.generics.ty_generics();
+        quote! { #ty #ty_generics }
+    }
+
+    /// The borrowed type along with its generic parameters, e.g. `IdRef<T>`.
+    pub fn ref_type(&self) -> proc_macro2::TokenStream {
+        let ref_ty = self.ref_ty;
+        let ty_generics = self.generics.ty_generics();
+        quote! { #ref_ty #ty_generics }
+    }
+
     fn constructor(&self) -> proc_macro2::TokenStream {
         match &self.check_mode {
             CheckMode::None => self.infallible_constructor(),
@@ -32,9 +52,12 @@ impl<'a> OwnedCodeGen<'a> {
 
         let param = self.field.name.input_name();
         let create = self.field.self_constructor();
-        let ref_ty = self.ref_ty;
+        let ref_ty = self.ref_type();
         let field_ty = &self.field.ty;
         let alloc = self.std_lib.alloc();
+        let from_raw = self.slice.method("from_", "");
+        let static_ty = self.slice.static_ty();
+        let from_static_raw = self.slice.static_raw();
 
         let vis = self
             .expose_inner
@@ -50,8 +73,8 @@ impl<'a> OwnedCodeGen<'a> {
             #[inline]
             #[doc = #static_doc_comment]
             #[track_caller]
-            pub fn from_static(raw: &'static str) -> Self {
-                ::#alloc::borrow::ToOwned::to_owned(#ref_ty::from_static(raw))
+            pub fn from_static(raw: &'static #static_ty) -> Self {
+                ::#alloc::borrow::ToOwned::to_owned(<#ref_ty>::#from_raw(#from_static_raw))
             }
         }
     }
@@ -75,13 +98,19 @@ impl<'a> OwnedCodeGen<'a> {
             self.ty, validator_tokens
         );
 
-        let validator = crate::as_validator(validator);
+        let ty = self.ty;
+        let validator = self.slice.validator(validator);
         let param = self.field.name.input_name();
         let create = self.field.self_constructor();
-        let ref_ty = self.ref_ty;
+        let ref_ty = self.ref_type();
         let field_ty = &self.field.ty;
         let core = self.std_lib.core();
         let alloc = self.std_lib.alloc();
+        let unsized_ty = self.slice.unsized_ty();
+        let from_raw = self.slice.method("from_", "");
+        let static_ty = self.slice.static_ty();
+        let from_static_raw = self.slice.static_raw();
+        let panic_doc = self.slice.static_panic_doc("not valid");
 
         let vis = self
             .expose_inner
@@ -91,7 +120,7 @@ impl<'a> OwnedCodeGen<'a> {
             #[doc = #doc_comment]
             #[inline]
             #vis fn new(#param: #field_ty) -> ::#core::result::Result<Self, #validator::Error> {
-                #validator::validate(#param.as_ref())?;
+                #validator::validate(::#core::convert::AsRef::<#unsized_ty>::as_ref(&#param))?;
                 ::#core::result::Result::Ok(#create)
             }
 
@@ -107,10 +136,11 @@ impl<'a> OwnedCodeGen<'a> {
             #[doc = ""]
             #[doc = "# Panics"]
             #[doc = ""]
-            #[doc = "This function will panic if the provided raw string is not valid."]
+            #[doc = #panic_doc]
             #[track_caller]
-            pub fn from_static(raw: &'static str) -> Self {
-                ::#alloc::borrow::ToOwned::to_owned(#ref_ty::from_static(raw))
+            pub fn from_static(raw: &'static #static_ty) -> Self {
+                let raw = <#ref_ty>::#from_raw(#from_static_raw).expect(concat!("invalid ", stringify!(#ty)));
+                ::#alloc::borrow::ToOwned::to_owned(raw)
             }
         }
     }
@@ -139,13 +169,19 @@ impl<'a> OwnedCodeGen<'a> {
         );
 
         let ty = self.ty;
-        let validator = crate::as_validator(normalizer);
-        let normalizer = crate::as_normalizer(normalizer);
+        let validator = self.slice.validator(normalizer);
+        let normalizer = self.slice.normalizer(normalizer);
         let param = self.field.name.input_name();
         let create = self.field.self_constructor();
-        let ref_ty = self.ref_ty;
+        let ref_ty = self.ref_type();
         let field_ty = &self.field.ty;
         let core = self.std_lib.core();
+        let alloc = self.std_lib.alloc();
+        let unsized_ty = self.slice.unsized_ty();
+        let from_raw = self.slice.method("from_", "");
+        let static_ty = self.slice.static_ty();
+        let from_static_raw = self.slice.static_raw();
+        let panic_doc = self.slice.static_panic_doc("not valid");
 
         let vis = self
             .expose_inner
@@ -155,7 +191,8 @@ impl<'a> OwnedCodeGen<'a> {
             #[doc = #doc_comment]
             #[inline]
             #vis fn new(#param: #field_ty) -> ::#core::result::Result<Self, #validator::Error> {
-                let #param = ::#core::convert::From::from(#normalizer::normalize(#param.as_ref())?);
+                let normalized = #normalizer::normalize(::#core::convert::AsRef::<#unsized_ty>::as_ref(&#param))?;
+                let #param = ::#core::convert::From::from(::#alloc::borrow::Cow::into_owned(normalized));
                 ::#core::result::Result::Ok(#create)
             }
 
@@ -171,10 +208,10 @@ impl<'a> OwnedCodeGen<'a> {
             #[doc = ""]
             #[doc = "# Panics"]
             #[doc = ""]
-            #[doc = "This function will panic if the provided raw string is not valid."]
+            #[doc = #panic_doc]
             #[track_caller]
-            pub fn from_static(raw: &'static str) -> Self {
-                #ref_ty::from_str(raw).expect(concat!("invalid ", stringify!(#ty))).into_owned()
+            pub fn from_static(raw: &'static #static_ty) -> Self {
+                <#ref_ty>::#from_raw(#from_static_raw).expect(concat!("invalid ", stringify!(#ty))).into_owned()
             }
         }
     }
@@ -186,14 +223,15 @@ impl<'a> OwnedCodeGen<'a> {
             self.ref_ty.to_token_stream(),
         );
 
-        let ref_type = self.ref_ty;
+        let ref_type = self.ref_type();
         let field = &self.field.name;
         let alloc = self.std_lib.alloc();
         let box_pointer_reinterpret_safety_comment = {
             let doc = format!(
-                "SAFETY: `{ty}` is `#[repr(transparent)]` around a single `str` field, so a `*mut \
-                 str` can be safely reinterpreted as a `*mut {ty}`",
+                "SAFETY: `{ty}` is `#[repr(transparent)]` and its only non-zero-sized field is \
+                 `{raw}`, so a `*mut {raw}` can be safely reinterpreted as a `*mut {ty}`",
                 ty = self.ref_ty.to_token_stream(),
+                raw = self.slice.unsized_name(),
             );
 
             quote! {
@@ -202,18 +240,72 @@ impl<'a> OwnedCodeGen<'a> {
             }
         };
 
+        // A shared string can't give up its allocation, so it is copied into a new box
+        let (doc, boxed) = match self.field.shared() {
+            Some(shared) => (
+                format!(
+                    "Converts this `{}` into a [`Box<{}>`]\n\nThis copies the string out of its \
+                     `{}`.",
+                    self.ty,
+                    self.ref_ty.to_token_stream(),
+                    shared.name(),
+                ),
+                quote! { ::#alloc::boxed::Box::<str>::from(self.as_str()) },
+            ),
+            None => (doc, self.slice.into_boxed(alloc, quote! { self.#field })),
+        };
+
         quote! {
             #[doc = #doc]
             #[allow(unsafe_code)]
             #[inline]
             pub fn into_boxed_ref(self) -> ::#alloc::boxed::Box<#ref_type> {
                 #box_pointer_reinterpret_safety_comment
-                let box_str = ::#alloc::string::String::from(self.#field).into_boxed_str();
-                unsafe { ::#alloc::boxed::Box::from_raw(::#alloc::boxed::Box::into_raw(box_str) as *mut #ref_type) }
+                let boxed = #boxed;
+                unsafe { ::#alloc::boxed::Box::from_raw(::#alloc::boxed::Box::into_raw(boxed) as *mut #ref_type) }
             }
         }
     }
 
+    /// `into_arc_ref` or `into_rc_ref`, which hands a shared string's allocation to a pointer to
+    /// the borrowed type.
+    fn make_into_shared_ref(&self) -> Option<proc_macro2::TokenStream> {
+        let shared = self.field.shared()?;
+        let doc = format!(
+            "Converts this `{}` into an [`{name}<{}>`] without copying the string",
+            self.ty,
+            self.ref_ty.to_token_stream(),
+            name = shared.name(),
+        );
+
+        let ref_type = self.ref_type();
+        let field = &self.field.name;
+        let method = shared.into_ref_method();
+        let ptr = shared.path(self.std_lib.alloc());
+        let pointer_reinterpret_safety_comment = {
+            let doc = format!(
+                "SAFETY: `{ty}` is `#[repr(transparent)]` and its only non-zero-sized field is \
+                 `str`, so a `*const str` can be safely reinterpreted as a `*const {ty}`",
+                ty = self.ref_ty.to_token_stream(),
+            );
+
+            quote! {
+                #[doc = #doc]
+                fn ptr_safety_comment() {}
+            }
+        };
+
+        Some(quote! {
+            #[doc = #doc]
+            #[allow(unsafe_code)]
+            #[inline]
+            pub fn #method(self) -> #ptr<#ref_type> {
+                #pointer_reinterpret_safety_comment
+                unsafe { #ptr::from_raw(#ptr::into_raw(self.#field) as *const #ref_type) }
+            }
+        })
+    }
+
     fn make_take(&self) -> proc_macro2::TokenStream {
         let field = &self.field.name;
         let field_ty = &self.field.ty;
@@ -236,31 +328,58 @@ impl<'a> OwnedCodeGen<'a> {
     }
 
     fn inherent(&self) -> proc_macro2::TokenStream {
-        let name = self.ty;
+        let name = self.owned_type();
+        let impl_generics = self.generics.impl_generics();
+        let where_clause = self.generics.where_clause();
         let constructor = self.constructor();
         let into_boxed_ref = self.make_into_boxed_ref();
+        let into_shared_ref = self.make_into_shared_ref();
         let into_string = self.make_take();
 
         quote! {
             #[automatically_derived]
-            impl #name {
+            impl #impl_generics #name #where_clause {
                 #constructor
                 #into_boxed_ref
+                #into_shared_ref
                 #into_string
             }
         }
     }
 
     fn common_conversion(&self) -> proc_macro2::TokenStream {
-        let ty = self.ty;
+        let ty = self.owned_type();
         let field_name = &self.field.name;
-        let ref_ty = self.ref_ty;
+        let ref_ty = self.ref_type();
         let core = self.std_lib.core();
         let alloc = self.std_lib.alloc();
+        let impl_generics = self.generics.impl_generics();
+        let impl_generics_a = self.generics.impl_generics_with(quote!('a));
+        let where_clause = self.generics.where_clause();
+        let buf_ty = self.slice.buf_ty(alloc);
+        let as_raw = self.slice.method("as_", "");
+
+        // A shared string has no `Into<String>`, so its contents are copied instead
+        let into_string = match self.field.shared() {
+            Some(_) => quote! { ::#alloc::string::String::from(s.as_str()) },
+            None => quote! { ::#core::convert::From::from(s.#field_name) },
+        };
+        let shared = self.shared_conversion();
+        let as_ref_raw = self.slice.as_ref_tys().into_iter().map(|target| {
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics ::#core::convert::AsRef<#target> for #ty #where_clause {
+                    #[inline]
+                    fn as_ref(&self) -> &#target {
+                        ::#core::convert::AsRef::<#target>::as_ref(self.#as_raw())
+                    }
+                }
+            }
+        });
 
         quote! {
             #[automatically_derived]
-            impl ::#core::convert::From<&'_ #ref_ty> for #ty {
+            impl #impl_generics ::#core::convert::From<&'_ #ref_ty> for #ty #where_clause {
                 #[inline]
                 fn from(s: &#ref_ty) -> Self {
                     ::#alloc::borrow::ToOwned::to_owned(s)
@@ -268,15 +387,17 @@ impl<'a> OwnedCodeGen<'a> {
             }
 
             #[automatically_derived]
-            impl ::#core::convert::From<#ty> for ::#alloc::string::String {
+            impl #impl_generics ::#core::convert::From<#ty> for #buf_ty #where_clause {
                 #[inline]
                 fn from(s: #ty) -> Self {
-                    ::#core::convert::From::from(s.#field_name)
+                    #into_string
                 }
             }
 
+            #shared
+
             #[automatically_derived]
-            impl ::#core::borrow::Borrow<#ref_ty> for #ty {
+            impl #impl_generics ::#core::borrow::Borrow<#ref_ty> for #ty #where_clause {
                 #[inline]
                 fn borrow(&self) -> &#ref_ty {
                     ::#core::ops::Deref::deref(self)
@@ -284,24 +405,17 @@ impl<'a> OwnedCodeGen<'a> {
             }
 
             #[automatically_derived]
-            impl ::#core::convert::AsRef<#ref_ty> for #ty {
+            impl #impl_generics ::#core::convert::AsRef<#ref_ty> for #ty #where_clause {
                 #[inline]
                 fn as_ref(&self) -> &#ref_ty {
                     ::#core::ops::Deref::deref(self)
                 }
             }
 
-            #[automatically_derived]
-            impl ::#core::convert::AsRef<str> for #ty {
-                #[inline]
-                fn as_ref(&self) -> &str {
-                    self.as_str()
-                }
-            }
-
+            #(#as_ref_raw)*
 
             #[automatically_derived]
-            impl ::#core::convert::From<#ty> for ::#alloc::boxed::Box<#ref_ty> {
+            impl #impl_generics ::#core::convert::From<#ty> for ::#alloc::boxed::Box<#ref_ty> #where_clause {
                 #[inline]
                 fn from(r: #ty) -> Self {
                     r.into_boxed_ref()
@@ -309,7 +423,7 @@ impl<'a> OwnedCodeGen<'a> {
             }
 
             #[automatically_derived]
-            impl ::#core::convert::From<::#alloc::boxed::Box<#ref_ty>> for #ty {
+            impl #impl_generics ::#core::convert::From<::#alloc::boxed::Box<#ref_ty>> for #ty #where_clause {
                 #[inline]
                 fn from(r: ::#alloc::boxed::Box<#ref_ty>) -> Self {
                     r.into_owned()
@@ -317,7 +431,7 @@ impl<'a> OwnedCodeGen<'a> {
             }
 
             #[automatically_derived]
-            impl<'a> ::#core::convert::From<::#alloc::borrow::Cow<'a, #ref_ty>> for #ty {
+            impl #impl_generics_a ::#core::convert::From<::#alloc::borrow::Cow<'a, #ref_ty>> for #ty #where_clause {
                 #[inline]
                 fn from(r: ::#alloc::borrow::Cow<'a, #ref_ty>) -> Self {
                     match r {
@@ -328,7 +442,7 @@ impl<'a> OwnedCodeGen<'a> {
             }
 
             #[automatically_derived]
-            impl<'a> ::#core::convert::From<#ty> for ::#alloc::borrow::Cow<'a, #ref_ty> {
+            impl #impl_generics_a ::#core::convert::From<#ty> for ::#alloc::borrow::Cow<'a, #ref_ty> #where_clause {
                 #[inline]
                 fn from(owned: #ty) -> Self {
                     ::#alloc::borrow::Cow::Owned(owned)
@@ -337,63 +451,117 @@ impl<'a> OwnedCodeGen<'a> {
         }
     }
 
-    fn infallible_conversion(&self) -> proc_macro2::TokenStream {
-        let ty = self.ty;
-        let ref_ty = self.ref_ty;
-        let field_name = &self.field.name;
+    /// Conversions between a shared string braid and a pointer to its borrowed type, which share
+    /// the same allocation.
+    fn shared_conversion(&self) -> Option<proc_macro2::TokenStream> {
+        let shared = self.field.shared()?;
+        let ty = self.owned_type();
+        let ref_ty = self.ref_type();
         let core = self.std_lib.core();
-        let alloc = self.std_lib.alloc();
+        let ptr = shared.path(self.std_lib.alloc());
+        let method = shared.into_ref_method();
+        let impl_generics = self.generics.impl_generics();
+        let where_clause = self.generics.where_clause();
+        let create = self.field.construct(quote! { Self }, quote! { raw });
+        let pointer_reinterpret_safety_comment = {
+            let doc = format!(
+                "SAFETY: `{ty}` is `#[repr(transparent)]` and its only non-zero-sized field is \
+                 `str`, so a `*const {ty}` can be safely reinterpreted as a `*const str`, and its \
+                 value already satisfies the type's invariant",
+                ty = self.ref_ty.to_token_stream(),
+            );
 
-        quote! {
+            quote! {
+                #[doc = #doc]
+                fn ptr_safety_comment() {}
+            }
+        };
+
+        Some(quote! {
             #[automatically_derived]
-            impl ::#core::convert::From<::#alloc::string::String> for #ty {
+            impl #impl_generics ::#core::convert::From<#ty> for #ptr<#ref_ty> #where_clause {
                 #[inline]
-                fn from(s: ::#alloc::string::String) -> Self {
-                    Self::new(From::from(s))
+                fn from(s: #ty) -> Self {
+                    s.#method()
                 }
             }
 
             #[automatically_derived]
-            impl ::#core::convert::From<&'_ str> for #ty {
+            impl #impl_generics ::#core::convert::From<#ptr<#ref_ty>> for #ty #where_clause {
+                #[allow(unsafe_code)]
                 #[inline]
-                fn from(s: &str) -> Self {
-                    Self::new(::#core::convert::From::from(s))
+                fn from(r: #ptr<#ref_ty>) -> Self {
+                    #pointer_reinterpret_safety_comment
+                    let raw = unsafe { #ptr::from_raw(#ptr::into_raw(r) as *const str) };
+                    #create
                 }
             }
+        })
+    }
 
+    fn infallible_conversion(&self) -> proc_macro2::TokenStream {
+        let ty = self.owned_type();
+        let ref_ty = self.ref_type();
+        let field_name = &self.field.name;
+        let core = self.std_lib.core();
+        let alloc = self.std_lib.alloc();
+        let impl_generics = self.generics.impl_generics();
+        let where_clause = self.generics.where_clause();
+        let unsized_ty = self.slice.unsized_ty();
+        let buf_ty = self.slice.buf_ty(alloc);
+        let from_raw = self.slice.method("from_", "");
+        let to_field = self.slice.to_field(core, alloc, quote! { s });
+
+        let from_str = (self.slice == Slice::Str).then(|| {
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics ::#core::str::FromStr for #ty #where_clause {
+                    type Err = ::#core::convert::Infallible;
+
+                    #[inline]
+                    fn from_str(s: &str) -> ::#core::result::Result<Self, Self::Err> {
+                        ::#core::result::Result::Ok(::#core::convert::From::from(s))
+                    }
+                }
+            }
+        });
+        let borrow_raw = self.borrow_raw();
+
+        quote! {
             #[automatically_derived]
-            impl ::#core::convert::From<::#alloc::boxed::Box<str>> for #ty {
+            impl #impl_generics ::#core::convert::From<#buf_ty> for #ty #where_clause {
                 #[inline]
-                fn from(s: ::#alloc::boxed::Box<str>) -> Self {
+                fn from(s: #buf_ty) -> Self {
                     Self::new(::#core::convert::From::from(s))
                 }
             }
 
             #[automatically_derived]
-            impl ::#core::str::FromStr for #ty {
-                type Err = ::#core::convert::Infallible;
-
+            impl #impl_generics ::#core::convert::From<&'_ #unsized_ty> for #ty #where_clause {
                 #[inline]
-                fn from_str(s: &str) -> ::#core::result::Result<Self, Self::Err> {
-                    ::#core::result::Result::Ok(::#core::convert::From::from(s))
+                fn from(s: &#unsized_ty) -> Self {
+                    Self::new(#to_field)
                 }
             }
 
             #[automatically_derived]
-            impl ::#core::borrow::Borrow<str> for #ty {
+            impl #impl_generics ::#core::convert::From<::#alloc::boxed::Box<#unsized_ty>> for #ty #where_clause {
                 #[inline]
-                fn borrow(&self) -> &str {
-                    self.as_str()
+                fn from(s: ::#alloc::boxed::Box<#unsized_ty>) -> Self {
+                    Self::new(::#core::convert::From::from(s))
                 }
             }
 
+            #from_str
+            #borrow_raw
+
             #[automatically_derived]
-            impl ::#core::ops::Deref for #ty {
+            impl #impl_generics ::#core::ops::Deref for #ty #where_clause {
                 type Target = #ref_ty;
 
                 #[inline]
                 fn deref(&self) -> &Self::Target {
-                    #ref_ty::from_str(::#core::convert::AsRef::as_ref(&self.#field_name))
+                    <#ref_ty>::#from_raw(::#core::convert::AsRef::<#unsized_ty>::as_ref(&self.#field_name))
                 }
             }
         }
@@ -416,145 +584,239 @@ impl<'a> OwnedCodeGen<'a> {
         }
     }
 
-    fn fallible_conversion(&self, validator: &crate::grammar::Type) -> proc_macro2::TokenStream {
-        let ty = self.ty;
-        let ref_ty = self.ref_ty;
+    /// The conversions of a checked braid from its buffer and unsized types, which go through
+    /// the validator or normalizer. A string braid can also be parsed with `FromStr`.
+    fn checked_conversion(
+        &self,
+        checker: &crate::grammar::Type,
+        is_normalized: bool,
+    ) -> proc_macro2::TokenStream {
+        let ty = self.owned_type();
+        let ref_ty = self.ref_type();
         let field_name = &self.field.name;
         let field_ty = &self.field.ty;
-        let validator = crate::as_validator(validator);
+        let validator = self.slice.validator(checker);
         let core = self.std_lib.core();
         let alloc = self.std_lib.alloc();
-        let unchecked_safety_comment = Self::unchecked_safety_comment(false);
-
-        quote! {
-            #[automatically_derived]
-            impl ::#core::convert::TryFrom<::#alloc::string::String> for #ty {
-                type Error = #validator::Error;
+        let unchecked_safety_comment = Self::unchecked_safety_comment(is_normalized);
+        let impl_generics = self.generics.impl_generics();
+        let where_clause = self.generics.where_clause();
+        let unsized_ty = self.slice.unsized_ty();
+        let buf_ty = self.slice.buf_ty(alloc);
+        let from_raw = self.slice.method("from_", "");
+        let from_raw_unchecked = self.slice.method("from_", "_unchecked");
+
+        let to_owned = if is_normalized {
+            quote! { ref_ty.into_owned() }
+        } else {
+            quote! { ::#alloc::borrow::ToOwned::to_owned(ref_ty) }
+        };
 
-                #[inline]
-                fn try_from(s: ::#alloc::string::String) -> ::#core::result::Result<Self, Self::Error> {
-                    const fn ensure_try_from_string_error_converts_to_validator_error<T: ?Sized + From<<#field_ty as ::#core::convert::TryFrom<::#alloc::string::String>>::Error>>() {}
-                    ensure_try_from_string_error_converts_to_validator_error::<Self::Error>();
+        // The field of a string braid may only convert fallibly from a `String`, in which case
+        // its error has to convert into the validator's
+        let from_buf = match self.slice {
+            Slice::Str => quote! {
+                const fn ensure_try_from_string_error_converts_to_validator_error<T: ?Sized + From<<#field_ty as ::#core::convert::TryFrom<::#alloc::string::String>>::Error>>() {}
+                ensure_try_from_string_error_converts_to_validator_error::<Self::Error>();
 
-                    Self::new(::#core::convert::TryFrom::try_from(s)?)
+                Self::new(::#core::convert::TryFrom::try_from(s)?)
+            },
+            _ => quote! { Self::new(::#core::convert::From::from(s)) },
+        };
+        let from_str = (self.slice == Slice::Str).then(|| {
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics ::#core::str::FromStr for #ty #where_clause {
+                    type Err = #validator::Error;
+
+                    #[inline]
+                    fn from_str(s: &str) -> ::#core::result::Result<Self, Self::Err> {
+                        let ref_ty = <#ref_ty>::from_str(s)?;
+                        ::#core::result::Result::Ok(#to_owned)
+                    }
                 }
             }
+        });
+        // A normalized braid can't borrow as its raw value, which would let a lookup find it by
+        // a value that isn't normalized
+        let borrow_raw = (!is_normalized).then(|| self.borrow_raw());
 
+        quote! {
             #[automatically_derived]
-            impl ::#core::convert::TryFrom<&'_ str> for #ty {
+            impl #impl_generics ::#core::convert::TryFrom<#buf_ty> for #ty #where_clause {
                 type Error = #validator::Error;
 
                 #[inline]
-                fn try_from(s: &str) -> ::#core::result::Result<Self, Self::Error> {
-                    let ref_ty = #ref_ty::from_str(s)?;
-                    ::#core::result::Result::Ok(::#alloc::borrow::ToOwned::to_owned(ref_ty))
+                fn try_from(s: #buf_ty) -> ::#core::result::Result<Self, Self::Error> {
+                    #from_buf
                 }
             }
 
             #[automatically_derived]
-            impl ::#core::str::FromStr for #ty {
-                type Err = #validator::Error;
+            impl #impl_generics ::#core::convert::TryFrom<&'_ #unsized_ty> for #ty #where_clause {
+                type Error = #validator::Error;
 
                 #[inline]
-                fn from_str(s: &str) -> ::#core::result::Result<Self, Self::Err> {
-                    let ref_ty = #ref_ty::from_str(s)?;
-                    ::#core::result::Result::Ok(::#alloc::borrow::ToOwned::to_owned(ref_ty))
+                fn try_from(s: &#unsized_ty) -> ::#core::result::Result<Self, Self::Error> {
+                    let ref_ty = <#ref_ty>::#from_raw(s)?;
+                    ::#core::result::Result::Ok(#to_owned)
                 }
             }
 
-            #[automatically_derived]
-            impl ::#core::borrow::Borrow<str> for #ty {
-                #[inline]
-                fn borrow(&self) -> &str {
-                    self.as_str()
-                }
-            }
+            #from_str
+            #borrow_raw
 
             #[automatically_derived]
-            impl ::#core::ops::Deref for #ty {
+            impl #impl_generics ::#core::ops::Deref for #ty #where_clause {
                 type Target = #ref_ty;
 
                 #[allow(unsafe_code)]
                 #[inline]
                 fn deref(&self) -> &Self::Target {
                     #unchecked_safety_comment
-                    unsafe { #ref_ty::from_str_unchecked(::#core::convert::AsRef::as_ref(&self.#field_name)) }
+                    unsafe { <#ref_ty>::#from_raw_unchecked(::#core::convert::AsRef::<#unsized_ty>::as_ref(&self.#field_name)) }
                 }
             }
         }
     }
 
-    fn normalized_conversion(&self, normalizer: &crate::grammar::Type) -> proc_macro2::TokenStream {
-        let ty = self.ty;
-        let ref_ty = self.ref_ty;
-        let field_name = &self.field.name;
-        let field_ty = &self.field.ty;
-        let validator = crate::as_validator(normalizer);
+    /// Conversions of a C string braid from bytes or strings, which fail if the value contains an
+    /// interior nul byte. For a checked braid, the validator's error must be able to represent
+    /// that failure by implementing `From<NulError>`.
+    fn nul_checked_conversion(&self) -> Option<proc_macro2::TokenStream> {
+        if self.slice != Slice::CStr {
+            return None;
+        }
+
+        let ty = self.owned_type();
         let core = self.std_lib.core();
         let alloc = self.std_lib.alloc();
-        let unchecked_safety_comment = Self::unchecked_safety_comment(true);
+        let impl_generics = self.generics.impl_generics();
+        let where_clause = self.generics.where_clause();
+        let (error, construct) = match &self.check_mode {
+            CheckMode::None => (
+                quote! { ::#alloc::ffi::NulError },
+                quote! { ::#core::result::Result::Ok(Self::new(::#core::convert::From::from(raw))) },
+            ),
+            CheckMode::Validate(checker) | CheckMode::Normalize(checker) => {
+                let validator = self.slice.validator(checker);
+                (
+                    quote! { #validator::Error },
+                    quote! { Self::new(::#core::convert::From::from(raw)) },
+                )
+            }
+        };
+
+        let sources = [
+            quote! { ::#alloc::vec::Vec<u8> },
+            quote! { ::#alloc::string::String },
+            quote! { &'_ [u8] },
+            quote! { &'_ str },
+        ];
+        let impls = sources.iter().map(|source| {
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics ::#core::convert::TryFrom<#source> for #ty #where_clause {
+                    type Error = #error;
+
+                    #[inline]
+                    fn try_from(raw: #source) -> ::#core::result::Result<Self, Self::Error> {
+                        let raw = ::#alloc::ffi::CString::new(raw)?;
+                        #construct
+                    }
+                }
+            }
+        });
+
+        Some(quote! { #(#impls)* })
+    }
+
+    fn conversion(&self) -> proc_macro2::TokenStream {
+        let common = self.common_conversion();
+        let convert = match &self.check_mode {
+            CheckMode::None => self.infallible_conversion(),
+            CheckMode::Validate(validator) => self.checked_conversion(validator, false),
+            CheckMode::Normalize(normalizer) => self.checked_conversion(normalizer, true),
+        };
+        let nul_checked = self.nul_checked_conversion();
+        let facet_proxy = (self.impls.facet.has_owned_impl()
+            && !matches!(self.check_mode, CheckMode::None))
+        .then(|| {
+            ImplFacet::proxy_conversion(
+                &self.owned_type(),
+                self.generics,
+                self.std_lib.core(),
+                &self.slice.buf_ty(self.std_lib.alloc()),
+                &self.slice.method("as_", ""),
+            )
+        });
 
         quote! {
-            #[automatically_derived]
-            impl ::#core::convert::TryFrom<::#alloc::string::String> for #ty {
-                type Error = #validator::Error;
+            #common
+            #convert
+            #nul_checked
+            #facet_proxy
+        }
+    }
 
-                #[inline]
-                fn try_from(s: ::#alloc::string::String) -> ::#core::result::Result<Self, Self::Error> {
-                    const fn ensure_try_from_string_error_converts_to_validator_error<T: ?Sized + From<<#field_ty as ::#core::convert::TryFrom<::#alloc::string::String>>::Error>>() {}
-                    ensure_try_from_string_error_converts_to_validator_error::<Self::Error>();
+    /// The struct definition, with a marker field added for any generic parameters.
+    fn definition(&self) -> proc_macro2::TokenStream {
+        definition(self.body, self.field, self.generics)
+    }
 
-                    Self::new(::#core::convert::TryFrom::try_from(s)?)
-                }
-            }
+    /// The values of `self` and `other` that comparison impls look at: the wrapped values, or
+    /// their raw slices when the braid ignores case.
+    pub fn comparands(&self) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+        let field_name = &self.field.name;
+        let as_raw = self.slice.method("as_", "");
+        if self.case.is_sensitive() {
+            (quote! { &self.#field_name }, quote! { &other.#field_name })
+        } else {
+            (quote! { self.#as_raw() }, quote! { other.#as_raw() })
+        }
+    }
 
-            #[automatically_derived]
-            impl ::#core::convert::TryFrom<&'_ str> for #ty {
-                type Error = #validator::Error;
+    fn borrow_raw(&self) -> Option<proc_macro2::TokenStream> {
+        let as_raw = self.slice.method("as_", "");
+        self.case.borrow_raw(
+            self.std_lib.core(),
+            self.generics,
+            self.owned_type(),
+            &self.slice.unsized_ty(),
+            quote! { self.#as_raw() },
+        )
+    }
 
-                #[inline]
-                fn try_from(s: &str) -> ::#core::result::Result<Self, Self::Error> {
-                    let ref_ty = #ref_ty::from_str(s)?;
-                    ::#core::result::Result::Ok(ref_ty.into_owned())
-                }
-            }
+    /// Manual comparison impls for generic braids, which only compare the wrapped string, and for
+    /// braids that ignore case.
+    fn comparison(&self) -> proc_macro2::TokenStream {
+        let ty = self.owned_type();
+        let core = self.std_lib.core();
+        let impl_generics = self.generics.impl_generics();
+        let where_clause = self.generics.where_clause();
+        let (this, that) = self.comparands();
+        let hash = self.case.hash(core, this.clone());
+        let eq = self.case.eq(this, that);
 
+        quote! {
             #[automatically_derived]
-            impl ::#core::str::FromStr for #ty {
-                type Err = #validator::Error;
-
+            impl #impl_generics ::#core::hash::Hash for #ty #where_clause {
                 #[inline]
-                fn from_str(s: &str) -> ::#core::result::Result<Self, Self::Err> {
-                    let ref_ty = #ref_ty::from_str(s)?;
-                    ::#core::result::Result::Ok(ref_ty.into_owned())
+                fn hash<H: ::#core::hash::Hasher>(&self, state: &mut H) {
+                    #hash
                 }
             }
 
             #[automatically_derived]
-            impl ::#core::ops::Deref for #ty {
-                type Target = #ref_ty;
-
-                #[allow(unsafe_code)]
+            impl #impl_generics ::#core::cmp::PartialEq for #ty #where_clause {
                 #[inline]
-                fn deref(&self) -> &Self::Target {
-                    #unchecked_safety_comment
-                    unsafe { #ref_ty::from_str_unchecked(&self.#field_name) }
+                fn eq(&self, other: &Self) -> bool {
+                    #eq
                 }
             }
-        }
-    }
 
-    fn conversion(&self) -> proc_macro2::TokenStream {
-        let common = self.common_conversion();
-        let convert = match &self.check_mode {
-            CheckMode::None => self.infallible_conversion(),
-            CheckMode::Validate(validator) => self.fallible_conversion(validator),
-            CheckMode::Normalize(normalizer) => self.normalized_conversion(normalizer),
-        };
-
-        quote! {
-            #common
-            #convert
+            #[automatically_derived]
+            impl #impl_generics ::#core::cmp::Eq for #ty #where_clause {}
         }
     }
 
@@ -563,34 +825,41 @@ impl<'a> OwnedCodeGen<'a> {
         let display = self.impls.display.to_owned_impl(self);
         let debug = self.impls.debug.to_owned_impl(self);
         let ord = self.impls.ord.to_owned_impl(self);
+        let facet = self.impls.facet.to_owned_impl(self);
         let serde = self.impls.serde.to_owned_impl(self);
         let rusqlite = self.impls.rusqlite.to_owned_impl(self);
+        let diesel = self.impls.diesel.to_owned_impl(self);
+        let postgres = self.impls.postgres.to_owned_impl(self);
         let sailfish = self.impls.sailfish.to_owned_impl(self);
-
-        let owned_attrs: proc_macro2::TokenStream =
-            self.attrs.iter().map(|a| quote! {#[#a]}).collect();
-        let body = &self.body;
+        let sqlx = self.impls.sqlx.to_owned_impl(self);
+        let schemars = self.impls.schemars.to_owned_impl(self);
+        let utoipa = self.impls.utoipa.to_owned_impl(self);
+        let ts_rs = self.impls.ts_rs.to_owned_impl(self);
+        let proptest = self.impls.proptest.to_owned_impl(self);
+        let arbitrary = self.impls.arbitrary.to_owned_impl(self);
+        let quickcheck = self.impls.quickcheck.to_owned_impl(self);
+
+        let owned_attrs = self.attrs;
+        let body = self.definition();
         let inherent = self.inherent();
         let conversion = self.conversion();
 
-        // Only add transparent attribute for tuple structs (unnamed fields)
-        let facet_attr = match &self.field.name {
-            super::FieldName::Unnamed => quote! {
-                #[facet(crate = ::strid::facet, transparent)]
-            },
-            super::FieldName::Named(_) => quote! {
-                #[facet(crate = ::strid::facet)]
-            },
+        // Deriving the comparison traits for a generic braid would needlessly bound the parameters
+        let (derives, comparison) = if self.generics.is_empty() && self.case.is_sensitive() {
+            (Some(quote! { #[derive(Hash, PartialEq, Eq)] }), None)
+        } else {
+            (None, Some(self.comparison()))
         };
 
         quote! {
             #clone
-            #[derive(Hash, PartialEq, Eq, ::strid::facet::Facet)]
-            #facet_attr
+            #derives
+            #facet
             #[repr(transparent)]
-            #owned_attrs
+            #(#owned_attrs)*
             #body
 
+            #comparison
             #inherent
             #conversion
             #debug
@@ -598,7 +867,67 @@ impl<'a> OwnedCodeGen<'a> {
             #ord
             #serde
             #rusqlite
+            #diesel
+            #postgres
             #sailfish
+            #sqlx
+            #schemars
+            #utoipa
+            #ts_rs
+            #proptest
+            #arbitrary
+            #quickcheck
+        }
+    }
+}
+
+/// The definition of an owned struct, with a marker field added for any generic parameters.
+pub fn definition(
+    body: &crate::grammar::ItemStruct,
+    field: &Field,
+    generics: &Generics,
+) -> proc_macro2::TokenStream {
+    let Some(marker) = field.marker_decl() else {
+        return body.to_token_stream();
+    };
+
+    let crate::grammar::ItemStruct {
+        attrs,
+        vis,
+        ident,
+        fields,
+        ..
+    } = body;
+    let vis = vis.as_ref().map(unsynn::ToTokens::to_token_stream);
+    let decl_generics = generics.decl_generics();
+    let where_clause = generics.where_clause();
+
+    let fields = match unsynn::ToTokens::to_token_stream(fields).into_iter().next() {
+        Some(proc_macro2::TokenTree::Group(group)) => {
+            let mut stream = group.stream();
+            let has_trailing_comma = matches!(
+                stream.clone().into_iter().last(),
+                Some(proc_macro2::TokenTree::Punct(p)) if p.as_char() == ','
+            );
+            if !has_trailing_comma {
+                stream.extend(quote! { , });
+            }
+            stream.extend(marker);
+            let mut fields = proc_macro2::Group::new(group.delimiter(), stream);
+            fields.set_span(group.span());
+            fields
         }
+        _ => unreachable!("a field is always added to the braid"),
+    };
+
+    // The where clause comes after the fields of a tuple struct but before named fields
+    let body = match fields.delimiter() {
+        proc_macro2::Delimiter::Parenthesis => quote! { #fields #where_clause; },
+        _ => quote! { #where_clause #fields },
+    };
+
+    quote! {
+        #(#attrs)*
+        #vis struct #ident #decl_generics #body
     }
 }
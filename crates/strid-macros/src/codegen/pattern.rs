@@ -0,0 +1,48 @@
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+use super::{Generics, StdLib};
+
+/// A regular expression given with `pattern = "..."`, which the braid uses as its validator
+#[derive(Clone, Debug)]
+pub struct Pattern {
+    pub lit: Literal,
+    /// Whether the braid also describes itself with the pattern through `strid::Constraints`
+    pub constraints: bool,
+}
+
+impl Pattern {
+    /// Implements `strid::Validator`, and `strid::Constraints` if requested, for `ty`
+    pub fn tokens(&self, ty: &TokenStream, generics: &Generics, std_lib: &StdLib) -> TokenStream {
+        let lit = &self.lit;
+        let core = std_lib.core();
+        let impl_generics = generics.impl_generics();
+        let where_clause = generics.where_clause();
+
+        let constraints = self.constraints.then(|| {
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics ::strid::Constraints for #ty #where_clause {
+                    const PATTERN: ::#core::option::Option<&'static str> =
+                        ::#core::option::Option::Some(#lit);
+                }
+            }
+        });
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::strid::Validator for #ty #where_clause {
+                type Error = ::strid::PatternError;
+
+                #[inline]
+                fn validate(raw: &str) -> ::#core::result::Result<(), Self::Error> {
+                    static PATTERN: ::strid::__private::LazyPattern =
+                        ::strid::__private::LazyPattern::new(#lit);
+                    PATTERN.validate(raw)
+                }
+            }
+
+            #constraints
+        }
+    }
+}
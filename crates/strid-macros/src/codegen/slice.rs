@@ -0,0 +1,330 @@
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
+use quote::quote;
+use unsynn::Ident;
+
+use super::{get_field_info, symbol};
+use crate::error::Error;
+
+/// The unsized type that the borrowed form of a braid wraps, along with the owned buffer of that
+/// type that the owned form holds by default
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Slice {
+    /// `str`, owned as a `String`
+    Str,
+    /// `[u8]`, owned as a `Vec<u8>`
+    Bytes,
+    /// `OsStr`, owned as an `OsString`
+    OsStr,
+    /// `Path`, owned as a `PathBuf`
+    Path,
+    /// `CStr`, owned as a `CString`
+    CStr,
+}
+
+impl Slice {
+    /// The kind of a `braid`, which wraps a C string when its field is a `CString`
+    pub fn of_owned(body: &crate::grammar::ItemStruct) -> Self {
+        if field_is(body, "CString") {
+            Self::CStr
+        } else {
+            Self::Str
+        }
+    }
+
+    /// The kind of a `braid_ref`, which follows the unsized type of its field
+    pub fn of_borrowed(body: &crate::grammar::ItemStruct) -> Self {
+        if field_is(body, "CStr") {
+            Self::CStr
+        } else if field_is(body, "Path") {
+            Self::Path
+        } else if field_is(body, "OsStr") {
+            Self::OsStr
+        } else if field_is_bytes(body) {
+            Self::Bytes
+        } else {
+            Self::Str
+        }
+    }
+
+    /// Whether braids of this kind take the argument `name`. Only string braids can be displayed,
+    /// rendered, or described by a schema, and the database and fuzzing integrations only know
+    /// how to store strings and bytes.
+    pub fn supports(self, name: &Ident) -> bool {
+        const STR_ONLY: [symbol::Symbol; 9] = [
+            symbol::DISPLAY,
+            symbol::PATTERN,
+            symbol::CONSTRAINTS,
+            symbol::SAILFISH,
+            symbol::SCHEMARS,
+            symbol::UTOIPA,
+            symbol::TS_RS,
+            symbol::QUICKCHECK,
+            symbol::QUICKCHECK_GENERATOR,
+        ];
+        const STR_OR_BYTES: [symbol::Symbol; 9] = [
+            symbol::CASE,
+            symbol::FACET,
+            symbol::RUSQLITE,
+            symbol::DIESEL,
+            symbol::POSTGRES,
+            symbol::SQLX,
+            symbol::ARBITRARY,
+            symbol::PROPTEST,
+            symbol::PROPTEST_STRATEGY,
+        ];
+        let is_any = |symbols: &[symbol::Symbol]| symbols.iter().any(|s| name == *s);
+
+        match self {
+            Self::Str => true,
+            Self::Bytes => !is_any(&STR_ONLY),
+            Self::CStr => !is_any(&STR_ONLY) && !is_any(&STR_OR_BYTES),
+            // Paths and OS strings only exist with `std`, and have no `const` accessors to check
+            Self::OsStr | Self::Path => {
+                !is_any(&STR_ONLY)
+                    && !is_any(&STR_OR_BYTES)
+                    && !is_any(&[symbol::NO_STD, symbol::CONST_VALIDATOR])
+            }
+        }
+    }
+
+    /// The error for an argument that braids of this kind don't take
+    pub fn unsupported(self, name: &Ident) -> Error {
+        let kind = match self {
+            Self::Str => String::new(),
+            Self::Bytes => " for a `[u8]` braid".to_owned(),
+            Self::OsStr => " for an `OsStr` braid".to_owned(),
+            Self::Path => " for a `Path` braid".to_owned(),
+            Self::CStr => " for a `CStr` braid".to_owned(),
+        };
+
+        Error::new(
+            name.span(),
+            format!("unsupported argument `{}`{}", name, kind),
+        )
+    }
+
+    /// The suffix of the generated accessors and constructors, as in `as_bytes` and `from_bytes`
+    fn method_suffix(self) -> &'static str {
+        match self {
+            Self::Str => "str",
+            Self::Bytes => "bytes",
+            Self::OsStr => "os_str",
+            Self::Path => "path",
+            Self::CStr => "c_str",
+        }
+    }
+
+    /// An ident combining `prefix` with the method suffix, such as `from_path_unchecked`
+    pub fn method(self, prefix: &str, suffix: &str) -> Ident {
+        Ident::new(
+            &format!("{prefix}{}{suffix}", self.method_suffix()),
+            Span::call_site(),
+        )
+    }
+
+    /// How the raw value is described in generated docs
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Str => "string slice",
+            Self::Bytes => "byte slice",
+            Self::OsStr => "OS string slice",
+            Self::Path => "path",
+            Self::CStr => "C string",
+        }
+    }
+
+    /// How the argument of `from_static` is described in generated docs
+    pub fn static_description(self) -> &'static str {
+        match self {
+            Self::OsStr | Self::Path => "string",
+            Self::Str | Self::Bytes | Self::CStr => self.description(),
+        }
+    }
+
+    /// The `# Panics` section of a checked `from_static`, which panics when the value is
+    /// `problem`, such as "not valid"
+    pub fn static_panic_doc(self, problem: &str) -> String {
+        let raw = match self {
+            Self::Str => "raw string",
+            _ => self.static_description(),
+        };
+        format!("This function will panic if the provided {raw} is {problem}.")
+    }
+
+    /// The unsized type as it is named in generated docs
+    pub fn unsized_name(self) -> &'static str {
+        match self {
+            Self::Str => "str",
+            Self::Bytes => "[u8]",
+            Self::OsStr => "OsStr",
+            Self::Path => "Path",
+            Self::CStr => "CStr",
+        }
+    }
+
+    /// The unsized type that the borrowed form wraps
+    pub fn unsized_ty(self) -> TokenStream {
+        match self {
+            Self::Str => quote! { str },
+            Self::Bytes => quote! { [u8] },
+            Self::OsStr => quote! { ::std::ffi::OsStr },
+            Self::Path => quote! { ::std::path::Path },
+            Self::CStr => quote! { ::core::ffi::CStr },
+        }
+    }
+
+    /// The field type of an owned braid declared without one
+    pub fn default_field_ty(self) -> &'static str {
+        match self {
+            Self::Str => "String",
+            Self::Bytes => "Vec<u8>",
+            Self::OsStr => "::std::ffi::OsString",
+            Self::Path => "::std::path::PathBuf",
+            Self::CStr => "::std::ffi::CString",
+        }
+    }
+
+    /// The standard owned buffer of the unsized type
+    pub fn buf_ty(self, alloc: &Ident) -> TokenStream {
+        match self {
+            Self::Str => quote! { ::#alloc::string::String },
+            Self::Bytes => quote! { ::#alloc::vec::Vec<u8> },
+            Self::OsStr => quote! { ::std::ffi::OsString },
+            Self::Path => quote! { ::std::path::PathBuf },
+            Self::CStr => quote! { ::#alloc::ffi::CString },
+        }
+    }
+
+    /// Converts the borrowed `value` into the field of an owned braid. The field of a string
+    /// braid may be anything that converts from `&str`, while other fields convert from a copy
+    /// in the standard buffer, since few types convert from a borrowed `[u8]` or `Path`.
+    pub fn to_field(self, core: &Ident, alloc: &Ident, value: TokenStream) -> TokenStream {
+        let value = match self {
+            Self::Str => value,
+            Self::Bytes => quote! { <[u8]>::to_vec(#value) },
+            Self::OsStr => quote! { ::std::ffi::OsStr::to_os_string(#value) },
+            Self::Path => quote! { ::std::path::Path::to_path_buf(#value) },
+            Self::CStr => quote! { ::#alloc::ffi::CString::from(#value) },
+        };
+
+        quote! { ::#core::convert::From::from(#value) }
+    }
+
+    /// Converts the owned `value` into a box of the unsized type
+    pub fn into_boxed(self, alloc: &Ident, value: TokenStream) -> TokenStream {
+        match self {
+            Self::Str => quote! { ::#alloc::string::String::from(#value).into_boxed_str() },
+            Self::Bytes => quote! { ::#alloc::vec::Vec::<u8>::from(#value).into_boxed_slice() },
+            Self::OsStr => quote! { ::std::ffi::OsString::from(#value).into_boxed_os_str() },
+            Self::Path => quote! { ::std::path::PathBuf::from(#value).into_boxed_path() },
+            Self::CStr => quote! { ::#alloc::ffi::CString::from(#value).into_boxed_c_str() },
+        }
+    }
+
+    /// The type accepted by `from_static`, which is a string for paths, since there are no
+    /// literals of `Path` or `OsStr`
+    pub fn static_ty(self) -> TokenStream {
+        match self {
+            Self::Str | Self::OsStr | Self::Path => quote! { str },
+            Self::Bytes => quote! { [u8] },
+            Self::CStr => quote! { ::core::ffi::CStr },
+        }
+    }
+
+    /// Whether `from_static` can be `const` when there is nothing to check, which it can't be
+    /// for paths, since `Path::new` and `OsStr::new` aren't `const`
+    pub fn has_const_static(self) -> bool {
+        !matches!(self, Self::OsStr | Self::Path)
+    }
+
+    /// Converts the `raw` argument of `from_static` into the unsized type
+    pub fn static_raw(self) -> TokenStream {
+        match self {
+            Self::Str | Self::Bytes | Self::CStr => quote! { raw },
+            Self::OsStr => quote! { ::std::ffi::OsStr::new(raw) },
+            Self::Path => quote! { ::std::path::Path::new(raw) },
+        }
+    }
+
+    /// The types that both forms of the braid implement `AsRef` for
+    pub fn as_ref_tys(self) -> Vec<TokenStream> {
+        match self {
+            Self::Str => vec![quote! { str }],
+            Self::Bytes => vec![quote! { [u8] }],
+            Self::OsStr => vec![quote! { ::std::ffi::OsStr }, quote! { ::std::path::Path }],
+            Self::Path => vec![quote! { ::std::path::Path }, quote! { ::std::ffi::OsStr }],
+            Self::CStr => vec![quote! { ::core::ffi::CStr }],
+        }
+    }
+
+    /// The name of the `strid` trait that validates values of this kind
+    fn validator_trait(self) -> Ident {
+        let name = match self {
+            Self::Str => "Validator",
+            Self::Bytes => "ByteValidator",
+            Self::OsStr => "OsStrValidator",
+            Self::Path => "PathValidator",
+            Self::CStr => "CStrValidator",
+        };
+        Ident::new(name, Span::call_site())
+    }
+
+    /// The name of the `strid` trait that normalizes values of this kind
+    fn normalizer_trait(self) -> Ident {
+        let name = match self {
+            Self::Str => "Normalizer",
+            Self::Bytes => "ByteNormalizer",
+            Self::OsStr => "OsStrNormalizer",
+            Self::Path => "PathNormalizer",
+            Self::CStr => "CStrNormalizer",
+        };
+        Ident::new(name, Span::call_site())
+    }
+
+    /// The validator as the `strid` trait for this kind, e.g. `<T as ::strid::Validator>`
+    pub fn validator(self, validator: &crate::grammar::Type) -> TokenStream {
+        let ty = validator.to_token_stream();
+        let trait_ = self.validator_trait();
+        quote! { <#ty as ::strid::#trait_> }
+    }
+
+    /// The normalizer as the `strid` trait for this kind, e.g. `<T as ::strid::Normalizer>`
+    pub fn normalizer(self, normalizer: &crate::grammar::Type) -> TokenStream {
+        let ty = normalizer.to_token_stream();
+        let trait_ = self.normalizer_trait();
+        quote! { <#ty as ::strid::#trait_> }
+    }
+}
+
+/// The tokens of the type of the field of `body`, if it has a single field
+fn field_tokens(body: &crate::grammar::ItemStruct) -> Option<Vec<TokenTree>> {
+    let (ty, ..) = get_field_info(&body.ident, &body.fields).ok()?;
+    Some(ty.to_token_stream().into_iter().collect())
+}
+
+/// Whether the field of `body` is of the type with the given name, however it's qualified
+fn field_is(body: &crate::grammar::ItemStruct, ty_name: &str) -> bool {
+    let Some(tokens) = field_tokens(body) else {
+        return false;
+    };
+    let Some((TokenTree::Ident(name), path)) = tokens.split_last() else {
+        return false;
+    };
+
+    name == ty_name
+        && path.iter().all(|tt| match tt {
+            TokenTree::Ident(_) => true,
+            TokenTree::Punct(p) => p.as_char() == ':',
+            _ => false,
+        })
+}
+
+/// Whether the field of `body` is a `[u8]`
+fn field_is_bytes(body: &crate::grammar::ItemStruct) -> bool {
+    match field_tokens(body).as_deref() {
+        Some([TokenTree::Group(g)]) => {
+            g.delimiter() == Delimiter::Bracket && g.stream().to_string() == "u8"
+        }
+        _ => false,
+    }
+}
@@ -1,6 +1,12 @@
-use std::fmt::{self, Display};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
 
-use unsynn::{IParse, Ident, Literal, ToTokenIter};
+use proc_macro2::{Group, Span, TokenStream};
+use unsynn::{IParse, Ident, Literal, ToTokenIter, TokenTree};
+
+use crate::error::{Error, Errors, Result};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Symbol(&'static str);
@@ -11,15 +17,31 @@ pub const CLONE: Symbol = Symbol("clone");
 pub const DEBUG: Symbol = Symbol("debug");
 pub const DISPLAY: Symbol = Symbol("display");
 pub const ORD: Symbol = Symbol("ord");
+pub const CASE: Symbol = Symbol("case");
+pub const FACET: Symbol = Symbol("facet");
 pub const SERDE: Symbol = Symbol("serde");
 pub const RUSQLITE: Symbol = Symbol("rusqlite");
+pub const DIESEL: Symbol = Symbol("diesel");
+pub const POSTGRES: Symbol = Symbol("postgres");
 pub const SAILFISH: Symbol = Symbol("sailfish");
+pub const SQLX: Symbol = Symbol("sqlx");
+pub const SCHEMARS: Symbol = Symbol("schemars");
+pub const UTOIPA: Symbol = Symbol("utoipa");
+pub const TS_RS: Symbol = Symbol("ts_rs");
+pub const PROPTEST: Symbol = Symbol("proptest");
+pub const PROPTEST_STRATEGY: Symbol = Symbol("proptest_strategy");
+pub const ARBITRARY: Symbol = Symbol("arbitrary");
+pub const QUICKCHECK: Symbol = Symbol("quickcheck");
+pub const QUICKCHECK_GENERATOR: Symbol = Symbol("quickcheck_generator");
 pub const REF: Symbol = Symbol("ref_name");
 pub const REF_DOC: Symbol = Symbol("ref_doc");
 pub const REF_ATTR: Symbol = Symbol("ref_attr");
 pub const OWNED_ATTR: Symbol = Symbol("owned_attr");
 pub const NO_STD: Symbol = Symbol("no_std");
 pub const NO_EXPOSE: Symbol = Symbol("no_expose");
+pub const CONST_VALIDATOR: Symbol = Symbol("const_validator");
+pub const PATTERN: Symbol = Symbol("pattern");
+pub const CONSTRAINTS: Symbol = Symbol("constraints");
 pub const VALIDATOR: Symbol = Symbol(super::check_mode::VALIDATOR);
 pub const NORMALIZER: Symbol = Symbol(super::check_mode::NORMALIZER);
 
@@ -41,7 +63,7 @@ impl Display for Symbol {
     }
 }
 
-fn get_lit_str(attr_name: Symbol, lit: &Literal) -> Result<String, String> {
+fn get_lit_str(attr_name: Symbol, lit: &Literal) -> Result<String> {
     // proc_macro2::Literal doesn't have variants, so we parse its string representation
     let lit_str = lit.to_string();
 
@@ -50,35 +72,178 @@ fn get_lit_str(attr_name: Symbol, lit: &Literal) -> Result<String, String> {
         // Remove the surrounding quotes and unescape
         Ok(lit_str[1..lit_str.len() - 1].to_string())
     } else {
-        Err(format!(
-            "expected attribute `{}` to have a string value (`{} = \"value\"`)",
-            attr_name, attr_name
+        Err(Error::new(
+            lit.span(),
+            format!(
+                "expected attribute `{}` to have a string value (`{} = \"value\"`)",
+                attr_name, attr_name
+            ),
         ))
     }
 }
 
-// fn parse_lit_into_path(attr_name: Symbol, lit: &syn::Lit) -> Result<syn::Path, ()> {
-//     let string = get_lit_str( attr_name, lit)?;
-//     parse_lit_str(string).map_err(|_| {
-//         syn::Error::new_spanned(lit, format!("failed to parse path: {:?}", string.value()))
-//     })
-// }
-
 /// Parse a literal into a string.
-pub(super) fn parse_lit_into_string(attr_name: Symbol, lit: &Literal) -> Result<String, String> {
+pub(super) fn parse_lit_into_string(attr_name: Symbol, lit: &Literal) -> Result<String> {
     get_lit_str(attr_name, lit)
 }
 
 /// Parse a string literal into a type by parsing its contents.
+///
+/// The tokens of the type take on the span of the literal, so that errors involving the type
+/// point back at the string it came from.
 pub(super) fn parse_lit_into_type(
-    _attr_name: Symbol,
-    string: &str,
-) -> Result<crate::grammar::Type, String> {
-    let tokens: proc_macro2::TokenStream = string
-        .parse()
-        .map_err(|e| format!("failed to parse type from string: {}", e))?;
+    attr_name: Symbol,
+    lit: &Literal,
+) -> Result<crate::grammar::Type> {
+    let string = parse_lit_into_string(attr_name, lit)?;
+    let tokens: TokenStream = string.parse().map_err(|e| {
+        Error::new(
+            lit.span(),
+            format!("failed to parse type from string: {}", e),
+        )
+    })?;
+    let tokens = respan(tokens, lit.span());
 
     let mut iter = tokens.to_token_iter();
-    iter.parse::<crate::grammar::Type>()
-        .map_err(|e| format!("failed to parse type: {}", e))
+    let ty = iter
+        .parse::<crate::grammar::Type>()
+        .map_err(|e| Error::new(lit.span(), format!("failed to parse type: {}", e)))?;
+    match iter.next() {
+        None => Ok(ty),
+        Some(_) => Err(Error::new(
+            lit.span(),
+            format!("failed to parse type: unexpected tokens in `{}`", string),
+        )),
+    }
+}
+
+/// Parse a string literal into a regular expression, checking that the expression is valid.
+///
+/// The literal itself is returned so that the generated code sees exactly the same string as the
+/// one that was checked here, escapes and all.
+pub(super) fn parse_lit_into_pattern(attr_name: Symbol, lit: &Literal) -> Result<Literal> {
+    let pattern = unescape_lit_str(&lit.to_string()).ok_or_else(|| {
+        Error::new(
+            lit.span(),
+            format!(
+                "expected attribute `{}` to have a string value (`{} = \"value\"`)",
+                attr_name, attr_name
+            ),
+        )
+    })?;
+    regex_syntax::parse(&pattern).map_err(|e| {
+        let reason = match &e {
+            regex_syntax::Error::Parse(e) => e.kind().to_string(),
+            regex_syntax::Error::Translate(e) => e.kind().to_string(),
+            e => e.to_string(),
+        };
+        Error::new(lit.span(), format!("invalid `{}`: {}", attr_name, reason))
+    })?;
+    Ok(lit.clone())
+}
+
+/// Recover the value of a string literal, including raw strings, from its source representation.
+///
+/// Returns `None` if the literal is not a string literal.
+fn unescape_lit_str(repr: &str) -> Option<String> {
+    if let Some(raw) = repr.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let body = raw.get(hashes..raw.len().checked_sub(hashes)?)?;
+        return body.strip_prefix('"')?.strip_suffix('"').map(str::to_owned);
+    }
+
+    let body = repr.strip_prefix('"')?.strip_suffix('"')?;
+    let mut value = String::with_capacity(body.len());
+    let mut chars = body.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => value.push('\n'),
+            'r' => value.push('\r'),
+            't' => value.push('\t'),
+            '0' => value.push('\0'),
+            '\\' => value.push('\\'),
+            '\'' => value.push('\''),
+            '"' => value.push('"'),
+            'x' => {
+                let code: String = chars.by_ref().take(2).collect();
+                value.push(char::from(u8::from_str_radix(&code, 16).ok()?));
+            }
+            'u' => {
+                if chars.next()? != '{' {
+                    return None;
+                }
+                let code: String = chars
+                    .by_ref()
+                    .take_while(|&c| c != '}')
+                    .filter(|&c| c != '_')
+                    .collect();
+                value.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+            }
+            '\n' => while chars.next_if(|c| c.is_whitespace()).is_some() {},
+            _ => return None,
+        }
+    }
+    Some(value)
+}
+
+/// Parse a string literal into one of the options for an impl, such as `"impl"` or `"omit"`.
+pub(super) fn parse_lit_into_option<T>(attr_name: Symbol, lit: &Literal) -> Result<T>
+where
+    T: FromStr<Err = &'static str>,
+{
+    let value = parse_lit_into_string(attr_name, lit)?;
+    value.parse().map_err(|e| {
+        Error::new(
+            lit.span(),
+            format!("invalid value `{}` for `{}`: {}", value, attr_name, e),
+        )
+    })
+}
+
+/// Parse the contents of a list argument, such as `owned_attr(derive(Default), must_use)`, into
+/// the attributes that it describes.
+pub(super) fn parse_list_into_attrs(
+    attr_name: Symbol,
+    contents: &[TokenTree],
+) -> Result<Vec<crate::grammar::Attribute>> {
+    let mut errors = Errors::default();
+    let attrs = contents
+        .split(|tt| matches!(tt, TokenTree::Punct(p) if p.as_char() == ','))
+        .filter(|meta| !meta.is_empty())
+        .filter_map(|meta| {
+            let meta: TokenStream = meta.iter().cloned().collect();
+            let tokens = quote::quote! { #[#meta] };
+            let mut iter = tokens.to_token_iter();
+            let attr = iter.parse::<crate::grammar::Attribute>().map_err(|e| {
+                Error::new_spanned(
+                    &meta,
+                    format!("failed to parse attribute in `{}`: {}", attr_name, e),
+                )
+            });
+            errors.handle(attr)
+        })
+        .collect();
+
+    errors.finish()?;
+    Ok(attrs)
+}
+
+fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|mut tt| {
+            if let TokenTree::Group(g) = &tt {
+                let mut group = Group::new(g.delimiter(), respan(g.stream(), span));
+                group.set_span(span);
+                tt = TokenTree::Group(group);
+            } else {
+                tt.set_span(span);
+            }
+            tt
+        })
+        .collect()
 }
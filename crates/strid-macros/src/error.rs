@@ -0,0 +1,115 @@
+//! Span-aware errors for reporting problems back to the user of the macros.
+
+use std::fmt::Display;
+
+use proc_macro2::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+use quote::{ToTokens, TokenStreamExt};
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// One or more error messages, each attached to the span of the tokens that caused it.
+#[derive(Debug)]
+pub struct Error {
+    messages: Vec<ErrorMessage>,
+}
+
+#[derive(Debug)]
+struct ErrorMessage {
+    start: Span,
+    end: Span,
+    message: String,
+}
+
+impl Error {
+    /// Creates an error pointing at a single span.
+    pub fn new(span: Span, message: impl Display) -> Self {
+        Self {
+            messages: vec![ErrorMessage {
+                start: span,
+                end: span,
+                message: message.to_string(),
+            }],
+        }
+    }
+
+    /// Creates an error pointing at the full range of `tokens`, falling back to the call site if
+    /// there are no tokens to point at.
+    pub fn new_spanned(tokens: impl ToTokens, message: impl Display) -> Self {
+        let mut iter = tokens.into_token_stream().into_iter();
+        let start = iter.next().map_or_else(Span::call_site, |tt| tt.span());
+        let end = iter.last().map_or(start, |tt| tt.span());
+        Self {
+            messages: vec![ErrorMessage {
+                start,
+                end,
+                message: message.to_string(),
+            }],
+        }
+    }
+
+    /// Adds the messages from `other` to this error.
+    pub fn combine(&mut self, other: Error) {
+        self.messages.extend(other.messages);
+    }
+
+    /// Renders every message as a `compile_error!` invocation at its span.
+    pub fn to_compile_error(&self) -> TokenStream {
+        self.messages
+            .iter()
+            .map(ErrorMessage::to_compile_error)
+            .collect()
+    }
+}
+
+impl ErrorMessage {
+    // `compile_error!` reports the range between the span of the path and the span of its
+    // arguments, so giving them the first and last spans covers all of the offending tokens.
+    fn to_compile_error(&self) -> TokenStream {
+        let punct = |ch, spacing| {
+            let mut punct = Punct::new(ch, spacing);
+            punct.set_span(self.start);
+            punct
+        };
+
+        let mut tokens = TokenStream::new();
+        tokens.append(punct(':', Spacing::Joint));
+        tokens.append(punct(':', Spacing::Alone));
+        tokens.append(Ident::new("core", self.start));
+        tokens.append(punct(':', Spacing::Joint));
+        tokens.append(punct(':', Spacing::Alone));
+        tokens.append(Ident::new("compile_error", self.start));
+        tokens.append(punct('!', Spacing::Alone));
+
+        let mut message = Literal::string(&self.message);
+        message.set_span(self.end);
+        let mut group = Group::new(Delimiter::Brace, TokenTree::from(message).into());
+        group.set_span(self.end);
+        tokens.append(group);
+
+        tokens
+    }
+}
+
+/// Accumulates errors so that several problems can be reported from a single expansion.
+#[derive(Debug, Default)]
+pub struct Errors(Option<Error>);
+
+impl Errors {
+    /// Records an error.
+    pub fn push(&mut self, error: Error) {
+        match &mut self.0 {
+            Some(existing) => existing.combine(error),
+            None => self.0 = Some(error),
+        }
+    }
+
+    /// Records the error, if any, and returns the successful value otherwise.
+    pub fn handle<T>(&mut self, result: Result<T>) -> Option<T> {
+        result.map_err(|e| self.push(e)).ok()
+    }
+
+    /// Fails with every recorded error, if there were any.
+    pub fn finish(self) -> Result<()> {
+        self.0.map_or(Ok(()), Err)
+    }
+}
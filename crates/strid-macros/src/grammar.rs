@@ -92,6 +92,8 @@ unsynn! {
         pub where_clause: Option<WhereClause>,
         /// The struct fields.
         pub fields: Fields,
+        /// Where clause following the fields of a tuple struct.
+        pub trailing_where_clause: Option<WhereClause>,
         /// Optional semicolon for unit structs.
         pub semi_token: Option<Semicolon>,
     }
@@ -101,7 +103,7 @@ unsynn! {
         /// The '<' token.
         pub _lt: Lt,
         /// Generic parameters.
-        pub params: Vec<TokenTree>,
+        pub params: VerbatimUntil<Gt>,
         /// The '>' token.
         pub _gt: Gt,
     }
@@ -110,8 +112,8 @@ unsynn! {
     pub struct WhereClause {
         /// The "where" keyword.
         pub _where: KWhere,
-        /// Predicates.
-        pub predicates: Vec<TokenTree>,
+        /// Predicates, up to the struct body or the closing semicolon.
+        pub predicates: Many<Cons<Except<Either<BraceGroup, Semicolon>>, TokenTree>>,
     }
 
     /// Struct fields - can be named, unnamed, or unit.
@@ -273,6 +275,11 @@ impl quote::ToTokens for ItemStruct {
         // Output fields
         tokens.extend(self.fields.to_token_stream());
 
+        // Output a where clause following tuple fields
+        if let Some(ref where_clause) = self.trailing_where_clause {
+            tokens.extend(where_clause.to_token_stream());
+        }
+
         // Output semicolon if present
         if let Some(ref semi) = self.semi_token {
             tokens.extend(semi.to_token_stream());
@@ -22,13 +22,16 @@ extern crate proc_macro;
 
 mod attr_grammar;
 mod codegen;
+mod error;
 mod grammar;
 
-use codegen::{Params, ParamsRef};
+use attr_grammar::AttrArgs;
+use codegen::{Params, ParamsRef, Slice};
+use error::{Error, Errors, Result};
 use grammar::ItemStruct;
 use proc_macro::TokenStream;
-use proc_macro2::TokenStream as TokenStream2;
-use unsynn::*;
+use proc_macro2::{Delimiter, Span, TokenStream as TokenStream2, TokenTree};
+use unsynn::{IParse, ToTokenIter};
 
 /// Constructs a braid
 ///
@@ -36,18 +39,35 @@ use unsynn::*;
 /// and borrowed types, except for doc-comments, with will only be applied to the
 /// owned form.
 ///
+/// If the field is a `CString`, the borrowed type wraps `CStr` instead of `str`, and values are
+/// checked with `strid::CStrValidator` and `strid::CStrNormalizer`. Such a braid takes the options
+/// of [`braid_bytes`] other than `case`, `facet`, and the database and fuzzing integrations.
+///
+/// If the field is an `Arc<str>` or `Rc<str>`, the owned type also gets `into_arc_ref` (or
+/// `into_rc_ref`), which converts it into a pointer to the borrowed type without copying.
+///
 /// Available options:
 /// * `ref_name = "RefName"`
 ///   * Sets the name of the borrowed type
 /// * `ref_doc = "Alternate doc comment"`
 ///   * Overrides the default doc comment for the borrowed type
-/// * `ref_attr = "#[derive(...)]"`
-///   * Provides an attribute to be placed only on the borrowed type
-/// * `owned_attr = "#[derive(...)]"`
-///   * Provides an attribute to be placed only on the owned type
+/// * `ref_attr(derive(...), ...)`
+///   * Provides attributes to be placed only on the borrowed type
+/// * `owned_attr(derive(...), ...)`
+///   * Provides attributes to be placed only on the owned type
 /// * either `validator [ = "Type" ]` or `normalizer [ = "Type" ]`
 ///   * Indicates the type is validated or normalized. If not specified, it is assumed that the
 ///     braid implements the relevant trait itself.
+/// * `const_validator = "path::to::const_fn"`
+///   * Names a `const fn(&str) -> bool` that agrees with the validator or normalizer, making the
+///     borrowed type's `from_static` a `const fn` for use with `strid::lit!`.
+/// * `pattern = "regex"`
+///   * Validates the braid with a regular expression instead of a `validator`, rejecting values
+///     with `strid::PatternError`. The pattern is also provided through `strid::Constraints`.
+///     Requires the `regex` feature of `strid`.
+/// * `constraints [ = "Type" ]`
+///   * Describes the braid in generated schemas using the `strid::Constraints` implementation of
+///     the given type, which defaults to the validator or normalizer, or to the braid itself.
 /// * `clone = "impl|omit"` (default: `impl`)
 ///   * Changes the automatic derivation of a `Clone` implementation on the owned type.
 /// * `debug = "impl|owned|omit"` (default `impl`)
@@ -62,45 +82,93 @@ use unsynn::*;
 ///   * Changes how automatic implementations of the `PartialOrd` and `Ord` traits are provided. If
 ///     `owned`, then the owned type will generate implementations that will just delegate to the
 ///     borrowed implementations. If `omit`, then no implementations will be provided.
+/// * `case = "sensitive|ascii_insensitive|insensitive"` (default `sensitive`)
+///   * Changes how the generated `PartialEq`, `Eq`, `Hash`, `PartialOrd`, and `Ord` implementations
+///     compare values, ignoring the case of ASCII letters, or applying Unicode case folding, which
+///     requires the `caseless` feature of `strid`. Braids that ignore case don't implement
+///     `Borrow<str>`.
+/// * `facet = "impl|owned|omit"` (default `impl`)
+///   * Changes which types derive `Facet`. Facet validates and normalizes the owned type like any
+///     other conversion, but only checks the borrowed type against the validator, so it accepts
+///     values that are already normalized. If `owned`, then only the owned type will derive
+///     `Facet`. If `omit`, then neither will.
 /// * `serde = "impl|omit"` (default `omit`)
-///   * Adds serialize and deserialize implementations
+///   * Adds serialize and deserialize implementations. Requires the `serde` feature of `strid`.
+/// * `rusqlite = "impl|omit"` (default `omit`)
+///   * Adds `ToSql` implementations and a validating `FromSql` implementation for the owned type.
+///     Requires the `rusqlite` feature of `strid`.
+/// * `diesel = "impl|omit"` (default `omit`)
+///   * Adds `AsExpression<Text>` and `ToSql<Text, _>` implementations, as well as validating
+///     `FromSql<Text, _>` and `Queryable` implementations for the owned type. Requires the `diesel`
+///     feature of `strid`.
+/// * `postgres = "impl|omit"` (default `omit`)
+///   * Adds `postgres_types::ToSql` and validating `postgres_types::FromSql` implementations for
+///     the owned type and references to the borrowed type. Requires the `postgres` feature of
+///     `strid`.
+/// * `sailfish = "impl|omit"` (default `omit`)
+///   * Adds `Render` implementations. Requires the `sailfish` feature of `strid`.
+/// * `sqlx = "impl|omit"` (default `omit`)
+///   * Adds `Type`, `Encode`, and validating `Decode` implementations for both types. Requires the
+///     `sqlx` feature of `strid`.
+/// * `schemars = "impl|omit"` (default `omit`)
+///   * Adds `JsonSchema` implementations describing each type by its name, doc comment, and
+///     constraints. Requires the `schemars` feature of `strid`.
+/// * `utoipa = "impl|omit"` (default `omit`)
+///   * Adds `ToSchema` and `PartialSchema` implementations describing each type by its name, doc
+///     comment, and constraints. Requires the `utoipa` feature of `strid`.
+/// * `ts_rs = "impl|omit"` (default `omit`)
+///   * Adds `TS` implementations declaring both types as the same TypeScript string, branded with
+///     the name of the owned type. Requires the `ts-rs` feature of `strid`.
+/// * `proptest = "impl|omit"` (default `omit`)
+///   * Adds an `Arbitrary` implementation for the owned type that generates strings and keeps those
+///     accepted by the braid's `FromStr` implementation. Requires the `proptest` feature of
+///     `strid`.
+/// * `proptest_strategy = "path::to::fn"`
+///   * Names a function returning a `Strategy` for the raw strings used by `proptest`, so that
+///     fewer of them are rejected. Defaults to `proptest::arbitrary::any::<String>`.
+/// * `quickcheck = "impl|omit"` (default `omit`)
+///   * Adds a `quickcheck::Arbitrary` implementation for the owned type that generates strings
+///     until one is accepted by the braid and only shrinks to values the braid accepts unchanged.
+///     Generation panics if none of 10,000 strings is accepted. Requires the `quickcheck` feature
+///     of `strid`.
+/// * `quickcheck_generator = "path::to::fn"`
+///   * Names a `fn(&mut quickcheck::Gen) -> String` generating the raw strings used by
+///     `quickcheck`, so that fewer of them are rejected. Defaults to arbitrary strings.
+/// * `arbitrary = "impl|omit"` (default `omit`)
+///   * Adds `Arbitrary` implementations for the owned type and references to the borrowed type that
+///     fail with `IncorrectFormat` when the braid rejects the input. Requires the `arbitrary`
+///     feature of `strid`.
 /// * `no_expose`
 ///   * Functions that expose the internal field type will not be exposed publicly.
 /// * `no_std`
 ///   * Generates `no_std`-compatible braid (still requires `alloc`)
 #[proc_macro_attribute]
 pub fn braid(args: TokenStream, input: TokenStream) -> TokenStream {
-    let args_ts: TokenStream2 = args.into();
-    let input_ts: TokenStream2 = input.into();
-
-    let mut args_iter = args_ts.to_token_iter();
-    let parsed_args = match args_iter.parse::<attr_grammar::AttrArgs>() {
-        Ok(args) => args,
-        Err(e) => return compile_error(format!("failed to parse braid args: {e}")).into(),
-    };
-    let args = match Params::from_args(parsed_args) {
-        Ok(args) => args,
-        Err(e) => return compile_error(format!("failed to process braid args: {e}")).into(),
-    };
-
-    let mut input_iter = input_ts.to_token_iter();
-    let body = match input_iter.parse::<ItemStruct>() {
-        Ok(body) => body,
-        Err(e) => return compile_error(format!("failed to parse struct: {e}")).into(),
-    };
-
-    args.build(body).map_or_else(
-        |e| compile_error(e).into(),
-        |codegen| codegen.generate().into(),
-    )
+    expand_braid(args.into(), input.into())
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
 }
 
 /// Constructs a ref-only braid
 ///
+/// If the field is a `[u8]`, `Path`, `OsStr`, or `CStr`, values are checked with the validator
+/// trait of that type, such as `strid::PathValidator`, and only the options that [`braid_bytes`],
+/// [`braid_path`], [`braid_os_str`], or a `braid` of a `CString` would take are supported.
+///
 /// Available options:
 /// * either `validator [ = "Type" ]`
 ///   * Indicates the type is validated. If not specified, it is assumed that the braid implements
 ///     the relevant trait itself.
+/// * `const_validator = "path::to::const_fn"`
+///   * Names a `const fn(&str) -> bool` that agrees with the validator, making `from_static` a
+///     `const fn` for use with `strid::lit!`.
+/// * `pattern = "regex"`
+///   * Validates the braid with a regular expression instead of a `validator`, rejecting values
+///     with `strid::PatternError`. The pattern is also provided through `strid::Constraints`.
+///     Requires the `regex` feature of `strid`.
+/// * `constraints [ = "Type" ]`
+///   * Describes the braid in generated schemas using the `strid::Constraints` implementation of
+///     the given type, which defaults to the validator or normalizer, or to the braid itself.
 /// * `debug = "impl|omit"` (default `impl`)
 ///   * Changes how automatic implementations of the `Debug` trait are provided. If `omit`, then no
 ///     implementations of `Debug` will be provided.
@@ -110,49 +178,272 @@ pub fn braid(args: TokenStream, input: TokenStream) -> TokenStream {
 /// * `ord = "impl|omit"` (default `impl`)
 ///   * Changes how automatic implementations of the `PartialOrd` and `Ord` traits are provided. If
 ///     `omit`, then no implementations will be provided.
+/// * `case = "sensitive|ascii_insensitive|insensitive"` (default `sensitive`)
+///   * Changes how the generated `PartialEq`, `Eq`, `Hash`, `PartialOrd`, and `Ord` implementations
+///     compare values, ignoring the case of ASCII letters, or applying Unicode case folding, which
+///     requires the `caseless` feature of `strid`. Braids that ignore case don't implement
+///     `Borrow<str>`.
+/// * `facet = "impl|omit"` (default `impl`)
+///   * Changes whether `Facet` is derived. The type is transparent over `str`, and facet checks a
+///     value against the validator when constructing it. If `omit`, then `Facet` will not be
+///     derived.
 /// * `serde = "impl|omit"` (default `omit`)
-///   * Adds serialize and deserialize implementations
+///   * Adds serialize and deserialize implementations. Requires the `serde` feature of `strid`.
+/// * `rusqlite = "impl|omit"` (default `omit`)
+///   * Adds a `ToSql` implementation. Requires the `rusqlite` feature of `strid`.
+/// * `diesel = "impl|omit"` (default `omit`)
+///   * Adds `AsExpression<Text>` and `ToSql<Text, _>` implementations. Requires the `diesel`
+///     feature of `strid`.
+/// * `postgres = "impl|omit"` (default `omit`)
+///   * Adds `postgres_types::ToSql` and validating `postgres_types::FromSql` implementations for
+///     references to the type. Requires the `postgres` feature of `strid`.
+/// * `sailfish = "impl|omit"` (default `omit`)
+///   * Adds a `Render` implementation. Requires the `sailfish` feature of `strid`.
+/// * `sqlx = "impl|omit"` (default `omit`)
+///   * Adds `Type`, `Encode`, and validating `Decode` implementations. Requires the `sqlx` feature
+///     of `strid`.
+/// * `schemars = "impl|omit"` (default `omit`)
+///   * Adds a `JsonSchema` implementation describing the type by its name, doc comment, and
+///     constraints. Requires the `schemars` feature of `strid`.
+/// * `utoipa = "impl|omit"` (default `omit`)
+///   * Adds `ToSchema` and `PartialSchema` implementations describing the type by its name, doc
+///     comment, and constraints. Requires the `utoipa` feature of `strid`.
+/// * `ts_rs = "impl|omit"` (default `omit`)
+///   * Adds a `TS` implementation declaring the type as a TypeScript string branded with its name.
+///     Requires the `ts-rs` feature of `strid`.
+/// * `arbitrary = "impl|omit"` (default `omit`)
+///   * Adds an `Arbitrary` implementation for references to the type that fails with
+///     `IncorrectFormat` when the input is invalid. Requires the `arbitrary` feature of `strid`.
 /// * `no_std`
 ///   * Generates a `no_std`-compatible braid that doesn't require `alloc`
 #[proc_macro_attribute]
 pub fn braid_ref(args: TokenStream, input: TokenStream) -> TokenStream {
-    let args_ts: TokenStream2 = args.into();
-    let input_ts: TokenStream2 = input.into();
+    expand_braid_ref(args.into(), input.into())
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
 
-    let mut args_iter = args_ts.to_token_iter();
-    let parsed_args = match args_iter.parse::<attr_grammar::AttrArgs>() {
-        Ok(args) => args,
-        Err(e) => return compile_error(format!("failed to parse braid_ref args: {e}")).into(),
+/// Constructs a byte-string braid
+///
+/// The owned type wraps a `Vec<u8>` unless another field is given, and the borrowed type wraps
+/// `[u8]`. Values are checked with `strid::ByteValidator` and `strid::ByteNormalizer` instead of
+/// their string counterparts.
+///
+/// The options are those of [`braid`], other than the ones that only apply to strings: `display`,
+/// `pattern`, `constraints`, `sailfish`, `schemars`, `utoipa`, `ts_rs`, `quickcheck`, and
+/// `quickcheck_generator`. Some of them differ for bytes:
+/// * `const_validator` names a `const fn(&[u8]) -> bool`.
+/// * `debug` formats the value like a byte string literal.
+/// * `case` only accepts `sensitive` and `ascii_insensitive`, and braids that ignore case don't
+///   implement `Borrow<[u8]>`.
+/// * `serde` uses the serde data model's bytes.
+/// * `diesel` and `postgres` store the value as `Binary` and `BYTEA` rather than as text.
+/// * `proptest` and `arbitrary` generate byte vectors, keeping those accepted by the braid.
+#[proc_macro_attribute]
+pub fn braid_bytes(args: TokenStream, input: TokenStream) -> TokenStream {
+    expand_braid_slice(args.into(), input.into(), Slice::Bytes)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Constructs a path braid
+///
+/// The owned type wraps a `PathBuf` unless another field is given, and the borrowed type wraps
+/// `Path`. Values are checked with `strid::PathValidator` and `strid::PathNormalizer`, which
+/// require the `std` feature of `strid`. Both types implement `AsRef<Path>` and `AsRef<OsStr>`,
+/// and the borrowed type provides `join`, `with_file_name`, and `with_extension`, which return a
+/// plain `PathBuf`.
+///
+/// The options are those of [`braid_bytes`] other than `const_validator`, `case`, `facet`, the
+/// database and fuzzing integrations, and `no_std`. The `debug` option formats the value like a
+/// `Path`, and `serde` represents it as serde does a `Path`, which requires the `std` feature of
+/// `strid` as well.
+#[proc_macro_attribute]
+pub fn braid_path(args: TokenStream, input: TokenStream) -> TokenStream {
+    expand_braid_slice(args.into(), input.into(), Slice::Path)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Constructs an OS string braid
+///
+/// The owned type wraps an `OsString` unless another field is given, and the borrowed type wraps
+/// `OsStr`. Values are checked with `strid::OsStrValidator` and `strid::OsStrNormalizer`, which
+/// require the `std` feature of `strid`. Both types implement `AsRef<OsStr>` and `AsRef<Path>`,
+/// and the borrowed type provides `join`, which returns a plain `PathBuf`.
+///
+/// The options are the same as those of [`braid_path`], except that `serde` represents the value
+/// as serde does an `OsStr`, which is specific to the platform, so the borrowed type can't be
+/// deserialized.
+#[proc_macro_attribute]
+pub fn braid_os_str(args: TokenStream, input: TokenStream) -> TokenStream {
+    expand_braid_slice(args.into(), input.into(), Slice::OsStr)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand_braid(args: TokenStream2, input: TokenStream2) -> Result<TokenStream2> {
+    let mut errors = Errors::default();
+    let args = AttrArgs::parse(args, &mut errors);
+    let body = parse_item_struct(input);
+    let slice = body.as_ref().map_or(Slice::Str, Slice::of_owned);
+    build_braid(errors, Params::from_args(args, slice), body)
+}
+
+fn expand_braid_ref(args: TokenStream2, input: TokenStream2) -> Result<TokenStream2> {
+    let mut errors = Errors::default();
+    let args = AttrArgs::parse(args, &mut errors);
+    let body = parse_item_struct(input);
+    let slice = body.as_ref().map_or(Slice::Str, Slice::of_borrowed);
+
+    let params = errors.handle(ParamsRef::from_args(args, slice));
+    let body = errors.handle(body);
+    errors.finish()?;
+
+    let (Some(params), Some(mut body)) = (params, body) else {
+        unreachable!("failures are reported as errors above");
     };
-    let args = match ParamsRef::from_args(parsed_args) {
-        Ok(args) => args,
-        Err(e) => return compile_error(format!("failed to process braid_ref args: {e}")).into(),
+    params.build(&mut body)
+}
+
+fn expand_braid_slice(
+    args: TokenStream2,
+    input: TokenStream2,
+    slice: Slice,
+) -> Result<TokenStream2> {
+    let mut errors = Errors::default();
+    let args = AttrArgs::parse(args, &mut errors);
+    build_braid(
+        errors,
+        Params::from_args(args, slice),
+        parse_item_struct(input),
+    )
+}
+
+fn build_braid(
+    mut errors: Errors,
+    params: Result<Params>,
+    body: Result<ItemStruct>,
+) -> Result<TokenStream2> {
+    let params = errors.handle(params);
+    let body = errors.handle(body);
+    errors.finish()?;
+
+    let (Some(params), Some(body)) = (params, body) else {
+        unreachable!("failures are reported as errors above");
     };
+    Ok(params.build(body)?.generate())
+}
 
-    let mut input_iter = input_ts.to_token_iter();
-    let mut body = match input_iter.parse::<ItemStruct>() {
-        Ok(body) => body,
-        Err(e) => return compile_error(format!("failed to parse struct: {e}")).into(),
+/// Parses the item that a macro was applied to, pointing any errors at the item's name, or at
+/// its keyword when it is not a struct at all.
+fn parse_item_struct(input: TokenStream2) -> Result<ItemStruct> {
+    let mut iter = input.clone().to_token_iter();
+    let parse_error = match iter.parse::<ItemStruct>() {
+        Ok(body) => return Ok(body),
+        Err(e) => e,
     };
 
-    args.build(&mut body)
-        .map_or_else(|e| compile_error(e).into(), |tokens| tokens.into())
+    let mut tokens = input.into_iter().peekable();
+    loop {
+        match tokens.next() {
+            // Skip over outer attributes and visibility
+            Some(TokenTree::Punct(p)) if p.as_char() == '#' => {
+                tokens.next();
+            }
+            Some(TokenTree::Ident(i)) if i == "pub" => {
+                if let Some(TokenTree::Group(g)) = tokens.peek()
+                    && g.delimiter() == Delimiter::Parenthesis
+                {
+                    tokens.next();
+                }
+            }
+            Some(TokenTree::Ident(i)) if i == "struct" => {
+                let span = tokens.next().map_or_else(|| i.span(), |tt| tt.span());
+                return Err(Error::new(
+                    span,
+                    format!("failed to parse struct: {parse_error}"),
+                ));
+            }
+            Some(tt) => {
+                return Err(Error::new(tt.span(), "expected a struct"));
+            }
+            None => return Err(Error::new(Span::call_site(), "expected a struct")),
+        }
+    }
 }
 
-/// Helper to create a compile error.
-fn compile_error(msg: impl std::fmt::Display) -> TokenStream2 {
-    let msg = msg.to_string();
-    quote::quote! {
-        compile_error!(#msg)
+#[cfg(test)]
+mod tests {
+    use proc_macro2::{Delimiter, TokenStream, TokenTree};
+    use quote::quote;
+
+    /// The doc comments on the struct named `name` in `tokens`
+    fn struct_docs(tokens: TokenStream, name: &str) -> Vec<String> {
+        let tokens: Vec<_> = tokens.into_iter().collect();
+        let mut docs = Vec::new();
+        for (i, tt) in tokens.iter().enumerate() {
+            match tt {
+                TokenTree::Group(g) if g.delimiter() == Delimiter::Bracket => {
+                    let attr: Vec<_> = g.stream().into_iter().collect();
+                    if let [
+                        TokenTree::Ident(doc),
+                        TokenTree::Punct(_),
+                        TokenTree::Literal(lit),
+                    ] = attr.as_slice()
+                        && doc == "doc"
+                    {
+                        docs.push(lit.to_string());
+                    }
+                }
+                TokenTree::Ident(kw) if kw == "struct" => match tokens.get(i + 1) {
+                    Some(TokenTree::Ident(ident)) if ident == name => return docs,
+                    _ => docs.clear(),
+                },
+                TokenTree::Group(g) if g.delimiter() == Delimiter::Brace => docs.clear(),
+                _ => {}
+            }
+        }
+        panic!("no struct named `{name}` was generated")
     }
-}
 
-fn as_validator(validator: &grammar::Type) -> proc_macro2::TokenStream {
-    let ty = validator.to_token_stream();
-    quote::quote! { <#ty as ::strid::Validator> }
-}
+    #[test]
+    fn owned_docs_stay_on_the_owned_type() {
+        let tokens = super::expand_braid(
+            TokenStream::new(),
+            quote! {
+                /// A name for a user
+                pub struct Username;
+            },
+        )
+        .unwrap();
+
+        // `///` comments are quoted as raw strings
+        assert_eq!(
+            struct_docs(tokens.clone(), "Username"),
+            [r#"r" A name for a user""#]
+        );
+        assert_eq!(
+            struct_docs(tokens, "UsernameRef"),
+            [r#""The borrowed form of [`Username`]""#]
+        );
+    }
 
-fn as_normalizer(normalizer: &grammar::Type) -> proc_macro2::TokenStream {
-    let ty = normalizer.to_token_stream();
-    quote::quote! { <#ty as ::strid::Normalizer> }
+    #[test]
+    fn ref_doc_replaces_the_owned_docs() {
+        let tokens = super::expand_braid(
+            quote! { ref_doc = "A borrowed name for a user" },
+            quote! {
+                /// A name for a user
+                #[doc(alias = "Login")]
+                pub struct Username;
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            struct_docs(tokens, "UsernameRef"),
+            [r#""A borrowed name for a user""#]
+        );
+    }
 }
@@ -160,6 +160,37 @@
 //! pub struct DatabaseName;
 //! ```
 //!
+//! # Generic marker parameters
+//!
+//! A braid can be generic over lifetimes and marker types, which makes it possible to
+//! share a single definition between many kinds of strings that should not be mixed up.
+//! Both the owned and borrowed forms take the same parameters, along with any bounds,
+//! defaults, and `where` clauses.
+//!
+//! ```
+//! # use strid::braid;
+//! #
+//! pub struct User;
+//! pub struct Org;
+//!
+//! #[braid]
+//! pub struct Id<T>;
+//!
+//! let user: Id<User> = Id::from_static("u-1234");
+//! let borrowed: &IdRef<User> = &user;
+//! assert_eq!(borrowed.as_str(), "u-1234");
+//! ```
+//!
+//! The parameters are carried by a [`PhantomData`][core::marker::PhantomData] field,
+//! so they don't need to implement anything for the generated impls to apply, and an
+//! `Id<User>` can't be compared with or borrowed as an `IdRef<Org>`. The marker field
+//! comes after the string in the owned form, and before it in the borrowed form, as
+//! the unsized `str` must be the last field.
+//!
+//! The lifetimes `'a`, `'b`, `'de`, `'q`, `'r`, and `'s` and the type parameters `D`,
+//! `DB`, `H`, `S`, and `ST` are used by the generated impls, and can't be used as
+//! parameters of a braid.
+//!
 //! # Extensibility
 //!
 //! The types created by the `braid` macro are placed in the same module where declared.
@@ -399,6 +430,127 @@
 //! }
 //! ```
 //!
+//! ## Built-in validators
+//!
+//! Common rules, such as requiring a value to be non-empty, limiting its length, or
+//! restricting it to a set of characters, are provided as composable validators in the
+//! [`validators`] module. They share the [`ValidationError`](validators::ValidationError)
+//! error type and can be named directly as a braid's validator.
+//!
+//! ```
+//! use strid::{braid, validators::{And, Ascii, MaxLen}};
+//!
+//! #[braid(validator = "And<Ascii, MaxLen<16>>")]
+//! pub struct ShortCode;
+//!
+//! assert!(ShortCodeRef::from_str("ABC-123").is_ok());
+//! assert!(ShortCodeRef::from_str("ÅBC").is_err());
+//! ```
+//!
+//! ## Patterns
+//!
+//! A braid whose values are described by a regular expression can declare it with
+//! `pattern` instead of writing a validator. The pattern is checked when the macro is
+//! expanded, compiled the first time a value is validated, and reused afterwards. Values
+//! that don't match are rejected with a [`PatternError`], which reports both the value
+//! and the pattern. A pattern that is too large to compile rejects every value instead.
+//! This requires enabling the `regex` feature of `strid`.
+//!
+//! ```
+//! use strid::braid;
+//!
+//! #[braid(pattern = "^[a-z][a-z0-9-]*$")]
+//! pub struct Hostname;
+//!
+//! assert!(HostnameRef::from_str("db-1").is_ok());
+//! assert!(HostnameRef::from_str("1db").is_err());
+//! ```
+//!
+//! As in JSON Schema, the pattern is not implicitly anchored, so it should start with `^`
+//! and end with `$` to match the whole value. The braid also implements [`Constraints`]
+//! with the pattern, so that the `schemars` and `utoipa` integrations include it, unless
+//! `constraints` names another type. A `pattern` cannot be combined with a `validator` or
+//! `normalizer`.
+//!
+//! ## Compile-time checked literals
+//!
+//! Trait methods cannot be `const`, so the `from_static` functions above check their input
+//! at runtime. To catch an invalid literal when compiling instead, provide a
+//! `const_validator` naming a `const fn(&str) -> bool` that accepts exactly the values the
+//! validator accepts (and, for normalized braids, only those already in normalized form).
+//! The borrowed form's `from_static` then becomes a `const fn`, usable in `const` and
+//! `static` items, and the [`lit!()`] macro forces it to be evaluated at compile time.
+//!
+//! ```
+//! # use strid::braid;
+//! #
+//! # #[derive(Debug, PartialEq, Eq)]
+//! # pub struct InvalidHeaderName;
+//! # strid::from_infallible!(InvalidHeaderName);
+//! #
+//! #[braid(validator, const_validator = "is_header_name")]
+//! pub struct HeaderName;
+//!
+//! const fn is_header_name(s: &str) -> bool {
+//!     let bytes = s.as_bytes();
+//!     let mut i = 0;
+//!     while i < bytes.len() {
+//!         if !bytes[i].is_ascii_lowercase() && bytes[i] != b'-' {
+//!             return false;
+//!         }
+//!         i += 1;
+//!     }
+//!     !bytes.is_empty()
+//! }
+//!
+//! impl strid::Validator for HeaderName {
+//!     type Error = InvalidHeaderName;
+//!     fn validate(s: &str) -> Result<(), Self::Error> {
+//!         if is_header_name(s) { Ok(()) } else { Err(InvalidHeaderName) }
+//!     }
+//! }
+//!
+//! static CONTENT_TYPE: &HeaderNameRef = HeaderNameRef::from_static("content-type");
+//!
+//! let accept = strid::lit!(HeaderNameRef, "accept");
+//! assert_eq!("accept", accept.as_str());
+//! assert_eq!("content-type", CONTENT_TYPE.as_str());
+//! ```
+//!
+//! An invalid literal is then a compile error rather than a panic in production:
+//!
+//! ```compile_fail
+//! # use strid::braid;
+//! #
+//! # #[derive(Debug, PartialEq, Eq)]
+//! # pub struct InvalidHeaderName;
+//! # strid::from_infallible!(InvalidHeaderName);
+//! #
+//! # #[braid(validator, const_validator = "is_header_name")]
+//! # pub struct HeaderName;
+//! #
+//! # const fn is_header_name(s: &str) -> bool {
+//! #     let bytes = s.as_bytes();
+//! #     let mut i = 0;
+//! #     while i < bytes.len() {
+//! #         if !bytes[i].is_ascii_lowercase() && bytes[i] != b'-' {
+//! #             return false;
+//! #         }
+//! #         i += 1;
+//! #     }
+//! #     !bytes.is_empty()
+//! # }
+//! #
+//! # impl strid::Validator for HeaderName {
+//! #     type Error = InvalidHeaderName;
+//! #     fn validate(s: &str) -> Result<(), Self::Error> {
+//! #         if is_header_name(s) { Ok(()) } else { Err(InvalidHeaderName) }
+//! #     }
+//! # }
+//! #
+//! let bad = strid::lit!(HeaderNameRef, "Bad Header");
+//! ```
+//!
 //! ## Normalization
 //!
 //! Braided strings can also have enforced normalization, which is carried out at the creation
@@ -478,6 +630,29 @@
 //! assert_eq!("lowercase", HeaderNameRef::from_static("lowercase").as_str());
 //! ```
 //!
+//! ## Built-in normalizers
+//!
+//! Unicode normalization forms, case folding, and whitespace trimming and collapsing are
+//! provided in the [`normalizers`] module. Normalizers are chained with
+//! [`Then`](normalizers::Then), which also accepts the built-in validators, so a single
+//! type can describe both how a value is normalized and what it must look like afterwards.
+//!
+//! ```
+//! use strid::{
+//!     braid,
+//!     normalizers::{Nfkc, Then, Trim},
+//!     validators::NonEmpty,
+//! };
+//!
+//! #[braid(normalizer = "Then<Nfkc, Then<Trim, NonEmpty>>")]
+//! pub struct Username;
+//!
+//! assert_eq!(Username::new("ｊｏｈｎ ".to_owned()).unwrap().as_str(), "john");
+//! ```
+//!
+//! The Unicode normalization forms require the `unicode-normalization` feature of `strid`,
+//! and case folding requires the `caseless` feature.
+//!
 //! ## Unchecked creation
 //!
 //! Where necessary for efficiency, it is possible to bypass the validations on creation through
@@ -579,6 +754,7 @@
 //! * [`core::borrow::Borrow<Borrowed>`]
 //! * [`core::str::FromStr`]
 //! * [`core::ops::Deref`] where `Target = Borrowed`
+//! * [`facet::Facet`]
 //!
 //! Additionally, unvalidated owned types implement
 //! * [`core::convert::From<String>`]
@@ -603,6 +779,7 @@
 //! * [`core::cmp::PartialOrd`]
 //! * [`core::convert::From<&Cow<Borrowed>>`]
 //! * [`alloc::borrow::ToOwned`] where `Owned = Owned`
+//! * [`facet::Facet`]
 //!
 //! Additionally, unvalidated borrowed types implement
 //! * [`core::convert::From<&str>`]
@@ -626,7 +803,8 @@
 //!
 //! `Borrow<str>` cannot be implemented for normalized braids because equality and hashing
 //! of equivalent braid values will have differing results for equality, which violates the
-//! contract implied by the `Borrow` trait.
+//! contract implied by the `Borrow` trait. For the same reason, it is not implemented for
+//! braids that [ignore case](#case-insensitive-comparison).
 //!
 //! `Deref` to a `str` is explicitly not implemented. This means that an explicit call is
 //! required to treat a value as an untyped string, whether `.as_str()`, `.to_string()`, or
@@ -648,6 +826,39 @@
 //! assert_not_impl_any!(Sensitive: Clone);
 //! ```
 //!
+//! ## Case-insensitive comparison
+//!
+//! Some values, such as hostnames or HTTP header names, should keep the spelling they were
+//! created with but compare without regard to case. Setting `case = "ascii_insensitive"`
+//! generates [`PartialEq`], [`Eq`], [`Hash`][core::hash::Hash], [`PartialOrd`], and [`Ord`]
+//! implementations that ignore the case of ASCII letters, for the owned and borrowed types
+//! alike, and so for `Box<Borrowed>` and `Cow<Borrowed>` too.
+//!
+//! ```
+//! # use strid::braid;
+//! use std::collections::HashSet;
+//!
+//! #[braid(case = "ascii_insensitive")]
+//! pub struct HeaderName;
+//!
+//! let name = HeaderName::from_static("Content-Type");
+//! assert_eq!(name, HeaderNameRef::from_static("content-type"));
+//! assert_eq!(name.as_str(), "Content-Type");
+//!
+//! let names: HashSet<_> = ["Accept", "ACCEPT", "accept"]
+//!     .into_iter()
+//!     .map(HeaderNameRef::from_static)
+//!     .collect();
+//! assert_eq!(names.len(), 1);
+//! ```
+//!
+//! `case = "insensitive"` uses Unicode default case folding instead, so that `"STRASSE"`
+//! and `"straße"` are equal. This requires enabling the `caseless` feature of `strid`. The
+//! default, `case = "sensitive"`, compares the strings as they are.
+//!
+//! These braids don't implement [`Borrow<str>`][core::borrow::Borrow], so a collection of
+//! them can't be searched with a plain `&str`. Look values up with the borrowed type instead.
+//!
 //! ## Custom `Display`, `Debug`, and `PartialOrd`/`Ord` implementations
 //!
 //! By default, the implementations of [`Display`][core::fmt::Display], [`Debug`][core::fmt::Debug]
@@ -707,6 +918,8 @@
 //!
 //! [`Serialize`] and [`Deserialize`] implementations from the [`serde`] crate
 //! can be automatically generated by including `serde` in the argument list for the macro.
+//! This requires enabling the `serde` feature of `strid`; the generated code refers to
+//! `serde` through `strid`, so the crate defining the braid doesn't need to depend on it.
 //!
 //! [`serde`]: https://docs.rs/serde/*/serde/
 //! [`Serialize`]: https://docs.rs/serde/*/serde/trait.Serialize.html
@@ -765,132 +978,688 @@
 //! assert!(serde_json::from_str::<&UsernameRef>("\"nobody\"").is_ok());
 //! ```
 //!
-//! # Custom string types
+//! # Facet
 //!
-//! The `braid` macro can be used to define a custom string type that wraps types
-//! other than the standard `String`. This allows defining a braid that is backed
-//! by a type that offers small-string optimizations, such as [`SmartString`] or
-//! [`CompactString`].
+//! Both the owned and borrowed types derive [`Facet`][facet::Facet] with a shape that is
+//! transparent over the wrapped string, so either can appear in reflected types, including
+//! behind a reference, a [`Box`][alloc::boxed::Box], or a [`Cow`][alloc::borrow::Cow].
 //!
-//! Functions that expose the inner wrapped type can be made private by adding the
-//! `no_expose` parameter to avoid leaking the type in the public interface.
+//! ```
+//! # use std::borrow::Cow;
+//! # use strid::braid;
+//! #
+//! #[braid]
+//! pub struct Username;
 //!
-//! [`SmartString`]: https://docs.rs/smartstring/*/smartstring/struct.SmartString.html
-//! [`CompactString`]: https://docs.rs/compact_str/*/compact_str/struct.CompactString.html
+//! #[derive(strid::facet::Facet)]
+//! #[facet(crate = strid::facet)]
+//! pub struct Config<'a> {
+//!     owner: Username,
+//!     admin: &'a UsernameRef,
+//!     guest: Cow<'a, UsernameRef>,
+//! }
+//! ```
 //!
-//! ```ignore
-//! # use strid::braid;
-//! use compact_str::CompactString;
-//! use smartstring::{SmartString, LazyCompact};
+//! Facet builds the owned type of a braid with a validator or normalizer from a string,
+//! which goes through the same conversion as any other, so its input is validated and
+//! normalized just as with serde. The borrowed type is built in place instead, so facet
+//! checks it against the validator, and a normalized braid rejects values that aren't
+//! already normalized. Either way, facet reports a rejected value with the `Display`
+//! output of the validator's error, if it implements `Display`.
 //!
-//! #[braid(no_expose)]
-//! pub struct UserId(CompactString);
+//! Use `facet = "owned"` to only derive `Facet` for the owned type, or `facet = "omit"` to
+//! derive it for neither. Facet can't check a borrowed type with generic parameters, so a
+//! generic braid with a validator or normalizer must use one of these, and is rejected
+//! otherwise.
 //!
-//! #[braid(no_expose)]
-//! pub struct AltUserId(SmartString<LazyCompact>);
+//! # Rusqlite and Sailfish
+//!
+//! Including `rusqlite` in the argument list implements [`ToSql`] for both types and
+//! [`FromSql`] for the owned type, which validates or normalizes values read from the
+//! database. Including `sailfish` implements [`Render`] for both types, so braids can be
+//! used directly in templates. These require enabling the `rusqlite` and `sailfish`
+//! features of `strid`, respectively.
+//!
+//! ```
+//! # use strid::braid;
+//! #
+//! #[braid(rusqlite, sailfish)]
+//! pub struct Username;
 //! ```
 //!
-//! It can also be used to wrap a [`ByteString`], which is a string backed by
-//! [`Bytes`], which may be useful if the type is primarily used in contexts
-//! where a zero-copy implementation is preferred.
+//! [`ToSql`]: https://docs.rs/rusqlite/*/rusqlite/types/trait.ToSql.html
+//! [`FromSql`]: https://docs.rs/rusqlite/*/rusqlite/types/trait.FromSql.html
+//! [`Render`]: https://docs.rs/sailfish/*/sailfish/runtime/trait.Render.html
 //!
-//! [`ByteString`]: https://docs.rs/bytestring/*/bytestring/struct.ByteString.html
-//! [`Bytes`]: https://docs.rs/bytes/*/bytes/struct.Bytes.html
+//! # JSON Schema
 //!
-//! ```ignore
-//! # use strid::braid;
-//! use bytestring::ByteString;
+//! Including `schemars` in the argument list implements [`JsonSchema`] for both types,
+//! describing each as a string named after the type and documented by its doc comment.
+//! The borrowed type uses its `ref_doc`, if any. This requires enabling the `schemars`
+//! feature of `strid`.
+//!
+//! Adding `constraints` describes the values accepted by the validator using its
+//! [`Constraints`] impl, which can specify a `pattern`, `minLength`, `maxLength`,
+//! `format`, `enum`, and `examples`. The validator or normalizer is expected to implement
+//! [`Constraints`], unless another type is named with `constraints = "Type"`.
 //!
-//! #[braid]
-//! pub struct ZeroCopyIdentifier(ByteString);
 //! ```
+//! # use strid::{braid, Constraints, Validator};
+//! #
+//! /// A user's login name
+//! #[braid(schemars, validator, constraints)]
+//! pub struct Username;
 //!
-//! ## Requirements
+//! impl Constraints for Username {
+//!     const PATTERN: Option<&'static str> = Some("^[a-z]+$");
+//!     const MAX_LENGTH: Option<u64> = Some(32);
+//! }
+//! #
+//! # #[derive(Debug)]
+//! # pub struct InvalidUsername;
+//! # strid::from_infallible!(InvalidUsername);
+//! #
+//! # impl Validator for Username {
+//! #     type Error = InvalidUsername;
+//! #     fn validate(s: &str) -> Result<(), Self::Error> {
+//! #         Ok(())
+//! #     }
+//! # }
+//! ```
 //!
-//! In order to be used as a custom string type, the type must implement the
-//! following traits:
+//! The schema of a generic braid is named after the braid alone, so every instantiation
+//! shares a single definition: `Id<User>` and `Id<Org>` are both described by `Id`.
 //!
-//! * [`core::clone::Clone`] (unless `clone` is `omit`)
-//! * [`core::fmt::Debug`] (unless `debug` is `omit`)
-//! * [`core::fmt::Display`] (unless `display` is `omit`)
-//! * [`core::cmp::Eq`]
-//! * [`core::cmp::PartialEq`]
-//! * [`core::hash::Hash`]
-//! * [`core::cmp::Ord`] (unless `ord` is `omit`)
-//! * [`core::cmp::PartialOrd`] (unless `ord` is `omit`)
-//! * [`serde::Serialize`] (unless `serde` is `omit`)
-//! * [`serde::Deserialize`] (unless `serde` is `omit`)
-//! * [`core::convert::From<&str>`]
-//! * [`core::convert::From<Box<str>>`]
-//! * [`core::convert::AsRef<str>`]
-//! * [`core::convert::Into<String>`]
+//! [`JsonSchema`]: https://docs.rs/schemars/*/schemars/trait.JsonSchema.html
 //!
-//! [`serde::Serialize`]: https://docs.rs/serde/*/serde/trait.Serialize.html
-//! [`serde::Deserialize`]: https://docs.rs/serde/*/serde/trait.Deserialize.html
+//! # OpenAPI
 //!
-//! # `no_std` support
+//! Including `utoipa` in the argument list implements [`ToSchema`] and [`PartialSchema`]
+//! for both types, describing them the same way as the `schemars` integration, including
+//! any `constraints`. This requires enabling the `utoipa` feature of `strid`.
 //!
-//! Braids can be implemented in `no_std` environments with `alloc`. By adding the
-//! `no_std` parameter to the macro, all impls will reference the `core` or `alloc`
-//! crates instead of the `std` crate, as appropriate.
+//! [`ToSchema`]: https://docs.rs/utoipa/*/utoipa/trait.ToSchema.html
+//! [`PartialSchema`]: https://docs.rs/utoipa/*/utoipa/trait.PartialSchema.html
+//!
+//! # TypeScript
+//!
+//! Including `ts_rs` in the argument list implements [`TS`] for both types, declaring
+//! the braid as a branded string. The owned and borrowed types map to the same
+//! TypeScript type, named after the owned type, so that either can be used in
+//! types shared with a frontend. This requires enabling the `ts-rs` feature of `strid`.
 //!
 //! ```
-//! extern crate alloc;
+//! # use strid::braid;
+//! #
+//! /// A unique identifier for a user
+//! #[braid(ts_rs)]
+//! pub struct UserId;
+//! ```
 //!
-//! use strid::braid;
-//! use alloc::string::String;
+//! The declaration exported for `UserId` and `UserIdRef` is:
 //!
-//! #[braid(no_std)]
-//! pub struct NoStdLibWrapper;
+//! ```ts
+//! type UserId = string & { readonly __brand: "UserId" };
+//! ```
+//!
+//! [`TS`]: https://docs.rs/ts-rs/*/ts_rs/trait.TS.html
+//!
+//! # Diesel
+//!
+//! Including `diesel` in the argument list implements [`AsExpression<Text>`] and
+//! [`ToSql<Text, DB>`][diesel-ToSql] for both types, along with [`FromSql<Text, DB>`] and
+//! [`Queryable`] for the owned type, so braids can be used directly as `Text` columns.
+//! Values loaded from the database are validated or normalized. This requires enabling the
+//! `diesel` feature of `strid`.
+//!
+//! ```no_run
+//! # use diesel::{prelude::*, sqlite::SqliteConnection};
+//! # use strid::braid;
 //! #
-//! # fn main() {}
+//! # diesel::table! {
+//! #     users (id) {
+//! #         id -> Integer,
+//! #         name -> Text,
+//! #     }
+//! # }
+//! #
+//! #[braid(diesel, validator)]
+//! pub struct Username;
+//! #
+//! # #[derive(Debug)]
+//! # pub struct InvalidUsername;
+//! # impl std::fmt::Display for InvalidUsername {
+//! #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+//! #         f.write_str("invalid username")
+//! #     }
+//! # }
+//! # impl std::error::Error for InvalidUsername {}
+//! # strid::from_infallible!(InvalidUsername);
+//! #
+//! # impl strid::Validator for Username {
+//! #     type Error = InvalidUsername;
+//! #     fn validate(s: &str) -> Result<(), Self::Error> {
+//! #         Ok(())
+//! #     }
+//! # }
+//! #
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let mut conn = SqliteConnection::establish("users.db")?;
+//!
+//! let names: Vec<Username> = users::table.select(users::name).load(&mut conn)?;
+//! # Ok(())
+//! # }
 //! ```
 //!
-//! In environments without an allocator, `braid_ref` can be used to create a
-//! reference-only braid. In order to remove the `alloc` dependency in `strid`,
-//! specify `default-features = "false"` in the `Cargo.toml` file.
+//! [`AsExpression<Text>`]: https://docs.rs/diesel/*/diesel/expression/trait.AsExpression.html
+//! [diesel-ToSql]: https://docs.rs/diesel/*/diesel/serialize/trait.ToSql.html
+//! [`FromSql<Text, DB>`]: https://docs.rs/diesel/*/diesel/deserialize/trait.FromSql.html
+//! [`Queryable`]: https://docs.rs/diesel/*/diesel/deserialize/trait.Queryable.html
+//!
+//! # Postgres
+//!
+//! Including `postgres` in the argument list implements [`ToSql`][postgres-ToSql] and
+//! [`FromSql`][postgres-FromSql] from [`postgres-types`] for the owned type and for
+//! references to the borrowed type, for use with `postgres` and `tokio-postgres`. The
+//! impls accept `TEXT`, `VARCHAR`, `BPCHAR`, and `NAME` columns. Values decoded as the
+//! owned type are validated or normalized, while values decoded as a borrowed reference
+//! are validated and must already be in normalized form. This requires enabling the
+//! `postgres` feature of `strid`.
 //!
 //! ```
-//! use strid::braid_ref;
+//! # use strid::braid;
+//! use postgres_types::{FromSql, Type};
 //!
-//! #[braid_ref(no_std)]
-//! pub struct NoStdValue(str);
+//! #[braid(postgres, validator)]
+//! pub struct Username;
 //! #
-//! # fn main() {}
+//! # #[derive(Debug)]
+//! # pub struct InvalidUsername;
+//! # impl std::fmt::Display for InvalidUsername {
+//! #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+//! #         f.write_str("invalid username")
+//! #     }
+//! # }
+//! # impl std::error::Error for InvalidUsername {}
+//! # strid::from_infallible!(InvalidUsername);
+//! #
+//! # impl strid::Validator for Username {
+//! #     type Error = InvalidUsername;
+//! #     fn validate(s: &str) -> Result<(), Self::Error> {
+//! #         if s.is_empty() { Err(InvalidUsername) } else { Ok(()) }
+//! #     }
+//! # }
+//!
+//! // `row.get::<_, &UsernameRef>(0)` decodes a column in the same way
+//! let name = <&UsernameRef>::from_sql(&Type::TEXT, b"alice").unwrap();
+//! assert_eq!(name.as_str(), "alice");
+//! assert!(<&UsernameRef>::from_sql(&Type::TEXT, b"").is_err());
 //! ```
 //!
-//! # Safety
+//! [`postgres-types`]: https://docs.rs/postgres-types
+//! [postgres-ToSql]: https://docs.rs/postgres-types/*/postgres_types/trait.ToSql.html
+//! [postgres-FromSql]: https://docs.rs/postgres-types/*/postgres_types/trait.FromSql.html
 //!
-//! Braid uses limited `unsafe` in order to be able to reinterpret string slices
-//! (`&str`) as the borrowed form. Because this functionality is provided as a
-//! macro, using the `#![forbid(unsafe_code)]` lint level on a crate that generates
-//! braids will result in compiler errors. Instead, the crate can be annotated with
-//! `#![deny(unsafe_code)]`, which allows for overrides as appropriate. The functions
-//! that require `unsafe` to work correctly are annotated with `#[allow(unsafe_code)]`,
-//! and all usages of unsafe that the macro generates are annotated with `SAFETY`
-//! code comments.
+//! # SQLx
 //!
-//! If strict adherence to forbid unsafe code is required, then the types can be
-//! segregated into an accessory crate without the prohibition, and then consumed
-//! safely from crates that otherwise forbid unsafe code.
-
-#![warn(
-    missing_docs,
-    unused_import_braces,
-    unused_imports,
-    unused_qualifications
-)]
-#![deny(
-    missing_debug_implementations,
-    trivial_casts,
-    trivial_numeric_casts,
-    unused_must_use
-)]
-#![deny(unsafe_code)]
+//! Including `sqlx` in the argument list implements [`sqlx::Type`], [`sqlx::Encode`], and
+//! [`sqlx::Decode`] for any database that supports the wrapped string type. Values decoded
+//! as the owned type are validated or normalized, while values decoded as a borrowed
+//! reference, for databases that support borrowing strings, are validated and must
+//! already be in normalized form. This requires enabling the `sqlx` feature of `strid`.
+//!
+//! ```ignore
+//! # use strid::braid;
+//! #
+//! #[braid(sqlx, validator)]
+//! pub struct Username;
+//!
+//! let user: Username = sqlx::query_scalar("SELECT name FROM users")
+//!     .fetch_one(&mut conn)
+//!     .await?;
+//! ```
+//!
+//! [`sqlx::Type`]: https://docs.rs/sqlx/*/sqlx/trait.Type.html
+//! [`sqlx::Encode`]: https://docs.rs/sqlx/*/sqlx/trait.Encode.html
+//! [`sqlx::Decode`]: https://docs.rs/sqlx/*/sqlx/trait.Decode.html
+//!
+//! # Property testing
+//!
+//! Including `proptest` in the argument list implements [`Arbitrary`] for the owned
+//! type. Arbitrary strings are passed through the braid's [`FromStr`] implementation,
+//! so values are validated or normalized, and any that are rejected are filtered out.
+//! This requires enabling the `proptest` feature of `strid`.
+//!
+//! Most random strings won't pass a strict validator, so a validator or normalizer can
+//! implement [`ProptestStrategy`] to generate raw strings that are likely to be valid, such
+//! as those matching a regular expression. These are still checked by the braid.
+//!
+//! ```
+//! # use strid::braid;
+//! #
+//! #[braid(validator, proptest)]
+//! pub struct Username;
+//!
+//! impl strid::ProptestStrategy for Username {
+//!     type Strategy = &'static str;
+//!
+//!     fn strategy() -> Self::Strategy {
+//!         "[a-z][a-z0-9_]{2,15}"
+//!     }
+//! }
+//! #
+//! # #[derive(Debug)]
+//! # pub struct InvalidUsername;
+//! # strid::from_infallible!(InvalidUsername);
+//! #
+//! # impl strid::Validator for Username {
+//! #     type Error = InvalidUsername;
+//! #     fn validate(s: &str) -> Result<(), Self::Error> {
+//! #         Ok(())
+//! #     }
+//! # }
+//! ```
+//!
+//! A braid can instead name a function returning a [`Strategy`] with `proptest_strategy`,
+//! which takes precedence over the validator's strategy. This suits a validator that is
+//! shared by braids with different shapes, such as those in [`validators`].
+//!
+//! ```
+//! # use strid::braid;
+//! #
+//! #[braid(
+//!     validator = "strid::validators::NonEmpty",
+//!     proptest,
+//!     proptest_strategy = "Tag::strategy"
+//! )]
+//! pub struct Tag;
+//!
+//! impl Tag {
+//!     fn strategy() -> &'static str {
+//!         "#[a-z]{1,8}"
+//!     }
+//! }
+//! ```
+//!
+//! [`Arbitrary`]: https://docs.rs/proptest/*/proptest/arbitrary/trait.Arbitrary.html
+//! [`FromStr`]: std::str::FromStr
+//! [`Strategy`]: https://docs.rs/proptest/*/proptest/strategy/trait.Strategy.html
+//!
+//! # QuickCheck
+//!
+//! Including `quickcheck` in the argument list implements QuickCheck's [`Arbitrary`][qc]
+//! for the owned type. Arbitrary strings are generated until one is accepted by the
+//! braid's [`FromStr`] implementation, and shrinking only produces values that the
+//! braid accepts unchanged, so a normalized braid shrinks to smaller normalized values.
+//! This requires enabling the `quickcheck` feature of `strid`.
+//!
+//! Unlike proptest, QuickCheck can't discard a generated value, so generation panics,
+//! failing the property, when none of 10,000 strings is accepted. A braid with a strict
+//! validator should name a `fn(&mut quickcheck::Gen) -> String` with `quickcheck_generator`
+//! that produces strings which are likely to be valid. These are still checked by the braid.
+//!
+//! ```
+//! # use strid::braid;
+//! #
+//! #[braid(validator, quickcheck, quickcheck_generator = "Digits::generate")]
+//! pub struct Digits;
+//!
+//! impl Digits {
+//!     fn generate(g: &mut quickcheck::Gen) -> String {
+//!         let len = usize::max(g.size(), 1);
+//!         (0..len).map(|_| *g.choose(&['0', '1', '7', '9']).unwrap()).collect()
+//!     }
+//! }
+//! #
+//! # #[derive(Debug)]
+//! # pub struct NotDigits;
+//! # strid::from_infallible!(NotDigits);
+//! # impl strid::Validator for Digits {
+//! #     type Error = NotDigits;
+//! #     fn validate(s: &str) -> Result<(), Self::Error> {
+//! #         if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+//! #             Ok(())
+//! #         } else {
+//! #             Err(NotDigits)
+//! #         }
+//! #     }
+//! # }
+//! ```
+//!
+//! [qc]: https://docs.rs/quickcheck/*/quickcheck/trait.Arbitrary.html
+//!
+//! # Fuzzing
+//!
+//! Including `arbitrary` in the argument list implements [`Arbitrary`] for the owned
+//! type and for references to the borrowed type, so that braids can be used in fuzz
+//! inputs. Values are taken from the input as UTF-8 and checked by the braid, failing
+//! with [`IncorrectFormat`] if they are rejected. As a reference can't be normalized,
+//! a normalized braid's borrowed form only accepts values that are already normalized.
+//! This requires enabling the `arbitrary` feature of `strid`.
+//!
+//! [`Arbitrary`]: https://docs.rs/arbitrary/*/arbitrary/trait.Arbitrary.html
+//! [`IncorrectFormat`]: https://docs.rs/arbitrary/*/arbitrary/enum.Error.html#variant.IncorrectFormat
+//!
+//! # Custom string types
+//!
+//! The `braid` macro can be used to define a custom string type that wraps types
+//! other than the standard `String`. This allows defining a braid that is backed
+//! by a type that offers small-string optimizations, such as [`SmartString`] or
+//! [`CompactString`].
+//!
+//! Functions that expose the inner wrapped type can be made private by adding the
+//! `no_expose` parameter to avoid leaking the type in the public interface.
+//!
+//! [`SmartString`]: https://docs.rs/smartstring/*/smartstring/struct.SmartString.html
+//! [`CompactString`]: https://docs.rs/compact_str/*/compact_str/struct.CompactString.html
+//!
+//! ```ignore
+//! # use strid::braid;
+//! use compact_str::CompactString;
+//! use smartstring::{SmartString, LazyCompact};
+//!
+//! #[braid(no_expose)]
+//! pub struct UserId(CompactString);
+//!
+//! #[braid(no_expose)]
+//! pub struct AltUserId(SmartString<LazyCompact>);
+//! ```
+//!
+//! It can also be used to wrap a [`ByteString`], which is a string backed by
+//! [`Bytes`], which may be useful if the type is primarily used in contexts
+//! where a zero-copy implementation is preferred.
+//!
+//! [`ByteString`]: https://docs.rs/bytestring/*/bytestring/struct.ByteString.html
+//! [`Bytes`]: https://docs.rs/bytes/*/bytes/struct.Bytes.html
+//!
+//! ```ignore
+//! # use strid::braid;
+//! use bytestring::ByteString;
+//!
+//! #[braid]
+//! pub struct ZeroCopyIdentifier(ByteString);
+//! ```
+//!
+//! ## Requirements
+//!
+//! In order to be used as a custom string type, the type must implement the
+//! following traits:
+//!
+//! * [`core::clone::Clone`] (unless `clone` is `omit`)
+//! * [`core::fmt::Debug`] (unless `debug` is `omit`)
+//! * [`core::fmt::Display`] (unless `display` is `omit`)
+//! * [`core::cmp::Eq`]
+//! * [`core::cmp::PartialEq`]
+//! * [`core::hash::Hash`]
+//! * [`core::cmp::Ord`] (unless `ord` is `omit`)
+//! * [`core::cmp::PartialOrd`] (unless `ord` is `omit`)
+//! * [`serde::Serialize`] (unless `serde` is `omit`)
+//! * [`serde::Deserialize`] (unless `serde` is `omit`)
+//! * [`core::convert::From<&str>`]
+//! * [`core::convert::From<Box<str>>`]
+//! * [`core::convert::AsRef<str>`]
+//! * [`core::convert::Into<String>`]
+//!
+//! [`serde::Serialize`]: https://docs.rs/serde/*/serde/trait.Serialize.html
+//! [`serde::Deserialize`]: https://docs.rs/serde/*/serde/trait.Deserialize.html
+//!
+//! ## Shared strings
+//!
+//! A braid can also be backed by an `Arc<str>` or `Rc<str>`, under any path, so
+//! that cloning it only bumps a reference count. These don't need `Into<String>`
+//! or serde's `rc` feature: the braid serializes as a plain string, and converting
+//! it into a `String` or a `Box` of the borrowed type copies the string.
+//!
+//! In exchange, the owned type gets an `into_arc_ref` method (or `into_rc_ref`)
+//! which hands its allocation to an `Arc` (or `Rc`) of the borrowed type without
+//! copying, along with `From` conversions in both directions.
+//!
+//! ```
+//! use std::sync::Arc;
+//! use strid::braid;
+//!
+//! #[braid]
+//! pub struct TaskId(Arc<str>);
+//!
+//! let id = TaskId::from_static("build");
+//! let shared: Arc<TaskIdRef> = id.clone().into_arc_ref();
+//! assert_eq!(shared.as_str().as_ptr(), id.as_str().as_ptr());
+//! assert_eq!(TaskId::from(shared), id);
+//! ```
+//!
+//! # Byte strings
+//!
+//! Not every identifier is text. The [`braid_bytes`] macro creates braids of
+//! arbitrary bytes, such as message keys or binary tokens, with an owned type
+//! wrapping a `Vec<u8>` and a borrowed type wrapping `[u8]`. They provide the same
+//! conversions as string braids, with `from_bytes` and `as_bytes` in place of
+//! `from_str` and `as_str`, and their `Debug` output looks like a byte string literal.
+//!
+//! ```
+//! use strid::braid_bytes;
+//!
+//! #[braid_bytes]
+//! pub struct MessageKey;
+//!
+//! let owned = MessageKey::new(vec![0xde, 0xad, b'!']);
+//! let borrowed = MessageKeyRef::from_bytes(&[0xde, 0xad, b'!']);
+//! assert_eq!(owned, borrowed);
+//! assert_eq!(format!("{borrowed:?}"), r#"b"\xde\xad!""#);
+//! ```
+//!
+//! Byte-string braids are validated with [`ByteValidator`] and normalized with
+//! [`ByteNormalizer`], which work like [`Validator`] and [`Normalizer`] on byte
+//! slices, including the `*_unchecked` constructors.
+//!
+//! ```
+//! use strid::{braid_bytes, ByteValidator};
+//!
+//! #[derive(Debug, PartialEq, Eq)]
+//! pub struct TooLong;
+//!
+//! #[braid_bytes(validator)]
+//! pub struct Token;
+//!
+//! impl ByteValidator for Token {
+//!     type Error = TooLong;
+//!     fn validate(raw: &[u8]) -> Result<(), Self::Error> {
+//!         if raw.len() <= 16 { Ok(()) } else { Err(TooLong) }
+//!     }
+//! }
+//!
+//! assert!(TokenRef::from_bytes(b"short").is_ok());
+//! assert_eq!(Token::new(vec![0; 32]), Err(TooLong));
+//! ```
+//!
+//! The owned type can also wrap another byte buffer, such as [`Bytes`], which must
+//! implement [`Clone`] (unless `clone` is `omit`), [`Eq`], [`Hash`][core::hash::Hash],
+//! `From<Vec<u8>>`, `From<Box<[u8]>>`, `AsRef<[u8]>`, and `Into<Vec<u8>>`. With
+//! `serde`, values are serialized as bytes, and the owned type also deserializes from
+//! strings and sequences of bytes for formats that have no bytes type. Facet support
+//! and the `clone`, `debug`, `ord`, `ref_name`, `ref_doc`, `ref_attr`, `owned_attr`,
+//! `no_expose`, and `no_std` options work as they do for string braids.
+//!
+//! ```
+//! use bytes::Bytes;
+//! use strid::braid_bytes;
+//!
+//! #[braid_bytes(serde)]
+//! pub struct Payload(Bytes);
+//! ```
+//!
+//! # Paths
+//!
+//! Paths and OS strings can be braided too, with the [`braid_path`] and
+//! [`braid_os_str`] macros. A path braid's owned type wraps a `PathBuf`, and its
+//! borrowed type wraps `Path`, so naming the owned type with a _Buf_ suffix gives the
+//! pair the same names as the standard library's. Both types implement `AsRef<Path>`
+//! and `AsRef<OsStr>`, so they can be passed straight to `std::fs`, and the borrowed
+//! type provides `from_path` and `as_path` along with `join`, `with_file_name`, and
+//! `with_extension`, which return a plain `PathBuf` since the new path isn't
+//! necessarily valid for the braid. `from_static` takes a string, since there are no
+//! path literals.
+//!
+//! ```
+//! use strid::braid_path;
+//!
+//! #[braid_path]
+//! pub struct ConfigDirBuf;
+//!
+//! let dir = ConfigDir::from_static("/etc/app");
+//! assert_eq!(dir.join("app.toml"), std::path::Path::new("/etc/app/app.toml"));
+//! assert_eq!(dir.to_owned().into_boxed_ref().as_path(), dir.as_path());
+//! ```
+//!
+//! Path braids are validated with [`PathValidator`] and normalized with
+//! [`PathNormalizer`], and OS string braids with [`OsStrValidator`] and
+//! [`OsStrNormalizer`]. These traits require the `std` feature. Neither macro
+//! supports the `no_std` or `facet` options.
+//!
+//! ```
+//! use std::path::Path;
+//! use strid::{braid_path, PathValidator};
+//!
+//! #[derive(Debug, PartialEq, Eq)]
+//! pub struct NotAbsolute;
+//!
+//! #[braid_path(validator)]
+//! pub struct SocketPathBuf;
+//!
+//! impl PathValidator for SocketPathBuf {
+//!     type Error = NotAbsolute;
+//!     fn validate(raw: &Path) -> Result<(), Self::Error> {
+//!         if raw.is_absolute() { Ok(()) } else { Err(NotAbsolute) }
+//!     }
+//! }
+//!
+//! assert!(SocketPath::from_path(Path::new("/run/app.sock")).is_ok());
+//! assert_eq!(SocketPathBuf::new("app.sock".into()), Err(NotAbsolute));
+//! ```
+//!
+//! # C strings
+//!
+//! A braid whose field is a `CString` wraps a C string instead of a `String`, with
+//! a borrowed type wrapping `CStr`, and `braid_ref` does the same for a `CStr`
+//! field. The borrowed type provides `from_c_str` and `as_c_str`, along with
+//! `as_ptr` and an unsafe `from_ptr`, which checks the value like any other
+//! constructor, so passing a braid to or from C keeps its invariants. The owned
+//! type can also be built from bytes or strings with `TryFrom`, which fails if
+//! they contain an interior nul byte. `from_static` takes a C string literal.
+//!
+//! ```
+//! use std::ffi::CString;
+//! use strid::braid;
+//!
+//! #[braid]
+//! pub struct Symbol(CString);
+//!
+//! let symbol = Symbol::try_from("main").unwrap();
+//! assert_eq!(symbol, SymbolRef::from_static(c"main"));
+//! assert!(Symbol::try_from("ma\0in").is_err());
+//!
+//! let raw = unsafe { SymbolRef::from_ptr(symbol.as_ptr()) };
+//! assert_eq!(raw.as_c_str(), c"main");
+//! ```
+//!
+//! C string braids are validated with [`CStrValidator`] and normalized with
+//! [`CStrNormalizer`]. The error of a checked braid must implement
+//! `From<NulError>`, so that the `TryFrom` conversions can report an interior nul
+//! byte. They take the same options as byte-string braids, except for `facet`.
+//!
+//! Because `CStr` doesn't need an allocator, a `braid_ref` around one works with
+//! `no_std` and without the `alloc` feature of `strid`, taking the `validator`,
+//! `debug`, `ord`, `serde`, and `no_std` options.
+//!
+//! ```
+//! use core::ffi::CStr;
+//! use strid::{braid_ref, CStrValidator};
+//!
+//! #[derive(Debug, PartialEq, Eq)]
+//! pub struct Empty;
+//!
+//! #[braid_ref(validator, no_std)]
+//! pub struct DeviceName(CStr);
+//!
+//! impl CStrValidator for DeviceName {
+//!     type Error = Empty;
+//!     fn validate(raw: &CStr) -> Result<(), Self::Error> {
+//!         if raw.is_empty() { Err(Empty) } else { Ok(()) }
+//!     }
+//! }
+//!
+//! assert!(DeviceName::from_c_str(c"eth0").is_ok());
+//! assert_eq!(DeviceName::from_c_str(c"").unwrap_err(), Empty);
+//! ```
+//!
+//! # `no_std` support
+//!
+//! Braids can be implemented in `no_std` environments with `alloc`. By adding the
+//! `no_std` parameter to the macro, all impls will reference the `core` or `alloc`
+//! crates instead of the `std` crate, as appropriate.
+//!
+//! ```
+//! extern crate alloc;
+//!
+//! use strid::braid;
+//! use alloc::string::String;
+//!
+//! #[braid(no_std)]
+//! pub struct NoStdLibWrapper;
+//! #
+//! # fn main() {}
+//! ```
+//!
+//! In environments without an allocator, `braid_ref` can be used to create a
+//! reference-only braid. In order to remove the `alloc` dependency in `strid`,
+//! specify `default-features = "false"` in the `Cargo.toml` file.
+//!
+//! ```
+//! use strid::braid_ref;
+//!
+//! #[braid_ref(no_std)]
+//! pub struct NoStdValue(str);
+//! #
+//! # fn main() {}
+//! ```
+//!
+//! # Safety
+//!
+//! Braid uses limited `unsafe` in order to be able to reinterpret string slices
+//! (`&str`) as the borrowed form. Because this functionality is provided as a
+//! macro, using the `#![forbid(unsafe_code)]` lint level on a crate that generates
+//! braids will result in compiler errors. Instead, the crate can be annotated with
+//! `#![deny(unsafe_code)]`, which allows for overrides as appropriate. The functions
+//! that require `unsafe` to work correctly are annotated with `#[allow(unsafe_code)]`,
+//! and all usages of unsafe that the macro generates are annotated with `SAFETY`
+//! code comments.
+//!
+//! If strict adherence to forbid unsafe code is required, then the types can be
+//! segregated into an accessory crate without the prohibition, and then consumed
+//! safely from crates that otherwise forbid unsafe code.
+
+#![warn(
+    missing_docs,
+    unused_import_braces,
+    unused_imports,
+    unused_qualifications
+)]
+#![deny(
+    missing_debug_implementations,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_must_use
+)]
+#![deny(unsafe_code)]
 #![no_std]
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(any(feature = "regex", feature = "std"))]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+pub mod normalizers;
+pub mod validators;
 
 /// A validator that can verify a given input is valid given certain preconditions
 ///
@@ -925,6 +1694,233 @@ pub trait Normalizer: Validator {
     fn normalize(raw: &str) -> Result<::alloc::borrow::Cow<'_, str>, Self::Error>;
 }
 
+/// A validator for byte-string braids, which can verify that the given bytes are valid
+///
+/// This is the counterpart of [`Validator`] for braids created with
+/// [`braid_bytes`]. If the type can be normalized, this implementation should also
+/// validate that the value is _already in normalized form_.
+pub trait ByteValidator {
+    /// The error produced when the bytes are invalid
+    type Error;
+
+    /// Validates bytes according to a predetermined set of rules
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are invalid or not in normalized form.
+    fn validate(raw: &[u8]) -> Result<(), Self::Error>;
+}
+
+/// A normalizer for byte-string braids, which can verify that the given bytes
+/// are valid and performs necessary normalization
+#[cfg(feature = "alloc")]
+pub trait ByteNormalizer: ByteValidator {
+    /// Validates and normalizes the borrowed input
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are invalid and cannot be normalized.
+    fn normalize(raw: &[u8]) -> Result<::alloc::borrow::Cow<'_, [u8]>, Self::Error>;
+}
+
+/// A validator for path braids, which can verify that the given path is valid
+///
+/// This is the counterpart of [`Validator`] for braids created with
+/// [`braid_path`]. If the type can be normalized, this implementation should also
+/// validate that the value is _already in normalized form_.
+#[cfg(feature = "std")]
+pub trait PathValidator {
+    /// The error produced when the path is invalid
+    type Error;
+
+    /// Validates a path according to a predetermined set of rules
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is invalid or not in normalized form.
+    fn validate(raw: &std::path::Path) -> Result<(), Self::Error>;
+}
+
+/// A normalizer for path braids, which can verify that the given path is valid
+/// and performs necessary normalization
+#[cfg(feature = "std")]
+pub trait PathNormalizer: PathValidator {
+    /// Validates and normalizes the borrowed input
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is invalid and cannot be normalized.
+    fn normalize(
+        raw: &std::path::Path,
+    ) -> Result<::alloc::borrow::Cow<'_, std::path::Path>, Self::Error>;
+}
+
+/// A validator for OS string braids, which can verify that the given OS string is valid
+///
+/// This is the counterpart of [`Validator`] for braids created with
+/// [`braid_os_str`]. If the type can be normalized, this implementation should also
+/// validate that the value is _already in normalized form_.
+#[cfg(feature = "std")]
+pub trait OsStrValidator {
+    /// The error produced when the OS string is invalid
+    type Error;
+
+    /// Validates an OS string according to a predetermined set of rules
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the OS string is invalid or not in normalized form.
+    fn validate(raw: &std::ffi::OsStr) -> Result<(), Self::Error>;
+}
+
+/// A normalizer for OS string braids, which can verify that the given OS string
+/// is valid and performs necessary normalization
+#[cfg(feature = "std")]
+pub trait OsStrNormalizer: OsStrValidator {
+    /// Validates and normalizes the borrowed input
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the OS string is invalid and cannot be normalized.
+    fn normalize(
+        raw: &std::ffi::OsStr,
+    ) -> Result<::alloc::borrow::Cow<'_, std::ffi::OsStr>, Self::Error>;
+}
+
+/// A validator for C string braids, which can verify that the given C string is valid
+///
+/// This is the counterpart of [`Validator`] for braids around a `CString` or `CStr`.
+/// If the type can be normalized, this implementation should also validate that the
+/// value is _already in normalized form_.
+pub trait CStrValidator {
+    /// The error produced when the C string is invalid
+    type Error;
+
+    /// Validates a C string according to a predetermined set of rules
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the C string is invalid or not in normalized form.
+    fn validate(raw: &core::ffi::CStr) -> Result<(), Self::Error>;
+}
+
+/// A normalizer for C string braids, which can verify that the given C string is
+/// valid and performs necessary normalization
+#[cfg(feature = "alloc")]
+pub trait CStrNormalizer: CStrValidator {
+    /// Validates and normalizes the borrowed input
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the C string is invalid and cannot be normalized.
+    fn normalize(
+        raw: &core::ffi::CStr,
+    ) -> Result<::alloc::borrow::Cow<'_, core::ffi::CStr>, Self::Error>;
+}
+
+/// Describes the values that a validator accepts
+///
+/// Schema generators use these constraints to describe a braid more precisely than
+/// as an arbitrary string. Every constraint is optional, and none of them are checked:
+/// they should agree with the validator, which remains the source of truth.
+pub trait Constraints {
+    /// A regular expression that matches valid values
+    const PATTERN: Option<&'static str> = None;
+
+    /// The minimum length of a valid value, in characters
+    const MIN_LENGTH: Option<u64> = None;
+
+    /// The maximum length of a valid value, in characters
+    const MAX_LENGTH: Option<u64> = None;
+
+    /// A well-known format of valid values, such as `email` or `uuid`
+    const FORMAT: Option<&'static str> = None;
+
+    /// The complete set of valid values
+    const ENUM: Option<&'static [&'static str]> = None;
+
+    /// Examples of valid values
+    const EXAMPLES: Option<&'static [&'static str]> = None;
+}
+
+/// Generates raw values that a validator is likely to accept
+///
+/// Braids that include `proptest` generate values from this strategy when their validator or
+/// normalizer implements it, unless the braid names its own with `proptest_strategy`. Either
+/// way, the braid still checks every value it is given.
+#[cfg(feature = "proptest")]
+pub trait ProptestStrategy {
+    /// The strategy for raw values, such as a regular expression as a `&'static str`
+    type Strategy: proptest::strategy::Strategy;
+
+    /// Returns the strategy for raw values
+    fn strategy() -> Self::Strategy;
+}
+
+/// The error produced when a value doesn't match the `pattern` of a braid
+#[cfg(feature = "regex")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatternError {
+    value: alloc::string::String,
+    pattern: &'static str,
+    invalid_pattern: Option<alloc::string::String>,
+}
+
+#[cfg(feature = "regex")]
+impl PatternError {
+    /// The value that was rejected
+    #[inline]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// The pattern that the value was expected to match
+    #[inline]
+    pub fn pattern(&self) -> &'static str {
+        self.pattern
+    }
+
+    /// Why the pattern couldn't be compiled, if that is why the value was rejected
+    ///
+    /// Patterns are parsed when the braid is expanded, but a pattern that parses can still
+    /// exceed the size limits of [`regex`] when it is compiled.
+    ///
+    /// [`regex`]: https://docs.rs/regex
+    #[inline]
+    pub fn invalid_pattern(&self) -> Option<&str> {
+        self.invalid_pattern.as_deref()
+    }
+}
+
+#[cfg(feature = "regex")]
+impl core::fmt::Display for PatternError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match &self.invalid_pattern {
+            Some(reason) => write!(
+                f,
+                "{:?} could not be checked against the pattern `{}`: {}",
+                self.value, self.pattern, reason
+            ),
+            None => write!(
+                f,
+                "{:?} does not match the pattern `{}`",
+                self.value, self.pattern
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "regex")]
+impl core::error::Error for PatternError {}
+
+#[cfg(feature = "regex")]
+impl From<core::convert::Infallible> for PatternError {
+    #[inline(always)]
+    fn from(x: core::convert::Infallible) -> Self {
+        match x {}
+    }
+}
+
 /// Utility macro for easily defining `From<Infallible>` for a given type.
 ///
 /// # Example
@@ -954,5 +1950,426 @@ macro_rules! from_infallible {
     };
 }
 
+/// Creates a `&'static` borrowed braid from a string literal, checked at compile time
+///
+/// The borrowed type must be generated with a `const_validator`, which makes its `from_static`
+/// function a `const fn`. This macro evaluates that function in a `const` item, so an invalid
+/// literal fails compilation instead of panicking at runtime.
+///
+/// # Example
+///
+/// ```
+/// use strid::{braid, lit};
+///
+/// # #[derive(Debug)]
+/// # pub struct Empty;
+/// # strid::from_infallible!(Empty);
+/// #[braid(validator, const_validator = "is_not_empty")]
+/// pub struct Name;
+///
+/// const fn is_not_empty(s: &str) -> bool {
+///     !s.is_empty()
+/// }
+///
+/// impl strid::Validator for Name {
+///     type Error = Empty;
+///     fn validate(s: &str) -> Result<(), Self::Error> {
+///         if is_not_empty(s) { Ok(()) } else { Err(Empty) }
+///     }
+/// }
+///
+/// let name: &NameRef = lit!(NameRef, "strid");
+/// assert_eq!("strid", name.as_str());
+/// ```
+#[macro_export]
+macro_rules! lit {
+    ($ty:ty, $raw:expr $(,)?) => {{
+        const VALUE: &'static $ty = <$ty>::from_static($raw);
+        VALUE
+    }};
+}
+
 pub use facet;
-pub use strid_macros::{braid, braid_ref};
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "arbitrary")]
+    pub use arbitrary;
+    #[cfg(feature = "postgres")]
+    pub use bytes;
+    #[cfg(feature = "diesel")]
+    pub use diesel;
+    #[cfg(feature = "postgres")]
+    pub use postgres_types;
+    #[cfg(feature = "proptest")]
+    pub use proptest;
+    #[cfg(feature = "quickcheck")]
+    pub use quickcheck;
+    #[cfg(feature = "rusqlite")]
+    pub use rusqlite;
+    #[cfg(feature = "sailfish")]
+    pub use sailfish;
+    #[cfg(feature = "schemars")]
+    pub use schemars;
+    #[cfg(feature = "serde")]
+    pub use serde;
+    #[cfg(feature = "sqlx")]
+    pub use sqlx;
+    #[cfg(feature = "ts-rs")]
+    pub use ts_rs;
+    #[cfg(feature = "utoipa")]
+    pub use utoipa;
+
+    /// The buffer that facet builds an owned braid from before converting it, so that the
+    /// braid checks and normalizes the value the same way as any other conversion
+    #[cfg(feature = "alloc")]
+    #[derive(Debug, facet::Facet)]
+    #[facet(transparent)]
+    pub struct FacetProxy<T>(pub T);
+
+    // Facet reports errors as strings, so it needs `alloc` whatever the features of strid
+    extern crate alloc as facet_alloc;
+
+    /// The message facet reports when a braid rejects a value
+    pub use facet_alloc::string::String as FacetMessage;
+
+    /// The error facet reports when a braid rejects the value it was built from, carrying
+    /// the description of the braid's own error
+    #[derive(Debug)]
+    pub struct FacetInvalid(pub FacetMessage);
+
+    impl core::fmt::Display for FacetInvalid {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            f.write_str(&self.0)
+        }
+    }
+
+    /// A braid's error, described by its `Display` impl if it has one
+    ///
+    /// `Validator::Error` isn't required to implement `Display`, so generated code calls
+    /// `(&FacetError(&error)).describe()` with both [`FacetDisplay`] and [`FacetFallback`] in
+    /// scope. Method resolution picks the `Display` description when it applies and falls
+    /// back to a fixed message otherwise.
+    #[derive(Debug)]
+    pub struct FacetError<'a, E>(pub &'a E);
+
+    /// Describes an error that implements `Display`
+    pub trait FacetDisplay {
+        /// The error's `Display` output
+        fn describe(&self) -> FacetMessage;
+    }
+
+    impl<E: core::fmt::Display> FacetDisplay for FacetError<'_, E> {
+        fn describe(&self) -> FacetMessage {
+            facet_alloc::string::ToString::to_string(self.0)
+        }
+    }
+
+    /// Describes an error that doesn't implement `Display`
+    pub trait FacetFallback {
+        /// A fixed message, since there is nothing better to report
+        fn describe(&self) -> FacetMessage;
+    }
+
+    impl<E> FacetFallback for &FacetError<'_, E> {
+        fn describe(&self) -> FacetMessage {
+            FacetMessage::from("value was rejected by the braid")
+        }
+    }
+
+    /// The validator of a braid that includes `proptest`, along with the type of raw value it
+    /// generates
+    ///
+    /// `ProptestStrategy` is optional, so generated code calls
+    /// `(&ProptestRaw::<V, B>(PhantomData)).raw_strategy()` with both [`ProptestValidator`] and
+    /// [`ProptestAny`] in scope. Method resolution picks the validator's strategy when it has
+    /// one and falls back to arbitrary raw values otherwise.
+    #[cfg(feature = "proptest")]
+    #[derive(Debug)]
+    pub struct ProptestRaw<V, B>(pub core::marker::PhantomData<fn(V) -> B>);
+
+    /// Generates raw values from a validator's strategy
+    #[cfg(feature = "proptest")]
+    pub trait ProptestValidator<B> {
+        /// The validator's strategy
+        fn raw_strategy(&self) -> proptest::strategy::BoxedStrategy<B>;
+    }
+
+    #[cfg(feature = "proptest")]
+    impl<V, B> ProptestValidator<B> for ProptestRaw<V, B>
+    where
+        V: crate::ProptestStrategy,
+        V::Strategy: proptest::strategy::Strategy<Value = B> + 'static,
+    {
+        fn raw_strategy(&self) -> proptest::strategy::BoxedStrategy<B> {
+            proptest::strategy::Strategy::boxed(V::strategy())
+        }
+    }
+
+    /// Generates arbitrary raw values for a validator without a strategy
+    #[cfg(feature = "proptest")]
+    pub trait ProptestAny<B> {
+        /// Any value of the raw type
+        fn raw_strategy(&self) -> proptest::strategy::BoxedStrategy<B>;
+    }
+
+    #[cfg(feature = "proptest")]
+    impl<V, B> ProptestAny<B> for &ProptestRaw<V, B>
+    where
+        B: proptest::arbitrary::Arbitrary,
+        B::Strategy: 'static,
+    {
+        fn raw_strategy(&self) -> proptest::strategy::BoxedStrategy<B> {
+            proptest::strategy::Strategy::boxed(proptest::arbitrary::any::<B>())
+        }
+    }
+
+    /// The constraints of a braid that doesn't declare any
+    #[derive(Debug)]
+    pub enum Unconstrained {}
+
+    impl crate::Constraints for Unconstrained {}
+
+    /// Builds the JSON Schema of a braid from its doc comment and constraints
+    #[cfg(feature = "schemars")]
+    pub fn string_schema<C: crate::Constraints + ?Sized>(doc: &str) -> schemars::Schema {
+        let mut schema = schemars::json_schema!({ "type": "string" });
+
+        let description = description(doc);
+        if !description.is_empty() {
+            schema.insert("description".into(), description.into());
+        }
+        if let Some(pattern) = C::PATTERN {
+            schema.insert("pattern".into(), pattern.into());
+        }
+        if let Some(min_length) = C::MIN_LENGTH {
+            schema.insert("minLength".into(), min_length.into());
+        }
+        if let Some(max_length) = C::MAX_LENGTH {
+            schema.insert("maxLength".into(), max_length.into());
+        }
+        if let Some(format) = C::FORMAT {
+            schema.insert("format".into(), format.into());
+        }
+        if let Some(values) = C::ENUM {
+            schema.insert("enum".into(), values.into());
+        }
+        if let Some(examples) = C::EXAMPLES {
+            schema.insert("examples".into(), examples.into());
+        }
+
+        schema
+    }
+
+    /// Builds the OpenAPI schema of a braid from its doc comment and constraints
+    #[cfg(feature = "utoipa")]
+    pub fn openapi_schema<C: crate::Constraints + ?Sized>(
+        doc: &str,
+    ) -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+        use utoipa::openapi::schema::{ObjectBuilder, SchemaFormat, Type};
+
+        let description = description(doc);
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .description((!description.is_empty()).then_some(description))
+            .pattern(C::PATTERN)
+            .min_length(C::MIN_LENGTH.and_then(|len| len.try_into().ok()))
+            .max_length(C::MAX_LENGTH.and_then(|len| len.try_into().ok()))
+            .format(C::FORMAT.map(|format| SchemaFormat::Custom(format.into())))
+            .enum_values(C::ENUM.map(|values| values.iter().copied()))
+            .examples(C::EXAMPLES.unwrap_or_default().iter().copied())
+            .into()
+    }
+
+    /// Whether two strings or byte strings are equal, ignoring the case of ASCII letters
+    pub fn ascii_case_eq<T: AsRef<[u8]> + ?Sized>(a: &T, b: &T) -> bool {
+        a.as_ref().eq_ignore_ascii_case(b.as_ref())
+    }
+
+    /// Compares two strings or byte strings, ignoring the case of ASCII letters
+    pub fn ascii_case_cmp<T: AsRef<[u8]> + ?Sized>(a: &T, b: &T) -> core::cmp::Ordering {
+        let a = a.as_ref().iter().map(u8::to_ascii_lowercase);
+        let b = b.as_ref().iter().map(u8::to_ascii_lowercase);
+        a.cmp(b)
+    }
+
+    /// Hashes a string or byte string so that it agrees with [`ascii_case_cmp`]
+    pub fn ascii_case_hash<T: AsRef<[u8]> + ?Sized, H: core::hash::Hasher>(s: &T, state: &mut H) {
+        for b in s.as_ref() {
+            state.write_u8(b.to_ascii_lowercase());
+        }
+        // Matches `str`, so that a value isn't a prefix of the value hashed after it
+        state.write_u8(0xff);
+    }
+
+    /// Whether two strings are equal after Unicode default case folding
+    #[cfg(feature = "caseless")]
+    pub fn case_fold_eq(a: &str, b: &str) -> bool {
+        use caseless::Caseless;
+
+        a.chars()
+            .default_case_fold()
+            .eq(b.chars().default_case_fold())
+    }
+
+    /// Compares two strings after Unicode default case folding
+    #[cfg(feature = "caseless")]
+    pub fn case_fold_cmp(a: &str, b: &str) -> core::cmp::Ordering {
+        use caseless::Caseless;
+
+        a.chars()
+            .default_case_fold()
+            .cmp(b.chars().default_case_fold())
+    }
+
+    /// Hashes a string so that it agrees with [`case_fold_eq`]
+    #[cfg(feature = "caseless")]
+    pub fn case_fold_hash<H: core::hash::Hasher>(s: &str, state: &mut H) {
+        use caseless::Caseless;
+
+        for c in s.chars().default_case_fold() {
+            state.write_u32(c.into());
+        }
+        state.write_u8(0xff);
+    }
+
+    /// Deserializes the bytes of a byte-string braid, accepting any of the forms that
+    /// formats without a bytes type use instead, such as strings and sequences
+    #[cfg(all(feature = "serde", feature = "alloc"))]
+    pub fn deserialize_byte_buf<'de, D>(deserializer: D) -> Result<alloc::vec::Vec<u8>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct ByteBufVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ByteBufVisitor {
+            type Value = alloc::vec::Vec<u8>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.write_str("a byte array")
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                Ok(v.into())
+            }
+
+            fn visit_byte_buf<E: serde::de::Error>(
+                self,
+                v: alloc::vec::Vec<u8>,
+            ) -> Result<Self::Value, E> {
+                Ok(v)
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(v.as_bytes().into())
+            }
+
+            fn visit_string<E: serde::de::Error>(
+                self,
+                v: alloc::string::String,
+            ) -> Result<Self::Value, E> {
+                Ok(v.into_bytes())
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let capacity = seq.size_hint().unwrap_or(0).min(4096);
+                let mut bytes = alloc::vec::Vec::with_capacity(capacity);
+                while let Some(b) = seq.next_element()? {
+                    bytes.push(b);
+                }
+                Ok(bytes)
+            }
+        }
+
+        deserializer.deserialize_byte_buf(ByteBufVisitor)
+    }
+
+    /// Generates raw strings with `generate` until one is accepted by the braid
+    ///
+    /// QuickCheck has no way to discard a generated value, so this panics, failing the property
+    /// under test, if none of a large number of attempts is accepted.
+    #[cfg(feature = "quickcheck")]
+    pub fn quickcheck_arbitrary<T, F>(g: &mut quickcheck::Gen, mut generate: F) -> T
+    where
+        T: core::str::FromStr,
+        F: FnMut(&mut quickcheck::Gen) -> alloc::string::String,
+    {
+        const ATTEMPTS: usize = 10_000;
+
+        for _ in 0..ATTEMPTS {
+            if let Ok(value) = generate(g).parse() {
+                return value;
+            }
+        }
+        panic!(
+            "no generated string was accepted by `{}` after {ATTEMPTS} attempts; use \
+             `quickcheck_generator` to generate likelier values",
+            core::any::type_name::<T>()
+        )
+    }
+
+    /// Shrinks the value of a braid, keeping only the candidates that the braid accepts as-is
+    ///
+    /// A candidate that the braid would normalize into a different value is dropped, so that
+    /// shrinking a normalized braid only ever moves toward smaller normalized values.
+    #[cfg(feature = "quickcheck")]
+    pub fn quickcheck_shrink<T>(value: &str) -> alloc::boxed::Box<dyn Iterator<Item = T>>
+    where
+        T: core::str::FromStr + AsRef<str> + 'static,
+    {
+        let raw = alloc::string::String::from(value);
+        alloc::boxed::Box::new(quickcheck::Arbitrary::shrink(&raw).filter_map(|raw| {
+            raw.parse::<T>()
+                .ok()
+                .filter(|value| value.as_ref() == raw.as_str())
+        }))
+    }
+
+    /// The regular expression of a braid declared with `pattern`, compiled on first use
+    #[cfg(feature = "regex")]
+    #[derive(Debug)]
+    pub struct LazyPattern {
+        pattern: &'static str,
+        regex: std::sync::OnceLock<Result<regex::Regex, regex::Error>>,
+    }
+
+    #[cfg(feature = "regex")]
+    impl LazyPattern {
+        pub const fn new(pattern: &'static str) -> Self {
+            Self {
+                pattern,
+                regex: std::sync::OnceLock::new(),
+            }
+        }
+
+        pub fn validate(&self, raw: &str) -> Result<(), crate::PatternError> {
+            let regex = self.regex.get_or_init(|| regex::Regex::new(self.pattern));
+            let invalid_pattern = match regex {
+                Ok(regex) if regex.is_match(raw) => return Ok(()),
+                Ok(_) => None,
+                Err(e) => Some(alloc::string::ToString::to_string(e)),
+            };
+
+            Err(crate::PatternError {
+                value: raw.into(),
+                pattern: self.pattern,
+                invalid_pattern,
+            })
+        }
+    }
+
+    /// Turns a doc comment into a description, dropping the space that follows each `///`
+    #[cfg(any(feature = "schemars", feature = "utoipa"))]
+    fn description(doc: &str) -> alloc::string::String {
+        let lines: alloc::vec::Vec<&str> = doc
+            .lines()
+            .map(|line| line.strip_prefix(' ').unwrap_or(line))
+            .collect();
+        lines.join("\n").trim().into()
+    }
+}
+
+pub use strid_macros::{braid, braid_bytes, braid_os_str, braid_path, braid_ref};
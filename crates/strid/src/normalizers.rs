@@ -0,0 +1,255 @@
+//! Composable normalizers for common forms
+//!
+//! Each normalizer is a zero-sized type implementing [`Normalizer`], so it can be named
+//! directly in `#[braid(normalizer = "...")]`. Normalizers are chained with [`Then`],
+//! which also accepts any of the [`validators`](crate::validators) to check the
+//! normalized value. They all share [`ValidationError`] as their error type, and
+//! reject a value that isn't already normalized with
+//! [`ValidationError::NotNormalized`] when used as a [`Validator`].
+//!
+//! A value that is already normalized is returned as [`Cow::Borrowed`], so normalizing
+//! it doesn't allocate.
+//!
+//! ```
+//! use strid::{
+//!     braid,
+//!     normalizers::{AsciiLowercase, Then, Trim},
+//!     validators::NonEmpty,
+//! };
+//!
+//! #[braid(normalizer = "Then<Trim, Then<AsciiLowercase, NonEmpty>>")]
+//! pub struct Tag;
+//!
+//! assert_eq!(Tag::new(" Rust ".to_owned()).unwrap().as_str(), "rust");
+//! assert!(TagRef::from_str("   ").is_err());
+//! assert!(TagRef::from_normalized_str("Rust").is_err());
+//! ```
+//!
+//! Unicode normalization forms require the `unicode-normalization` feature, and
+//! [`UnicodeCaseFold`] requires the `caseless` feature.
+
+use alloc::{borrow::Cow, string::String};
+use core::marker::PhantomData;
+
+use crate::{Normalizer, Validator, validators::ValidationError};
+
+/// Normalizes with `A`, and then with `B`
+///
+/// `B` is often one of the [`validators`](crate::validators), which checks the normalized
+/// value without changing it.
+#[derive(Debug)]
+pub struct Then<A, B>(PhantomData<fn() -> (A, B)>);
+
+impl<A, B> Validator for Then<A, B>
+where
+    A: Normalizer<Error = ValidationError>,
+    B: Normalizer<Error = ValidationError>,
+{
+    type Error = ValidationError;
+
+    #[inline]
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        A::validate(raw)?;
+        B::validate(raw)
+    }
+}
+
+impl<A, B> Normalizer for Then<A, B>
+where
+    A: Normalizer<Error = ValidationError>,
+    B: Normalizer<Error = ValidationError>,
+{
+    #[inline]
+    fn normalize(raw: &str) -> Result<Cow<'_, str>, Self::Error> {
+        match A::normalize(raw)? {
+            Cow::Borrowed(normalized) => B::normalize(normalized),
+            Cow::Owned(normalized) => match B::normalize(&normalized)? {
+                Cow::Borrowed(renormalized) if renormalized.len() == normalized.len() => {
+                    Ok(Cow::Owned(normalized))
+                }
+                renormalized => Ok(Cow::Owned(renormalized.into_owned())),
+            },
+        }
+    }
+}
+
+/// Rejects a value that isn't already normalized
+fn require_normalized(is_normalized: bool) -> Result<(), ValidationError> {
+    if is_normalized {
+        Ok(())
+    } else {
+        Err(ValidationError::NotNormalized)
+    }
+}
+
+macro_rules! unicode_forms {
+    ($($(#[$attr:meta])* $name:ident => $is_normalized:ident, $method:ident;)*) => {
+        $(
+            $(#[$attr])*
+            #[cfg(feature = "unicode-normalization")]
+            #[derive(Debug)]
+            pub struct $name;
+
+            #[cfg(feature = "unicode-normalization")]
+            impl Validator for $name {
+                type Error = ValidationError;
+
+                #[inline]
+                fn validate(raw: &str) -> Result<(), Self::Error> {
+                    require_normalized(unicode_normalization::$is_normalized(raw))
+                }
+            }
+
+            #[cfg(feature = "unicode-normalization")]
+            impl Normalizer for $name {
+                #[inline]
+                fn normalize(raw: &str) -> Result<Cow<'_, str>, Self::Error> {
+                    if unicode_normalization::$is_normalized(raw) {
+                        Ok(Cow::Borrowed(raw))
+                    } else {
+                        let normalized = unicode_normalization::UnicodeNormalization::$method(raw);
+                        Ok(Cow::Owned(normalized.collect()))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+unicode_forms! {
+    /// Normalization Form C: canonical decomposition followed by canonical composition
+    Nfc => is_nfc, nfc;
+    /// Normalization Form KC: compatibility decomposition followed by canonical composition
+    ///
+    /// This folds compatibility variants, such as full-width letters and ligatures, into
+    /// their plain forms, which suits identifiers like usernames.
+    Nfkc => is_nfkc, nfkc;
+    /// Normalization Form D: canonical decomposition
+    Nfd => is_nfd, nfd;
+}
+
+/// Lowercases ASCII letters, leaving all other characters unchanged
+#[derive(Debug)]
+pub struct AsciiLowercase;
+
+impl Validator for AsciiLowercase {
+    type Error = ValidationError;
+
+    #[inline]
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        require_normalized(!raw.bytes().any(|b| b.is_ascii_uppercase()))
+    }
+}
+
+impl Normalizer for AsciiLowercase {
+    #[inline]
+    fn normalize(raw: &str) -> Result<Cow<'_, str>, Self::Error> {
+        if raw.bytes().any(|b| b.is_ascii_uppercase()) {
+            Ok(Cow::Owned(raw.to_ascii_lowercase()))
+        } else {
+            Ok(Cow::Borrowed(raw))
+        }
+    }
+}
+
+/// Applies Unicode default case folding, for comparing values without regard to case
+///
+/// Case folding is not the same as lowercasing: for example, `ß` folds to `ss`. Case
+/// folding can leave a value in a different normalization form, so follow it with a
+/// Unicode normalization form if one is required.
+#[cfg(feature = "caseless")]
+#[derive(Debug)]
+pub struct UnicodeCaseFold;
+
+#[cfg(feature = "caseless")]
+impl Validator for UnicodeCaseFold {
+    type Error = ValidationError;
+
+    #[inline]
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        use caseless::Caseless;
+
+        require_normalized(raw.chars().default_case_fold().eq(raw.chars()))
+    }
+}
+
+#[cfg(feature = "caseless")]
+impl Normalizer for UnicodeCaseFold {
+    #[inline]
+    fn normalize(raw: &str) -> Result<Cow<'_, str>, Self::Error> {
+        use caseless::Caseless;
+
+        if raw.chars().default_case_fold().eq(raw.chars()) {
+            Ok(Cow::Borrowed(raw))
+        } else {
+            Ok(Cow::Owned(raw.chars().default_case_fold().collect()))
+        }
+    }
+}
+
+/// Removes leading and trailing whitespace
+#[derive(Debug)]
+pub struct Trim;
+
+impl Validator for Trim {
+    type Error = ValidationError;
+
+    #[inline]
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        require_normalized(raw.trim().len() == raw.len())
+    }
+}
+
+impl Normalizer for Trim {
+    #[inline]
+    fn normalize(raw: &str) -> Result<Cow<'_, str>, Self::Error> {
+        Ok(Cow::Borrowed(raw.trim()))
+    }
+}
+
+/// Replaces each run of whitespace with a single space
+///
+/// Leading and trailing whitespace is collapsed too, rather than removed, so combine this
+/// with [`Trim`] to remove it as well.
+#[derive(Debug)]
+pub struct CollapseWhitespace;
+
+impl CollapseWhitespace {
+    fn is_collapsed(raw: &str) -> bool {
+        let mut after_space = false;
+        raw.chars().all(|c| {
+            let ok = !c.is_whitespace() || (c == ' ' && !after_space);
+            after_space = c.is_whitespace();
+            ok
+        })
+    }
+}
+
+impl Validator for CollapseWhitespace {
+    type Error = ValidationError;
+
+    #[inline]
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        require_normalized(Self::is_collapsed(raw))
+    }
+}
+
+impl Normalizer for CollapseWhitespace {
+    fn normalize(raw: &str) -> Result<Cow<'_, str>, Self::Error> {
+        if Self::is_collapsed(raw) {
+            return Ok(Cow::Borrowed(raw));
+        }
+
+        let mut collapsed = String::with_capacity(raw.len());
+        let mut after_space = false;
+        for c in raw.chars() {
+            if !c.is_whitespace() {
+                collapsed.push(c);
+            } else if !after_space {
+                collapsed.push(' ');
+            }
+            after_space = c.is_whitespace();
+        }
+        Ok(Cow::Owned(collapsed))
+    }
+}
@@ -0,0 +1,365 @@
+//! Composable validators for common rules
+//!
+//! Each validator is a zero-sized type implementing [`Validator`], so it can be named
+//! directly in `#[braid(validator = "...")]`, and validators can be combined with
+//! [`And`], [`Or`], and [`Not`]. They all share [`ValidationError`] as their error type,
+//! which already implements `From<Infallible>`.
+//!
+//! Lengths are counted in characters, and positions are byte offsets into the
+//! rejected string.
+//!
+//! Every validator here is also a [`Normalizer`](crate::Normalizer) that accepts values
+//! as they are, so that it can follow one of the [`normalizers`](crate::normalizers)
+//! in a [`Then`](crate::normalizers::Then).
+//!
+//! ```
+//! use strid::{
+//!     braid,
+//!     validators::{And, AsciiAlphanumeric, Char, CharSet, MaxLen, NonEmpty},
+//! };
+//!
+//! /// ASCII letters, digits, `-`, and `_`, up to 64 characters long
+//! pub type SlugRules =
+//!     And<NonEmpty, And<MaxLen<64>, CharSet<(AsciiAlphanumeric, Char<'-'>, Char<'_'>)>>>;
+//!
+//! #[braid(validator = "SlugRules")]
+//! pub struct Slug;
+//!
+//! assert_eq!(Slug::from_static("user-name_1").as_str(), "user-name_1");
+//! assert!(SlugRef::from_str("").is_err());
+//! assert!(SlugRef::from_str("no spaces").is_err());
+//! ```
+
+use core::{convert::Infallible, fmt, marker::PhantomData};
+
+use crate::Validator;
+
+/// The reason that a string was rejected by one of the [`validators`](self)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ValidationError {
+    /// The string was empty
+    Empty,
+    /// The string had fewer characters than required
+    TooShort {
+        /// The minimum number of characters
+        min: usize,
+        /// The number of characters in the string
+        actual: usize,
+    },
+    /// The string had more characters than allowed
+    TooLong {
+        /// The maximum number of characters
+        max: usize,
+        /// The number of characters in the string
+        actual: usize,
+    },
+    /// The string contained a character that is not ASCII
+    NonAscii {
+        /// The byte offset of the character
+        position: usize,
+    },
+    /// The string contained a character outside of the allowed set
+    InvalidCharacter {
+        /// The byte offset of the character
+        position: usize,
+        /// The rejected character
+        character: char,
+    },
+    /// The string was accepted by a validator that it must not satisfy
+    Excluded,
+    /// The string was not already in the form produced by one of the
+    /// [`normalizers`](crate::normalizers)
+    NotNormalized,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("string cannot be empty"),
+            Self::TooShort { min, actual } => write!(
+                f,
+                "string must have at least {min} characters, but has {actual}"
+            ),
+            Self::TooLong { max, actual } => write!(
+                f,
+                "string must have at most {max} characters, but has {actual}"
+            ),
+            Self::NonAscii { position } => {
+                write!(f, "non-ASCII character at position {position}")
+            }
+            Self::InvalidCharacter {
+                position,
+                character,
+            } => write!(f, "invalid character {character:?} at position {position}"),
+            Self::Excluded => f.write_str("string matches an excluded form"),
+            Self::NotNormalized => f.write_str("string is not in normalized form"),
+        }
+    }
+}
+
+impl core::error::Error for ValidationError {}
+
+impl From<Infallible> for ValidationError {
+    #[inline(always)]
+    fn from(x: Infallible) -> Self {
+        match x {}
+    }
+}
+
+/// Accepts any string that is not empty
+#[derive(Debug)]
+pub struct NonEmpty;
+
+impl Validator for NonEmpty {
+    type Error = ValidationError;
+
+    #[inline]
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.is_empty() {
+            Err(ValidationError::Empty)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Accepts strings with at least `N` characters
+#[derive(Debug)]
+pub struct MinLen<const N: usize>;
+
+impl<const N: usize> Validator for MinLen<N> {
+    type Error = ValidationError;
+
+    #[inline]
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        match raw.chars().count() {
+            actual if actual < N => Err(ValidationError::TooShort { min: N, actual }),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Accepts strings with at most `N` characters
+#[derive(Debug)]
+pub struct MaxLen<const N: usize>;
+
+impl<const N: usize> Validator for MaxLen<N> {
+    type Error = ValidationError;
+
+    #[inline]
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        // Every character takes at least one byte, so only count them when it could matter
+        if raw.len() <= N {
+            return Ok(());
+        }
+        match raw.chars().count() {
+            actual if actual > N => Err(ValidationError::TooLong { max: N, actual }),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Accepts strings made up entirely of ASCII characters
+#[derive(Debug)]
+pub struct Ascii;
+
+impl Validator for Ascii {
+    type Error = ValidationError;
+
+    #[inline]
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        match raw.bytes().position(|b| !b.is_ascii()) {
+            Some(position) => Err(ValidationError::NonAscii { position }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Accepts strings whose characters all belong to the class `C`
+///
+/// Classes can be combined by listing them in a tuple, which matches a character
+/// belonging to any of them, such as `CharSet<(AsciiAlphanumeric, Char<'_'>)>`.
+#[derive(Debug)]
+pub struct CharSet<C>(PhantomData<fn() -> C>);
+
+impl<C: CharClass> Validator for CharSet<C> {
+    type Error = ValidationError;
+
+    #[inline]
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        match raw.char_indices().find(|&(_, c)| !C::contains(c)) {
+            Some((position, character)) => Err(ValidationError::InvalidCharacter {
+                position,
+                character,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Accepts strings that are accepted by both `A` and `B`
+///
+/// `A` is checked first, so its error is returned if both reject the string.
+#[derive(Debug)]
+pub struct And<A, B>(PhantomData<fn() -> (A, B)>);
+
+impl<A, B> Validator for And<A, B>
+where
+    A: Validator<Error = ValidationError>,
+    B: Validator<Error = ValidationError>,
+{
+    type Error = ValidationError;
+
+    #[inline]
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        A::validate(raw)?;
+        B::validate(raw)
+    }
+}
+
+/// Accepts strings that are accepted by either `A` or `B`
+///
+/// If neither accepts the string, the error from `B` is returned.
+#[derive(Debug)]
+pub struct Or<A, B>(PhantomData<fn() -> (A, B)>);
+
+impl<A, B> Validator for Or<A, B>
+where
+    A: Validator<Error = ValidationError>,
+    B: Validator<Error = ValidationError>,
+{
+    type Error = ValidationError;
+
+    #[inline]
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        A::validate(raw).or_else(|_| B::validate(raw))
+    }
+}
+
+/// Accepts strings that are rejected by `A`
+#[derive(Debug)]
+pub struct Not<A>(PhantomData<fn() -> A>);
+
+impl<A: Validator> Validator for Not<A> {
+    type Error = ValidationError;
+
+    #[inline]
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        match A::validate(raw) {
+            Ok(()) => Err(ValidationError::Excluded),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+/// A class of characters accepted by [`CharSet`]
+pub trait CharClass {
+    /// Whether the character belongs to the class
+    fn contains(c: char) -> bool;
+}
+
+macro_rules! ascii_classes {
+    ($($(#[$attr:meta])* $name:ident => $method:ident,)*) => {
+        $(
+            $(#[$attr])*
+            #[derive(Debug)]
+            pub struct $name;
+
+            impl CharClass for $name {
+                #[inline]
+                fn contains(c: char) -> bool {
+                    c.$method()
+                }
+            }
+        )*
+    };
+}
+
+ascii_classes! {
+    /// ASCII letters and digits: `a-z`, `A-Z`, and `0-9`
+    AsciiAlphanumeric => is_ascii_alphanumeric,
+    /// ASCII letters: `a-z` and `A-Z`
+    AsciiAlphabetic => is_ascii_alphabetic,
+    /// ASCII lowercase letters: `a-z`
+    AsciiLowercase => is_ascii_lowercase,
+    /// ASCII uppercase letters: `A-Z`
+    AsciiUppercase => is_ascii_uppercase,
+    /// ASCII digits: `0-9`
+    AsciiDigit => is_ascii_digit,
+    /// ASCII hexadecimal digits: `0-9`, `a-f`, and `A-F`
+    AsciiHexDigit => is_ascii_hexdigit,
+}
+
+/// The single character `C`
+#[derive(Debug)]
+pub struct Char<const C: char>;
+
+impl<const C: char> CharClass for Char<C> {
+    #[inline]
+    fn contains(c: char) -> bool {
+        c == C
+    }
+}
+
+/// The characters from `START` to `END`, inclusive
+#[derive(Debug)]
+pub struct CharRange<const START: char, const END: char>;
+
+impl<const START: char, const END: char> CharClass for CharRange<START, END> {
+    #[inline]
+    fn contains(c: char) -> bool {
+        (START..=END).contains(&c)
+    }
+}
+
+macro_rules! tuple_classes {
+    ($($name:ident)+) => {
+        impl<$($name: CharClass),+> CharClass for ($($name,)+) {
+            #[inline]
+            fn contains(c: char) -> bool {
+                $($name::contains(c))||+
+            }
+        }
+    };
+}
+
+tuple_classes!(A);
+tuple_classes!(A B);
+tuple_classes!(A B C);
+tuple_classes!(A B C D);
+tuple_classes!(A B C D E);
+tuple_classes!(A B C D E F);
+tuple_classes!(A B C D E F G);
+tuple_classes!(A B C D E F G H);
+
+/// Lets validators follow a normalizer in a [`Then`](crate::normalizers::Then), by accepting
+/// valid values as they are.
+macro_rules! validating_normalizers {
+    ($($ty:ty => [$($generics:tt)*],)*) => {
+        $(
+            #[cfg(feature = "alloc")]
+            impl<$($generics)*> crate::Normalizer for $ty
+            where
+                Self: Validator,
+            {
+                #[inline]
+                fn normalize(raw: &str) -> Result<alloc::borrow::Cow<'_, str>, Self::Error> {
+                    Self::validate(raw)?;
+                    Ok(alloc::borrow::Cow::Borrowed(raw))
+                }
+            }
+        )*
+    };
+}
+
+validating_normalizers! {
+    NonEmpty => [],
+    MinLen<N> => [const N: usize],
+    MaxLen<N> => [const N: usize],
+    Ascii => [],
+    CharSet<C> => [C],
+    And<A, B> => [A, B],
+    Or<A, B> => [A, B],
+    Not<A> => [A],
+}
@@ -0,0 +1,76 @@
+use arbitrary::{Arbitrary, Error, Unstructured};
+use strid::braid;
+
+#[path = "common/validators.rs"]
+mod validators;
+
+/// Any string at all
+#[braid(arbitrary)]
+pub struct Plain;
+
+/// A word of 3 to 16 lowercase letters
+#[braid(validator = "validators::Login", arbitrary)]
+pub struct Word;
+
+/// A non-empty string without ASCII uppercase letters
+#[braid(normalizer = "validators::Lowercase", arbitrary)]
+pub struct Lower;
+
+#[derive(Arbitrary, Debug)]
+#[allow(dead_code)]
+struct Input<'a> {
+    word: &'a WordRef,
+    plain: Plain,
+}
+
+fn take_rest<'a, T: Arbitrary<'a>>(bytes: &'a [u8]) -> arbitrary::Result<T> {
+    T::arbitrary_take_rest(Unstructured::new(bytes))
+}
+
+#[test]
+fn infallible() {
+    let owned: Plain = take_rest(b"Any Thing").unwrap();
+    assert_eq!(owned.as_str(), "Any Thing");
+    let borrowed: &PlainRef = take_rest(b"Any Thing").unwrap();
+    assert_eq!(borrowed.as_str(), "Any Thing");
+}
+
+#[test]
+fn validated() {
+    let owned: Word = take_rest(b"word").unwrap();
+    assert_eq!(owned.as_str(), "word");
+    let borrowed: &WordRef = take_rest(b"word").unwrap();
+    assert_eq!(borrowed.as_str(), "word");
+
+    assert!(matches!(
+        take_rest::<Word>(b"Word"),
+        Err(Error::IncorrectFormat)
+    ));
+    assert!(matches!(
+        take_rest::<&WordRef>(b""),
+        Err(Error::IncorrectFormat)
+    ));
+}
+
+#[test]
+fn normalized() {
+    let owned: Lower = take_rest(b"MiXeD").unwrap();
+    assert_eq!(owned.as_str(), "mixed");
+    let borrowed: &LowerRef = take_rest(b"lower").unwrap();
+    assert_eq!(borrowed.as_str(), "lower");
+
+    assert!(matches!(
+        take_rest::<&LowerRef>(b"MiXeD"),
+        Err(Error::IncorrectFormat)
+    ));
+}
+
+#[test]
+fn derived() {
+    let mut u = Unstructured::new(&[0xff; 64]);
+    // Every byte is invalid UTF-8, so no word can be read
+    assert!(matches!(
+        Input::arbitrary(&mut u),
+        Err(Error::IncorrectFormat)
+    ));
+}
@@ -0,0 +1,57 @@
+use std::collections::BTreeSet;
+
+use static_assertions::{assert_impl_all, assert_not_impl_any};
+use strid::braid;
+
+#[braid(owned_attr(derive(Default)))]
+pub struct OwnedDerive;
+
+assert_impl_all!(OwnedDerive: Default);
+
+#[braid(ord = "owned", ref_attr(derive(PartialOrd, Ord)))]
+pub struct RefDerive;
+
+assert_impl_all!(RefDeriveRef: PartialOrd, Ord);
+
+#[braid(
+    owned_attr(cfg_attr(all(), derive(Default)), cfg_attr(any(), derive(Copy))),
+    ref_attr(cfg_attr(all(), must_use = "references should be used"))
+)]
+pub struct CfgAttr;
+
+assert_impl_all!(CfgAttr: Default);
+assert_not_impl_any!(CfgAttr: Copy);
+
+#[braid(
+    owned_attr(must_use = "database name should always be used", derive(Default)),
+    ref_attr(must_use = "created a reference, but never used it"),
+    ref_attr(doc(alias = "DbNameRef"))
+)]
+pub struct DatabaseName;
+
+#[test]
+fn owned_attr_derive_applies_to_owned() {
+    assert_eq!("", OwnedDerive::default().as_str());
+}
+
+#[test]
+fn ref_attr_derive_applies_to_ref() {
+    let set: BTreeSet<&RefDeriveRef> = ["b", "a", "c"]
+        .into_iter()
+        .map(RefDeriveRef::from_str)
+        .collect();
+    let sorted: Vec<&str> = set.into_iter().map(RefDeriveRef::as_str).collect();
+    assert_eq!(vec!["a", "b", "c"], sorted);
+}
+
+#[test]
+fn cfg_attr_is_forwarded() {
+    assert_eq!("", CfgAttr::default().as_str());
+}
+
+#[test]
+fn key_value_attrs_are_forwarded() {
+    let owned = DatabaseName::default();
+    let borrowed: &DatabaseNameRef = &owned;
+    assert_eq!(owned, borrowed);
+}
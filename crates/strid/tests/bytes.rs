@@ -0,0 +1,258 @@
+use std::{
+    borrow::{Borrow, Cow},
+    collections::HashSet,
+    rc::Rc,
+    sync::Arc,
+};
+
+use bytes::Bytes;
+use static_assertions::{assert_impl_all, assert_not_impl_any};
+use strid::{ByteNormalizer, ByteValidator, braid_bytes, braid_ref, facet::Facet};
+
+/// A message key
+#[braid_bytes(serde)]
+pub struct Key;
+
+/// An HTTP header name as it appears on the wire
+#[braid_bytes(case = "ascii_insensitive")]
+pub struct RawHeaderName;
+
+/// A binary token of at most 16 bytes
+#[braid_bytes(serde, validator)]
+pub struct Token;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum InvalidBytes {
+    Empty,
+    TooLong,
+    NotLowercase,
+}
+
+impl std::fmt::Display for InvalidBytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "empty value",
+            Self::TooLong => "value is longer than 16 bytes",
+            Self::NotLowercase => "value contains uppercase bytes",
+        })
+    }
+}
+
+impl ByteValidator for Token {
+    type Error = InvalidBytes;
+
+    fn validate(raw: &[u8]) -> Result<(), Self::Error> {
+        if raw.len() > 16 {
+            Err(InvalidBytes::TooLong)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// A non-empty header value with ASCII letters lowercased
+#[braid_bytes(serde, normalizer, ref_name = "LowerValueRef")]
+pub struct LowerValue;
+
+impl ByteValidator for LowerValue {
+    type Error = InvalidBytes;
+
+    fn validate(raw: &[u8]) -> Result<(), Self::Error> {
+        if raw.is_empty() {
+            Err(InvalidBytes::Empty)
+        } else if raw.iter().any(u8::is_ascii_uppercase) {
+            Err(InvalidBytes::NotLowercase)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl ByteNormalizer for LowerValue {
+    fn normalize(raw: &[u8]) -> Result<Cow<'_, [u8]>, Self::Error> {
+        if raw.is_empty() {
+            Err(InvalidBytes::Empty)
+        } else if raw.iter().any(u8::is_ascii_uppercase) {
+            Ok(Cow::Owned(raw.to_ascii_lowercase()))
+        } else {
+            Ok(Cow::Borrowed(raw))
+        }
+    }
+}
+
+/// A payload shared without copying
+#[braid_bytes(serde)]
+pub struct Payload(Bytes);
+
+/// A signature with only a borrowed form
+#[braid_ref(serde, no_std)]
+pub struct Signature([u8]);
+
+/// A key scoped to a topic
+#[braid_bytes]
+pub struct TopicKey<T>;
+
+pub struct Orders;
+
+assert_impl_all!(Key: Borrow<[u8]>, Borrow<KeyRef>, AsRef<[u8]>, From<Vec<u8>>, Ord, Clone);
+assert_impl_all!(KeyRef: Borrow<[u8]>, AsRef<[u8]>, Ord);
+assert_impl_all!(Token: Borrow<[u8]>, TryFrom<Vec<u8>>);
+assert_not_impl_any!(Token: From<Vec<u8>>);
+assert_not_impl_any!(LowerValue: Borrow<[u8]>);
+assert_not_impl_any!(RawHeaderName: Borrow<[u8]>);
+assert_not_impl_any!(LowerValueRef: Borrow<[u8]>);
+assert_impl_all!(Key: Facet<'static>);
+assert_impl_all!(KeyRef: Facet<'static>);
+assert_impl_all!(Token: Facet<'static>);
+assert_impl_all!(Payload: Facet<'static>);
+assert_impl_all!(Signature: AsRef<[u8]>, Ord);
+assert_not_impl_any!(Signature: ToOwned);
+
+#[test]
+fn round_trips_between_forms() {
+    let owned = Key::new(vec![0, 159, 146, 150]);
+    let borrowed = KeyRef::from_bytes(&[0, 159, 146, 150]);
+
+    assert_eq!(owned, borrowed);
+    assert_eq!(borrowed, owned);
+    assert_eq!(owned.as_bytes(), &[0, 159, 146, 150]);
+    assert_eq!(borrowed.to_owned(), owned);
+
+    let boxed: Box<KeyRef> = owned.clone().into_boxed_ref();
+    assert_eq!(boxed.as_bytes(), borrowed.as_bytes());
+    assert_eq!(boxed.into_owned(), owned);
+
+    let cow: Cow<KeyRef> = Cow::Borrowed(borrowed);
+    assert_eq!(Key::from(cow), owned);
+
+    let rc: Rc<KeyRef> = borrowed.into();
+    let arc: Arc<KeyRef> = borrowed.into();
+    assert_eq!(&*rc, borrowed);
+    assert_eq!(&*arc, borrowed);
+
+    assert_eq!(Vec::<u8>::from(owned.clone()), vec![0, 159, 146, 150]);
+    assert_eq!(owned.take(), vec![0, 159, 146, 150]);
+}
+
+#[test]
+fn looks_up_by_bytes() {
+    let keys: HashSet<Key> = [Key::from_static(b"a"), Key::from_static(b"b")].into();
+    assert!(keys.contains(&b"a"[..]));
+    assert!(keys.contains(KeyRef::from_static(b"b")));
+    assert!(!keys.contains(&b"c"[..]));
+}
+
+#[test]
+fn debug_looks_like_a_byte_string() {
+    let key = Key::from(&b"id\x00\"\xff"[..]);
+    assert_eq!(format!("{key:?}"), r#"b"id\x00\"\xff""#);
+    assert_eq!(format!("{:?}", &*key), r#"b"id\x00\"\xff""#);
+}
+
+#[test]
+fn orders_by_bytes() {
+    let mut keys = vec![
+        Key::from_static(b"b"),
+        Key::from_static(b"\xff"),
+        Key::from_static(b"a"),
+    ];
+    keys.sort();
+    assert_eq!(keys, [&b"a"[..], b"b", b"\xff"].map(Key::from_static));
+}
+
+#[test]
+fn validates() {
+    assert_eq!(Token::new(vec![0; 17]).unwrap_err(), InvalidBytes::TooLong);
+    assert_eq!(
+        TokenRef::from_bytes(&[0; 17]).unwrap_err(),
+        InvalidBytes::TooLong
+    );
+    assert!(Token::try_from(&[0; 16][..]).is_ok());
+    assert!(<&TokenRef>::try_from(&[0; 4][..]).is_ok());
+
+    let unchecked = unsafe { TokenRef::from_bytes_unchecked(&[0; 17]) };
+    assert_eq!(unchecked.as_bytes().len(), 17);
+}
+
+#[test]
+#[should_panic(expected = "invalid TokenRef")]
+fn from_static_panics_when_invalid() {
+    TokenRef::from_static(&[0; 17]);
+}
+
+#[test]
+fn normalizes() {
+    let owned = LowerValue::new(b"Text/HTML".to_vec()).unwrap();
+    assert_eq!(owned.as_bytes(), b"text/html");
+
+    assert!(matches!(
+        LowerValueRef::from_bytes(b"text/html").unwrap(),
+        Cow::Borrowed(_)
+    ));
+    assert_eq!(
+        LowerValueRef::from_bytes(b"TEXT").unwrap().as_bytes(),
+        b"text"
+    );
+    assert!(LowerValueRef::from_normalized_bytes(b"TEXT").is_err());
+    assert_eq!(
+        LowerValue::try_from(&b"Gzip"[..]).unwrap().as_bytes(),
+        b"gzip"
+    );
+    assert_eq!(
+        LowerValue::new(Vec::new()).unwrap_err(),
+        InvalidBytes::Empty
+    );
+}
+
+#[test]
+fn wraps_other_buffers() {
+    let payload = Payload::new(Bytes::from_static(b"\x01\x02"));
+    assert_eq!(payload.as_bytes(), b"\x01\x02");
+    assert_eq!(payload.clone().into_boxed_ref().into_owned(), payload);
+    assert_eq!(PayloadRef::from_bytes(b"\x01\x02").to_owned(), payload);
+    assert_eq!(Vec::<u8>::from(payload), vec![1, 2]);
+}
+
+#[test]
+fn generic_markers() {
+    let key = TopicKey::<Orders>::from_static(b"order-1");
+    let borrowed: &TopicKeyRef<Orders> = &key;
+    assert_eq!(key, borrowed);
+    assert_eq!(borrowed.to_owned(), key);
+}
+
+#[test]
+fn serializes_as_bytes() {
+    let key = Key::from_static(b"\x00ab");
+    let json = serde_json::to_string(&key).unwrap();
+    assert_eq!(json, "[0,97,98]");
+    assert_eq!(serde_json::from_str::<Key>(&json).unwrap(), key);
+    assert_eq!(serde_json::from_str::<Key>(r#""\u0000ab""#).unwrap(), key);
+
+    let payload: Payload = serde_json::from_str("[1,2]").unwrap();
+    assert_eq!(payload.as_bytes(), b"\x01\x02");
+
+    let token: Result<Token, _> = serde_json::from_str(&format!("{:?}", [0; 17]));
+    assert!(token.is_err());
+
+    let value: LowerValue = serde_json::from_str(r#""GZIP""#).unwrap();
+    assert_eq!(value.as_bytes(), b"gzip");
+}
+
+#[test]
+fn ascii_case_insensitive() {
+    let names: HashSet<RawHeaderName> = [b"Host".as_slice(), b"HOST", b"accept"]
+        .into_iter()
+        .map(RawHeaderName::from)
+        .collect();
+    assert_eq!(names.len(), 2);
+    assert!(names.contains(RawHeaderNameRef::from_bytes(b"host")));
+    assert!(RawHeaderNameRef::from_bytes(b"a") < RawHeaderNameRef::from_bytes(b"B"));
+}
+
+#[test]
+fn borrows_bytes_without_an_owned_form() {
+    let signature = Signature::from_bytes(b"\x01\x02");
+    assert_eq!(signature.as_bytes(), b"\x01\x02");
+    assert_eq!(format!("{signature:?}"), r#"b"\x01\x02""#);
+}
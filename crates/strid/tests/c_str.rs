@@ -0,0 +1,305 @@
+use std::{
+    borrow::{Borrow, Cow},
+    collections::BTreeSet,
+    ffi::{CStr, CString, NulError},
+    rc::Rc,
+    sync::Arc,
+};
+
+use static_assertions::{assert_impl_all, assert_not_impl_any};
+use strid::{CStrNormalizer, CStrValidator, braid, braid_ref};
+
+/// The name of a symbol exported to C
+#[braid(serde)]
+pub struct Symbol(CString);
+
+/// The name of an environment variable, which can't contain `=`
+#[braid(serde, validator)]
+pub struct EnvName(CString);
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum InvalidCStr {
+    Empty,
+    InteriorNul,
+    Equals,
+    NotUppercase,
+}
+
+impl std::fmt::Display for InvalidCStr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "empty value",
+            Self::InteriorNul => "value contains a nul byte",
+            Self::Equals => "value contains `=`",
+            Self::NotUppercase => "value contains lowercase letters",
+        })
+    }
+}
+
+impl From<NulError> for InvalidCStr {
+    fn from(_: NulError) -> Self {
+        Self::InteriorNul
+    }
+}
+
+impl CStrValidator for EnvName {
+    type Error = InvalidCStr;
+
+    fn validate(raw: &CStr) -> Result<(), Self::Error> {
+        if raw.is_empty() {
+            Err(InvalidCStr::Empty)
+        } else if raw.to_bytes().contains(&b'=') {
+            Err(InvalidCStr::Equals)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// A constant name with ASCII letters uppercased
+#[braid(serde, normalizer, ref_name = "UpperNameRef")]
+pub struct UpperName(CString);
+
+impl CStrValidator for UpperName {
+    type Error = InvalidCStr;
+
+    fn validate(raw: &CStr) -> Result<(), Self::Error> {
+        if raw.is_empty() {
+            Err(InvalidCStr::Empty)
+        } else if raw.to_bytes().iter().any(u8::is_ascii_lowercase) {
+            Err(InvalidCStr::NotUppercase)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl CStrNormalizer for UpperName {
+    fn normalize(raw: &CStr) -> Result<Cow<'_, CStr>, Self::Error> {
+        if raw.is_empty() {
+            Err(InvalidCStr::Empty)
+        } else if raw.to_bytes().iter().any(u8::is_ascii_lowercase) {
+            let upper = CString::new(raw.to_bytes().to_ascii_uppercase())?;
+            Ok(Cow::Owned(upper))
+        } else {
+            Ok(Cow::Borrowed(raw))
+        }
+    }
+}
+
+/// A symbol scoped to a library
+#[braid]
+pub struct LibSymbol<T>(CString);
+
+/// The name of a network device
+#[braid_ref(validator, no_std)]
+pub struct DeviceName(CStr);
+
+impl CStrValidator for DeviceName {
+    type Error = InvalidCStr;
+
+    fn validate(raw: &CStr) -> Result<(), Self::Error> {
+        if raw.is_empty() {
+            Err(InvalidCStr::Empty)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// A label with only a borrowed form
+#[braid_ref(serde)]
+pub struct Label(CStr);
+
+pub struct Libc;
+
+assert_impl_all!(Symbol: Borrow<CStr>, Borrow<SymbolRef>, AsRef<CStr>, From<CString>, TryFrom<String>, TryFrom<&'static str>, TryFrom<Vec<u8>>, Ord, Clone);
+assert_impl_all!(SymbolRef: Borrow<CStr>, AsRef<CStr>, ToOwned, Ord);
+assert_impl_all!(EnvName: Borrow<CStr>, TryFrom<CString>, TryFrom<&'static [u8]>);
+assert_not_impl_any!(EnvName: From<CString>);
+assert_not_impl_any!(UpperName: Borrow<CStr>);
+assert_not_impl_any!(DeviceName: ToOwned);
+assert_impl_all!(Label: AsRef<CStr>, Ord);
+
+#[test]
+fn round_trips_between_forms() {
+    let owned = Symbol::new(c"main".to_owned());
+    let borrowed = SymbolRef::from_c_str(c"main");
+
+    assert_eq!(owned, borrowed);
+    assert_eq!(borrowed, owned);
+    assert_eq!(owned.as_c_str(), c"main");
+    assert_eq!(borrowed.to_owned(), owned);
+
+    let boxed: Box<SymbolRef> = owned.clone().into_boxed_ref();
+    assert_eq!(boxed.as_c_str(), borrowed.as_c_str());
+    assert_eq!(boxed.into_owned(), owned);
+
+    let cow: Cow<SymbolRef> = Cow::Borrowed(borrowed);
+    assert_eq!(Symbol::from(cow), owned);
+
+    let rc: Rc<SymbolRef> = borrowed.into();
+    let arc: Arc<SymbolRef> = borrowed.into();
+    assert_eq!(&*rc, borrowed);
+    assert_eq!(&*arc, borrowed);
+
+    assert_eq!(CString::from(owned.clone()), c"main".to_owned());
+    assert_eq!(owned.take(), c"main".to_owned());
+}
+
+#[test]
+fn checks_for_interior_nul_bytes() {
+    assert_eq!(
+        Symbol::try_from("main").unwrap(),
+        SymbolRef::from_static(c"main")
+    );
+    assert_eq!(
+        Symbol::try_from(String::from("main")).unwrap().as_c_str(),
+        c"main"
+    );
+    assert_eq!(
+        Symbol::try_from(b"main".to_vec()).unwrap().as_c_str(),
+        c"main"
+    );
+    assert_eq!(Symbol::try_from("ma\0in").unwrap_err().nul_position(), 2);
+    assert!(Symbol::try_from(&b"main\0"[..]).is_err());
+
+    assert_eq!(EnvName::try_from("HOME").unwrap().as_c_str(), c"HOME");
+    assert_eq!(
+        EnvName::try_from("HO\0ME").unwrap_err(),
+        InvalidCStr::InteriorNul
+    );
+    assert_eq!(EnvName::try_from("HO=ME").unwrap_err(), InvalidCStr::Equals);
+    assert_eq!(UpperName::try_from("max").unwrap().as_c_str(), c"MAX");
+}
+
+#[test]
+fn passes_through_pointers() {
+    let owned = Symbol::from_static(c"main");
+    let ptr = owned.as_ptr();
+    assert_eq!(ptr, owned.as_c_str().as_ptr());
+
+    let borrowed = unsafe { SymbolRef::from_ptr(ptr) };
+    assert_eq!(borrowed, owned);
+
+    let name = EnvName::from_static(c"PATH");
+    assert_eq!(
+        unsafe { EnvNameRef::from_ptr(name.as_ptr()) }.unwrap(),
+        name
+    );
+    assert_eq!(
+        unsafe { EnvNameRef::from_ptr(c"A=B".as_ptr()) }.unwrap_err(),
+        InvalidCStr::Equals
+    );
+    let unchecked = unsafe { EnvNameRef::from_ptr_unchecked(c"A=B".as_ptr()) };
+    assert_eq!(unchecked.as_c_str(), c"A=B");
+
+    assert!(matches!(
+        unsafe { UpperNameRef::from_ptr(c"max".as_ptr()) }.unwrap(),
+        Cow::Owned(_)
+    ));
+    assert!(matches!(
+        unsafe { UpperNameRef::from_ptr(c"MAX".as_ptr()) }.unwrap(),
+        Cow::Borrowed(_)
+    ));
+}
+
+#[test]
+fn looks_up_by_c_str() {
+    let symbols: BTreeSet<Symbol> = [c"b", c"a"].map(Symbol::from_static).into();
+    assert!(symbols.contains(c"a"));
+    assert!(symbols.contains(SymbolRef::from_static(c"b")));
+    assert!(!symbols.contains(c"c"));
+    assert_eq!(symbols.first().unwrap().as_c_str(), c"a");
+}
+
+#[test]
+fn debug_looks_like_a_c_str() {
+    let symbol = Symbol::from_static(c"main");
+    assert_eq!(format!("{symbol:?}"), format!("{:?}", c"main"));
+    assert_eq!(
+        format!("{:?}", DeviceName::from_c_str(c"eth0").unwrap()),
+        r#""eth0""#
+    );
+}
+
+#[test]
+fn validates() {
+    assert_eq!(
+        EnvName::new(c"".to_owned()).unwrap_err(),
+        InvalidCStr::Empty
+    );
+    assert_eq!(
+        EnvNameRef::from_c_str(c"A=B").unwrap_err(),
+        InvalidCStr::Equals
+    );
+    assert!(EnvName::try_from(c"HOME").is_ok());
+    assert!(<&EnvNameRef>::try_from(c"HOME").is_ok());
+}
+
+#[test]
+#[should_panic(expected = "invalid EnvNameRef")]
+fn from_static_panics_when_invalid() {
+    EnvNameRef::from_static(c"A=B");
+}
+
+#[test]
+fn normalizes() {
+    let owned = UpperName::new(c"max_len".to_owned()).unwrap();
+    assert_eq!(owned.as_c_str(), c"MAX_LEN");
+
+    assert!(matches!(
+        UpperNameRef::from_c_str(c"MAX").unwrap(),
+        Cow::Borrowed(_)
+    ));
+    assert!(UpperNameRef::from_normalized_c_str(c"max").is_err());
+    assert_eq!(
+        UpperName::new(c"".to_owned()).unwrap_err(),
+        InvalidCStr::Empty
+    );
+}
+
+#[test]
+fn generic_markers() {
+    let symbol = LibSymbol::<Libc>::from_static(c"malloc");
+    let borrowed: &LibSymbolRef<Libc> = &symbol;
+    assert_eq!(symbol, borrowed);
+    assert_eq!(
+        unsafe { LibSymbolRef::<Libc>::from_ptr(symbol.as_ptr()) },
+        borrowed
+    );
+}
+
+#[test]
+fn borrows_without_an_owned_type() {
+    let device = DeviceName::from_c_str(c"eth0").unwrap();
+    assert_eq!(device.as_c_str(), c"eth0");
+    assert_eq!(DeviceName::from_c_str(c"").unwrap_err(), InvalidCStr::Empty);
+    assert_eq!(
+        unsafe { DeviceName::from_ptr(device.as_ptr()) }.unwrap(),
+        device
+    );
+    assert!(<&DeviceName>::try_from(c"lo").is_ok());
+
+    let label = Label::from_static(c"x");
+    assert_eq!(AsRef::<CStr>::as_ref(label), c"x");
+    assert!(Label::from_static(c"a") < label);
+}
+
+#[test]
+fn serializes_as_bytes() {
+    let symbol = Symbol::from_static(c"ab");
+    let json = serde_json::to_string(&symbol).unwrap();
+    assert_eq!(json, "[97,98]");
+    assert_eq!(serde_json::from_str::<Symbol>(&json).unwrap(), symbol);
+    assert_eq!(
+        serde_json::to_string(Label::from_static(c"ab")).unwrap(),
+        json
+    );
+
+    assert!(serde_json::from_str::<Symbol>("[97,0,98]").is_err());
+    assert!(serde_json::from_str::<EnvName>("[61]").is_err());
+
+    let name: UpperName = serde_json::from_str("[97]").unwrap();
+    assert_eq!(name.as_c_str(), c"A");
+}
@@ -0,0 +1,130 @@
+use std::{
+    borrow::{Borrow, Cow},
+    collections::{BTreeSet, HashSet},
+    hash::{BuildHasher, BuildHasherDefault, DefaultHasher, Hash},
+};
+
+use static_assertions::{assert_impl_all, assert_not_impl_any};
+use strid::{braid, braid_ref};
+
+/// An HTTP header name
+#[braid(case = "ascii_insensitive")]
+pub struct HeaderName;
+
+/// A username compared with Unicode case folding
+#[braid(case = "insensitive", validator = "strid::validators::NonEmpty")]
+pub struct Username;
+
+/// A DNS label
+#[braid_ref(case = "ascii_insensitive")]
+pub struct Label(str);
+
+/// A header name scoped to a protocol
+#[braid(case = "ascii_insensitive")]
+pub struct ScopedName<P>;
+
+pub struct Http;
+
+/// A case-sensitive name, for comparison
+#[braid]
+pub struct Plain;
+
+assert_not_impl_any!(HeaderName: Borrow<str>);
+assert_not_impl_any!(HeaderNameRef: Borrow<str>);
+assert_not_impl_any!(Username: Borrow<str>);
+assert_not_impl_any!(Label: Borrow<str>);
+assert_impl_all!(HeaderName: Borrow<HeaderNameRef>);
+assert_impl_all!(Plain: Borrow<str>);
+
+fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+    BuildHasherDefault::<DefaultHasher>::default().hash_one(value)
+}
+
+#[test]
+fn keeps_original_spelling() {
+    let name = HeaderName::from_static("Content-Type");
+    assert_eq!(name.as_str(), "Content-Type");
+    assert_eq!(name.to_string(), "Content-Type");
+}
+
+#[test]
+fn ascii_equality() {
+    let owned = HeaderName::from_static("Content-Type");
+    let borrowed = HeaderNameRef::from_static("CONTENT-TYPE");
+
+    assert_eq!(owned, HeaderName::from_static("content-type"));
+    assert_eq!(owned, *borrowed);
+    assert_eq!(*borrowed, owned);
+    assert_eq!(owned, borrowed);
+    assert_eq!(borrowed, owned);
+    assert_ne!(owned, HeaderName::from_static("Content-Length"));
+
+    let boxed: Box<HeaderNameRef> = owned.clone().into_boxed_ref();
+    assert_eq!(boxed, Box::from(HeaderName::from_static("content-type")));
+
+    let cow: Cow<HeaderNameRef> = Cow::Borrowed(borrowed);
+    assert_eq!(cow, Cow::<HeaderNameRef>::Owned(owned.clone()));
+}
+
+#[test]
+fn ascii_hashing_agrees_across_forms() {
+    let owned = HeaderName::from_static("Accept");
+    let borrowed = HeaderNameRef::from_static("aCCEPT");
+
+    assert_eq!(hash(&owned), hash(borrowed));
+    assert_eq!(hash(&owned), hash(&HeaderName::from_static("ACCEPT")));
+    assert_eq!(
+        hash(&Box::<HeaderNameRef>::from(owned.clone())),
+        hash(&Cow::Borrowed(borrowed))
+    );
+
+    let names: HashSet<HeaderName> = ["Accept", "ACCEPT", "accept", "Host"]
+        .into_iter()
+        .map(HeaderName::from_static)
+        .collect();
+    assert_eq!(names.len(), 2);
+    assert!(names.contains(HeaderNameRef::from_static("HOST")));
+}
+
+#[test]
+fn ascii_ordering() {
+    let names: BTreeSet<HeaderName> = ["b", "A", "C"]
+        .into_iter()
+        .map(HeaderName::from_static)
+        .collect();
+    let names: Vec<&str> = names.iter().map(|name| name.as_str()).collect();
+    assert_eq!(names, ["A", "b", "C"]);
+
+    assert!(HeaderNameRef::from_static("a") < HeaderNameRef::from_static("B"));
+    assert!(Plain::from_static("a") > Plain::from_static("B"));
+}
+
+#[test]
+fn ascii_leaves_other_letters_alone() {
+    assert_ne!(HeaderName::from_static("Ä"), HeaderName::from_static("ä"));
+}
+
+#[test]
+fn unicode_case_folding() {
+    let upper = Username::from_static("STRASSE");
+    let lower = UsernameRef::from_static("straße");
+
+    assert_eq!(upper, *lower);
+    assert_eq!(hash(&upper), hash(lower));
+    assert_eq!(
+        Username::from_static("Ä").cmp(&Username::from_static("ä")),
+        std::cmp::Ordering::Equal
+    );
+    assert_ne!(upper, Username::from_static("strase"));
+}
+
+#[test]
+fn braid_ref_and_generic() {
+    assert_eq!(Label::from_static("Example"), Label::from_static("EXAMPLE"));
+    assert_eq!(hash(Label::from_static("a")), hash(Label::from_static("A")));
+
+    assert_eq!(
+        ScopedName::<Http>::from_static("Host"),
+        ScopedName::<Http>::from_static("host")
+    );
+}
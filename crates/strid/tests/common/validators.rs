@@ -0,0 +1,81 @@
+//! Validators and normalizers shared by the integration tests, which each use only some of them
+
+#![allow(dead_code)]
+
+use std::{borrow::Cow, fmt};
+
+use strid::{Constraints, Normalizer, Validator};
+
+/// The error of every shared validator
+#[derive(Debug, PartialEq, Eq)]
+pub struct Invalid;
+
+impl fmt::Display for Invalid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid value")
+    }
+}
+
+impl std::error::Error for Invalid {}
+
+strid::from_infallible!(Invalid);
+
+/// Accepts any string that isn't empty
+pub struct NonEmpty;
+
+impl Validator for NonEmpty {
+    type Error = Invalid;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.is_empty() { Err(Invalid) } else { Ok(()) }
+    }
+}
+
+/// Accepts non-empty strings without ASCII uppercase letters, which it lowercases
+pub struct Lowercase;
+
+impl Validator for Lowercase {
+    type Error = Invalid;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.is_empty() || raw.bytes().any(|b| b.is_ascii_uppercase()) {
+            Err(Invalid)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Normalizer for Lowercase {
+    fn normalize(raw: &str) -> Result<Cow<'_, str>, Self::Error> {
+        if raw.is_empty() {
+            Err(Invalid)
+        } else if raw.bytes().any(|b| b.is_ascii_uppercase()) {
+            Ok(Cow::Owned(raw.to_ascii_lowercase()))
+        } else {
+            Ok(Cow::Borrowed(raw))
+        }
+    }
+}
+
+/// Accepts login names of 3 to 16 ASCII lowercase letters
+pub struct Login;
+
+impl Validator for Login {
+    type Error = Invalid;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if (3..=16).contains(&raw.len()) && raw.bytes().all(|b| b.is_ascii_lowercase()) {
+            Ok(())
+        } else {
+            Err(Invalid)
+        }
+    }
+}
+
+impl Constraints for Login {
+    const PATTERN: Option<&'static str> = Some("^[a-z]+$");
+    const MIN_LENGTH: Option<u64> = Some(3);
+    const MAX_LENGTH: Option<u64> = Some(16);
+    const EXAMPLES: Option<&'static [&'static str]> = Some(&["alice", "bob"]);
+}
@@ -0,0 +1,117 @@
+use std::borrow::Cow;
+
+use strid::{braid, braid_ref, lit};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct InvalidIdent;
+
+strid::from_infallible!(InvalidIdent);
+
+const fn is_ident(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    if bytes.is_empty() || bytes[0].is_ascii_digit() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_alphanumeric() && bytes[i] != b'_' {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn is_lowercase_ident(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i].is_ascii_uppercase() {
+            return false;
+        }
+        i += 1;
+    }
+    is_ident(s)
+}
+
+#[braid(validator, const_validator = "is_ident")]
+pub struct Ident;
+
+impl strid::Validator for Ident {
+    type Error = InvalidIdent;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if is_ident(raw) {
+            Ok(())
+        } else {
+            Err(InvalidIdent)
+        }
+    }
+}
+
+#[braid(normalizer, const_validator = "is_lowercase_ident")]
+pub struct LowerIdent;
+
+impl strid::Validator for LowerIdent {
+    type Error = InvalidIdent;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if is_lowercase_ident(raw) {
+            Ok(())
+        } else {
+            Err(InvalidIdent)
+        }
+    }
+}
+
+impl strid::Normalizer for LowerIdent {
+    fn normalize(raw: &str) -> Result<Cow<'_, str>, Self::Error> {
+        if !is_ident(raw) {
+            Err(InvalidIdent)
+        } else if is_lowercase_ident(raw) {
+            Ok(Cow::Borrowed(raw))
+        } else {
+            Ok(Cow::Owned(raw.to_ascii_lowercase()))
+        }
+    }
+}
+
+#[braid_ref(validator = "Ident", const_validator = "is_ident")]
+pub struct IdentSlice(str);
+
+static SELF_IDENT: &IdentRef = IdentRef::from_static("self_");
+static LOWER: &LowerIdentRef = LowerIdentRef::from_static("lower");
+const SLICE: &IdentSlice = IdentSlice::from_static("slice");
+
+#[test]
+fn static_items_hold_valid_values() {
+    assert_eq!("self_", SELF_IDENT.as_str());
+    assert_eq!("lower", LOWER.as_str());
+    assert_eq!("slice", SLICE.as_str());
+}
+
+#[test]
+fn lit_creates_borrowed_values() {
+    assert_eq!("valid_ident", lit!(IdentRef, "valid_ident").as_str());
+    assert_eq!("lower", lit!(LowerIdentRef, "lower").as_str());
+    assert_eq!("slice", lit!(IdentSlice, "slice").as_str());
+}
+
+#[test]
+fn owned_from_static_uses_the_borrowed_form() {
+    assert_eq!("valid_ident", Ident::from_static("valid_ident").as_str());
+    assert_eq!("mixed", LowerIdent::from_static("Mixed").as_str());
+}
+
+#[test]
+#[should_panic(expected = "invalid IdentRef")]
+fn from_static_panics_at_runtime_on_invalid() {
+    IdentRef::from_static(std::hint::black_box("1st"));
+}
+
+#[test]
+#[should_panic(expected = "non-normalized LowerIdentRef")]
+fn from_static_panics_at_runtime_on_non_normalized() {
+    LowerIdentRef::from_static(std::hint::black_box("Mixed"));
+}
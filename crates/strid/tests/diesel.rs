@@ -0,0 +1,66 @@
+use diesel::{prelude::*, sqlite::SqliteConnection};
+use strid::braid;
+
+#[path = "common/validators.rs"]
+mod validators;
+
+diesel::table! {
+    names (id) {
+        id -> Integer,
+        value -> Text,
+    }
+}
+
+#[braid(diesel)]
+pub struct Plain;
+
+#[braid(diesel, validator = "validators::NonEmpty")]
+pub struct Checked;
+
+fn connection() -> SqliteConnection {
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    diesel::sql_query("CREATE TABLE names (id INTEGER PRIMARY KEY, value TEXT NOT NULL)")
+        .execute(&mut conn)
+        .unwrap();
+    conn
+}
+
+#[test]
+fn round_trip() {
+    let mut conn = connection();
+    let owned = Plain::from_static("value");
+    diesel::insert_into(names::table)
+        .values(names::value.eq(&owned))
+        .execute(&mut conn)
+        .unwrap();
+    diesel::insert_into(names::table)
+        .values(names::value.eq(PlainRef::from_str("other")))
+        .execute(&mut conn)
+        .unwrap();
+
+    let values = names::table
+        .select(names::value)
+        .order(names::id)
+        .load::<Plain>(&mut conn)
+        .unwrap();
+    assert_eq!(values, [owned.clone(), Plain::from_static("other")]);
+
+    let count = names::table
+        .filter(names::value.eq(owned))
+        .count()
+        .get_result::<i64>(&mut conn)
+        .unwrap();
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn loading_validates() {
+    let mut conn = connection();
+    diesel::insert_into(names::table)
+        .values(names::value.eq(""))
+        .execute(&mut conn)
+        .unwrap();
+
+    let result = names::table.select(names::value).load::<Checked>(&mut conn);
+    assert!(result.is_err());
+}
@@ -0,0 +1,103 @@
+use std::borrow::Cow;
+
+use static_assertions::{assert_impl_all, assert_not_impl_any};
+use strid::{braid, braid_ref, facet::Facet};
+
+#[path = "common/validators.rs"]
+mod validators;
+
+#[braid]
+pub struct Plain;
+
+#[braid(validator = "validators::NonEmpty")]
+pub struct Checked;
+
+#[braid(normalizer = "validators::Lowercase")]
+pub struct Lower;
+
+#[braid(facet = "owned")]
+pub struct OwnedOnly;
+
+#[braid(facet = "omit")]
+pub struct Omitted;
+
+#[braid_ref]
+pub struct Standalone(str);
+
+#[braid_ref(facet = "omit")]
+pub struct StandaloneOmitted(str);
+
+assert_impl_all!(Plain: Facet<'static>);
+assert_impl_all!(PlainRef: Facet<'static>);
+assert_impl_all!(&'static PlainRef: Facet<'static>);
+assert_impl_all!(Box<PlainRef>: Facet<'static>);
+assert_impl_all!(Cow<'static, PlainRef>: Facet<'static>);
+assert_impl_all!(Checked: Facet<'static>);
+assert_impl_all!(CheckedRef: Facet<'static>);
+assert_impl_all!(Standalone: Facet<'static>);
+
+assert_impl_all!(OwnedOnly: Facet<'static>);
+assert_not_impl_any!(OwnedOnlyRef: Facet<'static>);
+assert_not_impl_any!(Omitted: Facet<'static>);
+assert_not_impl_any!(OmittedRef: Facet<'static>);
+assert_not_impl_any!(StandaloneOmitted: Facet<'static>);
+
+#[derive(Facet)]
+#[facet(crate = strid::facet)]
+pub struct Borrowing<'a> {
+    plain: &'a PlainRef,
+    boxed: Box<CheckedRef>,
+    cow: Cow<'a, PlainRef>,
+}
+
+#[test]
+fn borrowed_types_can_be_reflected_fields() {
+    let owner = Plain::from_static("owner");
+    let value = Borrowing {
+        plain: &owner,
+        boxed: Checked::from_static("boxed").into_boxed_ref(),
+        cow: Cow::Borrowed(PlainRef::from_static("cow")),
+    };
+    assert_eq!(value.plain.as_str(), "owner");
+    assert_eq!(value.boxed.as_str(), "boxed");
+    assert_eq!(value.cow.as_str(), "cow");
+}
+
+#[test]
+fn facet_deserialization_validates() {
+    let checked: Checked = facet_json::from_str("\"value\"").unwrap();
+    assert_eq!(checked.as_str(), "value");
+    assert_eq!(facet_json::to_string(&checked).unwrap(), "\"value\"");
+    assert!(facet_json::from_str::<Checked>("\"\"").is_err());
+
+    let checked: &CheckedRef = facet_json::from_str("\"value\"").unwrap();
+    assert_eq!(checked.as_str(), "value");
+    assert!(facet_json::from_str::<&CheckedRef>("\"\"").is_err());
+}
+
+#[test]
+fn facet_deserialization_normalizes_owned_values() {
+    let lower: Lower = facet_json::from_str("\"value\"").unwrap();
+    assert_eq!(lower.as_str(), "value");
+    assert_eq!(
+        facet_json::from_str::<Lower>("\"Value\"").unwrap().as_str(),
+        "value"
+    );
+    assert!(facet_json::from_str::<Lower>("\"\"").is_err());
+    assert!(facet_json::from_str::<&LowerRef>("\"Value\"").is_err());
+}
+
+#[test]
+fn facet_errors_describe_why_a_value_was_rejected() {
+    let error = facet_json::from_str::<Checked>("\"\"").unwrap_err();
+    assert!(error.to_string().contains("invalid value"), "{error}");
+
+    let error = facet_json::from_str::<&CheckedRef>("\"\"").unwrap_err();
+    assert!(error.to_string().contains("invalid value"), "{error}");
+}
+
+#[test]
+fn unchecked_braids_accept_anything() {
+    let plain: Plain = facet_json::from_str("\"\"").unwrap();
+    assert_eq!(plain.as_str(), "");
+}
@@ -0,0 +1,135 @@
+use std::{
+    borrow::{Borrow, Cow},
+    collections::HashSet,
+    fmt::Debug,
+    hash::Hash,
+};
+
+use static_assertions::{assert_impl_all, assert_not_impl_any};
+use strid::{braid, braid_ref};
+
+/// A marker for identifiers of users
+pub struct User;
+
+/// A marker for identifiers of organizations
+pub struct Org;
+
+/// An identifier for a particular kind of record
+#[braid(serde)]
+pub struct Id<T>;
+
+/// A key scoped to a borrowed namespace
+#[braid(ref_doc = "A borrowed key scoped to a namespace")]
+pub struct Key<'n, T: 'n>(String)
+where
+    T: Debug;
+
+/// A name with named fields and a default marker
+#[braid(validator, facet = "owned")]
+pub struct Name<T = ()> {
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct EmptyName;
+
+strid::from_infallible!(EmptyName);
+
+impl<T> strid::Validator for Name<T> {
+    type Error = EmptyName;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.is_empty() {
+            Err(EmptyName)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// A standalone borrowed label
+#[braid_ref]
+pub struct LabelRef<T>(str);
+
+// The markers themselves implement nothing, so none of these may depend on them
+assert_impl_all!(Id<User>: Clone, Debug, Hash, Eq, Ord, Send, Sync, Borrow<IdRef<User>>);
+assert_impl_all!(IdRef<User>: Debug, Hash, Eq, Ord, Send, Sync, ToOwned<Owned = Id<User>>);
+assert_impl_all!(Key<'static, u8>: Clone, Borrow<KeyRef<'static, u8>>);
+assert_impl_all!(Name: Clone, Borrow<NameRef>);
+assert_impl_all!(LabelRef<User>: Debug, Hash, Eq, Ord);
+
+// Identifiers for different kinds of records are not interchangeable
+assert_not_impl_any!(Id<User>: PartialEq<Id<Org>>, PartialEq<IdRef<Org>>);
+assert_not_impl_any!(Id<User>: Borrow<IdRef<Org>>);
+
+#[test]
+fn owned_and_borrowed_round_trip() {
+    let id = Id::<User>::from_static("u-123");
+    let borrowed: &IdRef<User> = &id;
+    assert_eq!(borrowed.as_str(), "u-123");
+    assert_eq!(borrowed.to_owned(), id);
+    assert_eq!(id, *borrowed);
+    assert_eq!(id, borrowed);
+}
+
+#[test]
+fn constant_ref_works() {
+    const ID: &IdRef<Org> = IdRef::from_static("o-1");
+    assert_eq!(ID.as_str(), "o-1");
+}
+
+#[test]
+fn works_in_hash_sets() {
+    let mut ids = HashSet::new();
+    ids.insert(Id::<User>::from("a"));
+    ids.insert(Id::<User>::from("b"));
+    assert!(ids.contains(IdRef::<User>::from_str("a")));
+    assert!(!ids.contains(IdRef::<User>::from_str("c")));
+}
+
+#[test]
+fn conversions_carry_the_marker() {
+    let id: Id<User> = "u-1".parse().unwrap();
+    let boxed: Box<IdRef<User>> = id.clone().into_boxed_ref();
+    assert_eq!(Id::from(boxed), id);
+
+    let cow: Cow<IdRef<User>> = Cow::Borrowed(IdRef::from_str("u-1"));
+    assert_eq!(Id::from(cow), id);
+
+    let s: String = id.into();
+    assert_eq!(s, "u-1");
+}
+
+#[test]
+fn serde_round_trip() {
+    let id = Id::<User>::from_static("u-42");
+    let json = serde_json::to_string(&id).unwrap();
+    assert_eq!(json, "\"u-42\"");
+
+    let owned: Id<User> = serde_json::from_str(&json).unwrap();
+    assert_eq!(owned, id);
+
+    let borrowed: &IdRef<User> = serde_json::from_str(&json).unwrap();
+    assert_eq!(borrowed, &*id);
+}
+
+#[test]
+fn lifetimes_and_bounds() {
+    let key = Key::<'static, u8>::from_static("k");
+    let borrowed: &KeyRef<u8> = &key;
+    assert_eq!(borrowed.as_str(), "k");
+}
+
+#[test]
+fn named_fields_and_defaults() {
+    let name: Name = Name::new("ferris".to_owned()).unwrap();
+    assert_eq!(name.as_str(), "ferris");
+    assert_eq!(Name::<User>::new(String::new()), Err(EmptyName));
+    assert_eq!(NameRef::<User>::from_str(""), Err(EmptyName));
+}
+
+#[test]
+fn standalone_ref() {
+    let label = LabelRef::<User>::from_str("l");
+    assert_eq!(label.as_str(), "l");
+}
@@ -0,0 +1,129 @@
+use std::borrow::Cow;
+
+use strid::{
+    Normalizer, Validator, braid,
+    normalizers::{
+        AsciiLowercase, CollapseWhitespace, Nfc, Nfd, Nfkc, Then, Trim, UnicodeCaseFold,
+    },
+    validators::{MaxLen, NonEmpty, ValidationError},
+};
+
+type UsernameRules = Then<Nfkc, Then<UnicodeCaseFold, Then<Trim, Then<NonEmpty, MaxLen<16>>>>>;
+
+/// A login name, compared without regard to case or compatibility variants
+#[braid(normalizer = "UsernameRules")]
+pub struct Username;
+
+/// A display name with tidy whitespace
+#[braid(normalizer = "Then<Trim, Then<CollapseWhitespace, NonEmpty>>")]
+pub struct DisplayName;
+
+#[test]
+fn unicode_forms() {
+    // "é" as a single code point, and as "e" followed by a combining acute accent
+    let composed = "caf\u{e9}";
+    let decomposed = "cafe\u{301}";
+
+    assert_eq!(Nfc::normalize(decomposed).unwrap(), composed);
+    assert!(matches!(Nfc::normalize(composed), Ok(Cow::Borrowed(_))));
+    assert_eq!(Nfd::normalize(composed).unwrap(), decomposed);
+    assert!(matches!(Nfd::normalize(decomposed), Ok(Cow::Borrowed(_))));
+    assert_eq!(Nfkc::normalize("\u{fb01}le").unwrap(), "file");
+
+    assert_eq!(Nfc::validate(composed), Ok(()));
+    assert_eq!(
+        Nfc::validate(decomposed),
+        Err(ValidationError::NotNormalized)
+    );
+}
+
+#[test]
+fn case() {
+    assert_eq!(AsciiLowercase::normalize("HeLLo").unwrap(), "hello");
+    assert_eq!(AsciiLowercase::normalize("ÀB").unwrap(), "Àb");
+    assert!(matches!(
+        AsciiLowercase::normalize("hello"),
+        Ok(Cow::Borrowed(_))
+    ));
+
+    assert_eq!(UnicodeCaseFold::normalize("Straße").unwrap(), "strasse");
+    assert!(matches!(
+        UnicodeCaseFold::normalize("strasse"),
+        Ok(Cow::Borrowed(_))
+    ));
+    assert_eq!(
+        UnicodeCaseFold::validate("Straße"),
+        Err(ValidationError::NotNormalized)
+    );
+}
+
+#[test]
+fn whitespace() {
+    assert_eq!(Trim::normalize("  a b \n").unwrap(), "a b");
+    assert!(matches!(
+        Trim::normalize("  a b \n"),
+        Ok(Cow::Borrowed("a b"))
+    ));
+    assert_eq!(Trim::validate(" a"), Err(ValidationError::NotNormalized));
+
+    assert_eq!(
+        CollapseWhitespace::normalize("a \t b\n\nc").unwrap(),
+        "a b c"
+    );
+    assert_eq!(CollapseWhitespace::normalize("  a  ").unwrap(), " a ");
+    assert!(matches!(
+        CollapseWhitespace::normalize("a b c"),
+        Ok(Cow::Borrowed(_))
+    ));
+    assert_eq!(
+        CollapseWhitespace::validate("a\tb"),
+        Err(ValidationError::NotNormalized)
+    );
+}
+
+#[test]
+fn then_chains_normalizers_and_validators() {
+    type Rules = Then<Trim, Then<AsciiLowercase, NonEmpty>>;
+
+    assert_eq!(Rules::normalize(" Rust ").unwrap(), "rust");
+    assert!(matches!(
+        Rules::normalize("rust"),
+        Ok(Cow::Borrowed("rust"))
+    ));
+    assert_eq!(Rules::normalize("   "), Err(ValidationError::Empty));
+
+    assert_eq!(Rules::validate("rust"), Ok(()));
+    assert_eq!(Rules::validate("Rust"), Err(ValidationError::NotNormalized));
+    assert_eq!(Rules::validate(""), Err(ValidationError::Empty));
+}
+
+#[test]
+fn username() {
+    assert_eq!(
+        Username::new(" ＪｏｈｎＳｍｉｔｈ ".to_owned())
+            .unwrap()
+            .as_str(),
+        "johnsmith"
+    );
+    assert_eq!(UsernameRef::from_str("Straße").unwrap().as_str(), "strasse");
+    assert!(matches!(
+        UsernameRef::from_str("johnsmith"),
+        Ok(Cow::Borrowed(_))
+    ));
+    assert!(UsernameRef::from_str("  ").is_err());
+    assert!(UsernameRef::from_str("a-name-that-is-far-too-long").is_err());
+
+    assert!(UsernameRef::from_normalized_str("johnsmith").is_ok());
+    assert!(UsernameRef::from_normalized_str("JohnSmith").is_err());
+}
+
+#[test]
+fn display_name() {
+    assert_eq!(
+        DisplayName::new("  Ada \t Lovelace ".to_owned())
+            .unwrap()
+            .as_str(),
+        "Ada Lovelace"
+    );
+    assert!(DisplayNameRef::from_str(" \n ").is_err());
+}
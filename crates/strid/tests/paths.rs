@@ -0,0 +1,242 @@
+use std::{
+    borrow::{Borrow, Cow},
+    collections::BTreeSet,
+    ffi::{OsStr, OsString},
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::Arc,
+};
+
+use static_assertions::{assert_impl_all, assert_not_impl_any};
+use strid::{PathNormalizer, PathValidator, braid_os_str, braid_path, braid_ref};
+
+/// A directory holding configuration files
+#[braid_path(serde)]
+pub struct ConfigDirBuf;
+
+/// The path of an absolute Unix socket
+#[braid_path(serde, validator)]
+pub struct SocketPathBuf;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum InvalidPath {
+    Empty,
+    NotAbsolute,
+    NotRelative,
+}
+
+impl std::fmt::Display for InvalidPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "empty path",
+            Self::NotAbsolute => "path is not absolute",
+            Self::NotRelative => "path is not relative",
+        })
+    }
+}
+
+impl PathValidator for SocketPathBuf {
+    type Error = InvalidPath;
+
+    fn validate(raw: &Path) -> Result<(), Self::Error> {
+        if raw.is_absolute() {
+            Ok(())
+        } else {
+            Err(InvalidPath::NotAbsolute)
+        }
+    }
+}
+
+/// A file in the cache, relative to the cache directory and without a leading `./`
+#[braid_path(serde, normalizer)]
+pub struct CacheFile;
+
+impl PathValidator for CacheFile {
+    type Error = InvalidPath;
+
+    fn validate(raw: &Path) -> Result<(), Self::Error> {
+        if raw.as_os_str().is_empty() {
+            Err(InvalidPath::Empty)
+        } else if raw.has_root() || raw.starts_with(".") {
+            Err(InvalidPath::NotRelative)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl PathNormalizer for CacheFile {
+    fn normalize(raw: &Path) -> Result<Cow<'_, Path>, Self::Error> {
+        match raw.strip_prefix(".") {
+            Ok(stripped) => Self::validate(stripped).map(|()| Cow::Owned(stripped.to_owned())),
+            Err(_) => Self::validate(raw).map(|()| Cow::Borrowed(raw)),
+        }
+    }
+}
+
+/// The name of an environment variable
+#[braid_os_str(serde)]
+pub struct EnvKey;
+
+/// A log file scoped to a service
+#[braid_path]
+pub struct LogFileBuf<T>;
+
+pub struct Api;
+
+/// A mount point with only a borrowed form
+#[braid_ref(validator = "SocketPathBuf")]
+pub struct MountPoint(Path);
+
+assert_impl_all!(ConfigDirBuf: Borrow<Path>, Borrow<ConfigDir>, AsRef<Path>, AsRef<OsStr>, From<PathBuf>, Ord, Clone);
+assert_impl_all!(ConfigDir: Borrow<Path>, AsRef<Path>, AsRef<OsStr>, Ord);
+assert_impl_all!(SocketPathBuf: Borrow<Path>, TryFrom<PathBuf>);
+assert_not_impl_any!(SocketPathBuf: From<PathBuf>);
+assert_not_impl_any!(CacheFile: Borrow<Path>);
+assert_not_impl_any!(CacheFileRef: Borrow<Path>);
+assert_impl_all!(EnvKey: Borrow<OsStr>, AsRef<OsStr>, AsRef<Path>, From<OsString>);
+assert_impl_all!(MountPoint: AsRef<Path>, AsRef<OsStr>, Ord);
+assert_not_impl_any!(MountPoint: ToOwned);
+
+#[test]
+fn round_trips_between_forms() {
+    let owned = ConfigDirBuf::new(PathBuf::from("/etc/app"));
+    let borrowed = ConfigDir::from_path(Path::new("/etc/app"));
+
+    assert_eq!(owned, borrowed);
+    assert_eq!(borrowed, owned);
+    assert_eq!(owned.as_path(), Path::new("/etc/app"));
+    assert_eq!(borrowed.to_owned(), owned);
+
+    let boxed: Box<ConfigDir> = owned.clone().into_boxed_ref();
+    assert_eq!(boxed.as_path(), borrowed.as_path());
+    assert_eq!(boxed.into_owned(), owned);
+
+    let cow: Cow<ConfigDir> = Cow::Borrowed(borrowed);
+    assert_eq!(ConfigDirBuf::from(cow), owned);
+
+    let rc: Rc<ConfigDir> = borrowed.into();
+    let arc: Arc<ConfigDir> = borrowed.into();
+    assert_eq!(&*rc, borrowed);
+    assert_eq!(&*arc, borrowed);
+
+    assert_eq!(PathBuf::from(owned.clone()), PathBuf::from("/etc/app"));
+    assert_eq!(owned.take(), PathBuf::from("/etc/app"));
+}
+
+#[test]
+fn builds_raw_paths() {
+    let dir = ConfigDir::from_static("/etc/app");
+    assert_eq!(dir.join("app.toml"), Path::new("/etc/app/app.toml"));
+    assert_eq!(dir.with_file_name("other"), Path::new("/etc/other"));
+    assert_eq!(dir.with_extension("d"), Path::new("/etc/app.d"));
+
+    let key = EnvKey::from_static("HOME");
+    assert_eq!(key.join("bin"), Path::new("HOME/bin"));
+
+    let mount = MountPoint::from_path(Path::new("/mnt")).unwrap();
+    assert_eq!(mount.join("disk"), Path::new("/mnt/disk"));
+    assert_eq!(
+        MountPoint::from_path(Path::new("mnt")).unwrap_err(),
+        InvalidPath::NotAbsolute
+    );
+}
+
+#[test]
+fn passes_to_path_apis() {
+    fn components(path: impl AsRef<Path>) -> usize {
+        path.as_ref().components().count()
+    }
+
+    let owned = ConfigDirBuf::from_static("/etc/app");
+    assert_eq!(components(&owned), 3);
+    assert_eq!(components(&*owned), 3);
+    assert_eq!(components(EnvKey::from_static("HOME")), 1);
+}
+
+#[test]
+fn looks_up_by_path() {
+    let dirs: BTreeSet<ConfigDirBuf> = ["/etc/b", "/etc/a"].map(ConfigDirBuf::from_static).into();
+    assert!(dirs.contains(Path::new("/etc/a")));
+    assert!(dirs.contains(ConfigDir::from_static("/etc/b")));
+    assert!(!dirs.contains(Path::new("/etc/c")));
+    assert_eq!(dirs.first().unwrap().as_path(), Path::new("/etc/a"));
+}
+
+#[test]
+fn debug_looks_like_a_path() {
+    let dir = ConfigDirBuf::from_static("/etc/app");
+    assert_eq!(format!("{dir:?}"), r#""/etc/app""#);
+    assert_eq!(format!("{:?}", EnvKey::from_static("HOME")), r#""HOME""#);
+}
+
+#[test]
+fn validates() {
+    assert_eq!(
+        SocketPathBuf::new("app.sock".into()).unwrap_err(),
+        InvalidPath::NotAbsolute
+    );
+    assert_eq!(
+        SocketPath::from_path(Path::new("app.sock")).unwrap_err(),
+        InvalidPath::NotAbsolute
+    );
+    assert!(SocketPathBuf::try_from(Path::new("/run/app.sock")).is_ok());
+    assert!(<&SocketPath>::try_from(Path::new("/run/app.sock")).is_ok());
+
+    let unchecked = unsafe { SocketPath::from_path_unchecked(Path::new("app.sock")) };
+    assert_eq!(unchecked.as_path(), Path::new("app.sock"));
+}
+
+#[test]
+#[should_panic(expected = "invalid SocketPath")]
+fn from_static_panics_when_invalid() {
+    SocketPath::from_static("app.sock");
+}
+
+#[test]
+fn normalizes() {
+    let owned = CacheFile::new("./a/b".into()).unwrap();
+    assert_eq!(owned.as_path(), Path::new("a/b"));
+
+    assert!(matches!(
+        CacheFileRef::from_path(Path::new("a/b")).unwrap(),
+        Cow::Borrowed(_)
+    ));
+    assert_eq!(
+        CacheFileRef::from_path(Path::new("./a")).unwrap().as_path(),
+        Path::new("a")
+    );
+    assert!(CacheFileRef::from_normalized_path(Path::new("./a")).is_err());
+    assert_eq!(
+        CacheFile::new("/a".into()).unwrap_err(),
+        InvalidPath::NotRelative
+    );
+    assert_eq!(CacheFile::new(".".into()).unwrap_err(), InvalidPath::Empty);
+}
+
+#[test]
+fn generic_markers() {
+    let file = LogFileBuf::<Api>::from_static("/var/log/api.log");
+    let borrowed: &LogFile<Api> = &file;
+    assert_eq!(file, borrowed);
+    assert_eq!(borrowed.to_owned(), file);
+}
+
+#[test]
+fn serializes_as_paths() {
+    let dir = ConfigDirBuf::from_static("/etc/app");
+    let json = serde_json::to_string(&dir).unwrap();
+    assert_eq!(json, r#""/etc/app""#);
+    assert_eq!(serde_json::from_str::<ConfigDirBuf>(&json).unwrap(), dir);
+    assert_eq!(serde_json::from_str::<&ConfigDir>(&json).unwrap(), dir);
+
+    let socket: Result<SocketPathBuf, _> = serde_json::from_str(r#""app.sock""#);
+    assert!(socket.is_err());
+
+    let file: CacheFile = serde_json::from_str(r#""./a""#).unwrap();
+    assert_eq!(file.as_path(), Path::new("a"));
+
+    let key = EnvKey::from_static("HOME");
+    let json = serde_json::to_string(&key).unwrap();
+    assert_eq!(serde_json::from_str::<EnvKey>(&json).unwrap(), key);
+}
@@ -0,0 +1,93 @@
+use serde_json::json;
+use strid::{Constraints, PatternError, braid, braid_ref};
+
+/// A lowercase hostname label
+#[braid(pattern = "^[a-z][a-z0-9-]*$", schemars)]
+pub struct Hostname;
+
+/// A numeric code
+#[braid_ref(pattern = r"^\d{3}$")]
+pub struct Code(str);
+
+/// A version number, using escapes that only make sense once the literal is unescaped
+#[braid(pattern = "^v\\d+\\.\\d+$")]
+pub struct Version;
+
+/// A ticket reference, described by its own constraints
+#[braid(pattern = "^[A-Z]+-\\d+$", schemars, constraints = "TicketConstraints")]
+pub struct Ticket;
+
+pub struct TicketConstraints;
+
+impl Constraints for TicketConstraints {
+    const EXAMPLES: Option<&'static [&'static str]> = Some(&["CORE-1"]);
+}
+
+#[test]
+fn accepts_matching_values() {
+    assert_eq!(Hostname::new("db-1".to_owned()).unwrap().as_str(), "db-1");
+    assert_eq!(HostnameRef::from_str("db").unwrap().as_str(), "db");
+    assert_eq!(Code::from_str("042").unwrap().as_str(), "042");
+    assert_eq!(Version::from_static("v1.10").as_str(), "v1.10");
+    assert_eq!(Ticket::from_static("CORE-12").as_str(), "CORE-12");
+}
+
+#[test]
+fn rejects_other_values() {
+    assert!(HostnameRef::from_str("1db").is_err());
+    assert!(HostnameRef::from_str("DB").is_err());
+    assert!(Code::from_str("42").is_err());
+    assert!(VersionRef::from_str("v1x10").is_err());
+}
+
+#[test]
+fn error_reports_value_and_pattern() {
+    let err: PatternError = HostnameRef::from_str("-db").unwrap_err();
+    assert_eq!(err.value(), "-db");
+    assert_eq!(err.pattern(), "^[a-z][a-z0-9-]*$");
+    assert_eq!(
+        err.to_string(),
+        r#""-db" does not match the pattern `^[a-z][a-z0-9-]*$`"#
+    );
+}
+
+/// A pattern that parses, but is too large for `regex` to compile
+#[braid(pattern = r"^\w{1000}\w{1000}\w{1000}$")]
+pub struct Oversized;
+
+#[test]
+fn oversized_patterns_reject_values() {
+    let err: PatternError = OversizedRef::from_str("word").unwrap_err();
+    assert_eq!(err.value(), "word");
+    assert!(err.invalid_pattern().is_some());
+    assert!(
+        err.to_string()
+            .starts_with(r#""word" could not be checked against the pattern"#)
+    );
+    assert!(
+        HostnameRef::from_str("-db")
+            .unwrap_err()
+            .invalid_pattern()
+            .is_none()
+    );
+}
+
+#[test]
+fn pattern_is_a_constraint() {
+    assert_eq!(Hostname::PATTERN, Some("^[a-z][a-z0-9-]*$"));
+    assert_eq!(Code::PATTERN, Some(r"^\d{3}$"));
+    assert_eq!(Version::PATTERN, Some("^v\\d+\\.\\d+$"));
+}
+
+#[test]
+fn pattern_in_schema() {
+    let schema = schemars::schema_for!(Hostname);
+    assert_eq!(schema.get("pattern"), Some(&json!("^[a-z][a-z0-9-]*$")));
+}
+
+#[test]
+fn explicit_constraints_are_kept() {
+    let schema = schemars::schema_for!(Ticket);
+    assert_eq!(schema.get("pattern"), None);
+    assert_eq!(schema.get("examples"), Some(&json!(["CORE-1"])));
+}
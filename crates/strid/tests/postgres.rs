@@ -0,0 +1,65 @@
+use bytes::BytesMut;
+use postgres_types::{FromSql, IsNull, ToSql, Type};
+use strid::braid;
+
+#[path = "common/validators.rs"]
+mod validators;
+
+#[braid(postgres)]
+pub struct Plain;
+
+#[braid(postgres, normalizer = "validators::Lowercase")]
+pub struct Lower;
+
+fn encode(value: &dyn ToSql, ty: &Type) -> BytesMut {
+    let mut buf = BytesMut::new();
+    let is_null = value.to_sql_checked(ty, &mut buf).unwrap();
+    assert!(matches!(is_null, IsNull::No));
+    buf
+}
+
+#[test]
+fn round_trip() {
+    let owned = Plain::from_static("value");
+    let buf = encode(&owned, &Type::TEXT);
+    assert_eq!(Plain::from_sql(&Type::TEXT, &buf).unwrap(), owned);
+
+    let borrowed = PlainRef::from_str("other");
+    let buf = encode(&borrowed, &Type::VARCHAR);
+    assert_eq!(
+        <&PlainRef>::from_sql(&Type::VARCHAR, &buf).unwrap(),
+        borrowed
+    );
+}
+
+#[test]
+fn accepts_text_types() {
+    for ty in [Type::TEXT, Type::VARCHAR, Type::BPCHAR, Type::NAME] {
+        assert!(<Plain as ToSql>::accepts(&ty));
+        assert!(<&PlainRef as ToSql>::accepts(&ty));
+        assert!(<Plain as FromSql>::accepts(&ty));
+        assert!(<&PlainRef as FromSql>::accepts(&ty));
+    }
+
+    assert!(!<Plain as ToSql>::accepts(&Type::INT4));
+    let mut buf = BytesMut::new();
+    assert!(
+        Plain::from_static("value")
+            .to_sql_checked(&Type::INT4, &mut buf)
+            .is_err()
+    );
+}
+
+#[test]
+fn decoding_validates_and_normalizes() {
+    let buf = encode(&"Value", &Type::TEXT);
+    assert_eq!(
+        Lower::from_sql(&Type::TEXT, &buf).unwrap().as_str(),
+        "value"
+    );
+    assert!(<&LowerRef>::from_sql(&Type::TEXT, &buf).is_err());
+
+    let buf = encode(&"", &Type::TEXT);
+    assert!(Lower::from_sql(&Type::TEXT, &buf).is_err());
+    assert!(<&LowerRef>::from_sql(&Type::TEXT, &buf).is_err());
+}
@@ -0,0 +1,87 @@
+use proptest::prelude::*;
+use strid::braid;
+
+#[path = "common/validators.rs"]
+mod validators;
+
+/// Any string at all
+#[braid(proptest)]
+pub struct Plain;
+
+/// A user's login name
+#[braid(
+    validator = "validators::Login",
+    proptest,
+    proptest_strategy = "Username::strategy"
+)]
+pub struct Username;
+
+impl Username {
+    fn strategy() -> &'static str {
+        "[a-z]{3,16}"
+    }
+}
+
+/// A login name generated by its validator's strategy
+#[braid(validator = "Handle", proptest)]
+pub struct Account;
+
+pub struct Handle;
+
+impl strid::Validator for Handle {
+    type Error = validators::Invalid;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        <validators::Login as strid::Validator>::validate(raw)
+    }
+}
+
+impl strid::ProptestStrategy for Handle {
+    type Strategy = &'static str;
+
+    fn strategy() -> Self::Strategy {
+        "[a-z]{3,16}"
+    }
+}
+
+/// A login name generated by the braid's strategy rather than its validator's
+#[braid(validator = "Handle", proptest, proptest_strategy = "Short::strategy")]
+pub struct Short;
+
+impl Short {
+    fn strategy() -> &'static str {
+        "[a-z]{3}"
+    }
+}
+
+/// A non-empty string without ASCII uppercase letters
+#[braid(normalizer = "validators::Lowercase", proptest)]
+pub struct Lower;
+
+proptest! {
+    #[test]
+    fn plain_round_trips(plain: Plain) {
+        prop_assert_eq!(plain.as_str().parse::<Plain>().unwrap(), plain);
+    }
+
+    // Filtering arbitrary strings would reject too many to find any usernames
+    #[test]
+    fn usernames_are_valid(username: Username) {
+        prop_assert!(<validators::Login as strid::Validator>::validate(username.as_str()).is_ok());
+    }
+
+    #[test]
+    fn validator_strategies_generate_valid_values(account: Account) {
+        prop_assert!(<Handle as strid::Validator>::validate(account.as_str()).is_ok());
+    }
+
+    #[test]
+    fn braid_strategies_override_validator_strategies(short: Short) {
+        prop_assert_eq!(short.as_str().len(), 3);
+    }
+
+    #[test]
+    fn lowers_are_normalized(lower: Lower) {
+        prop_assert!(<validators::Lowercase as strid::Validator>::validate(lower.as_str()).is_ok());
+    }
+}
@@ -0,0 +1,90 @@
+use quickcheck::Arbitrary;
+use quickcheck_macros::quickcheck;
+use strid::braid;
+
+#[path = "common/validators.rs"]
+mod validators;
+
+/// A non-empty string
+#[braid(validator = "validators::NonEmpty", quickcheck)]
+pub struct NonEmpty;
+
+/// A non-empty string without ASCII uppercase letters
+#[braid(normalizer = "validators::Lowercase", quickcheck)]
+pub struct Lower;
+
+/// A PIN of exactly six digits, which random strings almost never are
+#[braid(validator, quickcheck, quickcheck_generator = "Pin::generate")]
+pub struct Pin;
+
+impl Pin {
+    fn generate(g: &mut quickcheck::Gen) -> String {
+        (0..6)
+            .map(|_| *g.choose(&['0', '4', '7', '9']).unwrap())
+            .collect()
+    }
+}
+
+impl strid::Validator for Pin {
+    type Error = validators::Invalid;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.len() == 6 && raw.bytes().all(|b| b.is_ascii_digit()) {
+            Ok(())
+        } else {
+            Err(validators::Invalid)
+        }
+    }
+}
+
+/// A braid that rejects every value, so nothing can be generated for it
+#[braid(validator, quickcheck)]
+pub struct Never;
+
+impl strid::Validator for Never {
+    type Error = validators::Invalid;
+
+    fn validate(_: &str) -> Result<(), Self::Error> {
+        Err(validators::Invalid)
+    }
+}
+
+#[quickcheck]
+fn generated_values_are_valid(value: NonEmpty) -> bool {
+    !value.as_str().is_empty()
+}
+
+#[quickcheck]
+fn generated_values_are_normalized(value: Lower) -> bool {
+    !value.as_str().bytes().any(|b| b.is_ascii_uppercase())
+}
+
+#[quickcheck]
+fn generator_produces_values(value: Pin) -> bool {
+    value.as_str().len() == 6
+}
+
+#[test]
+#[should_panic(expected = "quickcheck_generator")]
+fn generation_panics_when_nothing_is_accepted() {
+    Never::arbitrary(&mut quickcheck::Gen::new(8));
+}
+
+#[test]
+fn shrinking_preserves_validity() {
+    let value = NonEmpty::new("abc".to_owned()).unwrap();
+    let shrunk: Vec<_> = value.shrink().collect();
+    assert!(!shrunk.is_empty());
+    assert!(shrunk.iter().all(|v| !v.as_str().is_empty()));
+}
+
+#[test]
+fn shrinking_preserves_normalization() {
+    let value = Lower::new("ab c".to_owned()).unwrap();
+    // Characters shrink toward `\0` through uppercase letters, which are dropped, not normalized
+    assert!(
+        value
+            .shrink()
+            .all(|v| !v.as_str().bytes().any(|b| b.is_ascii_uppercase()) && v != value)
+    );
+}
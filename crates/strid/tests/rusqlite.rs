@@ -0,0 +1,69 @@
+use rusqlite::{Connection, params};
+use strid::{braid, braid_bytes};
+
+#[path = "common/validators.rs"]
+mod validators;
+
+#[braid(rusqlite)]
+pub struct Plain;
+
+#[braid(rusqlite, validator = "validators::NonEmpty")]
+pub struct Checked;
+
+#[braid_bytes(rusqlite)]
+pub struct Blob;
+
+fn connection() -> Connection {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute("CREATE TABLE t (value TEXT NOT NULL)", [])
+        .unwrap();
+    conn
+}
+
+#[test]
+fn round_trip() {
+    let conn = connection();
+    let owned = Plain::from_static("value");
+    conn.execute(
+        "INSERT INTO t VALUES (?1), (?2)",
+        params![owned, PlainRef::from_str("other")],
+    )
+    .unwrap();
+
+    let mut stmt = conn.prepare("SELECT value FROM t").unwrap();
+    let values = stmt
+        .query_map([], |row| row.get::<_, Plain>(0))
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(
+        values,
+        [Plain::from_static("value"), Plain::from_static("other")]
+    );
+}
+
+#[test]
+fn reading_validates() {
+    let conn = connection();
+    conn.execute("INSERT INTO t VALUES ('')", []).unwrap();
+
+    let result = conn.query_row("SELECT value FROM t", [], |row| row.get::<_, Checked>(0));
+    assert!(result.is_err());
+}
+
+#[test]
+fn byte_round_trip() {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute("CREATE TABLE b (value BLOB NOT NULL)", [])
+        .unwrap();
+    conn.execute(
+        "INSERT INTO b VALUES (?1)",
+        params![BlobRef::from_bytes(b"\x00\xff")],
+    )
+    .unwrap();
+
+    let value = conn
+        .query_row("SELECT value FROM b", [], |row| row.get::<_, Blob>(0))
+        .unwrap();
+    assert_eq!(value.as_bytes(), b"\x00\xff");
+}
@@ -0,0 +1,14 @@
+use sailfish::runtime::{Buffer, Render};
+use strid::braid;
+
+#[braid(sailfish)]
+pub struct Greeting;
+
+#[test]
+fn renders_as_the_inner_string() {
+    let owned = Greeting::from_static("hello");
+    let mut buf = Buffer::new();
+    owned.render(&mut buf).unwrap();
+    GreetingRef::from_str(", world").render(&mut buf).unwrap();
+    assert_eq!(buf.as_str(), "hello, world");
+}
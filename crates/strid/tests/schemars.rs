@@ -0,0 +1,110 @@
+use schemars::{JsonSchema, schema_for};
+use serde_json::json;
+use strid::{Constraints, braid, braid_ref};
+
+#[path = "common/validators.rs"]
+mod validators;
+
+/// A plain string
+///
+/// With a second paragraph
+#[braid(schemars)]
+pub struct Plain;
+
+/// A user's login name
+#[braid(
+    schemars,
+    validator = "validators::Login",
+    constraints,
+    ref_doc = "A borrowed login name"
+)]
+pub struct Username;
+
+pub struct Colours;
+
+impl Constraints for Colours {
+    const ENUM: Option<&'static [&'static str]> = Some(&["red", "green", "blue"]);
+}
+
+/// A colour
+#[braid_ref(schemars, constraints = "Colours")]
+pub struct ColourRef(str);
+
+#[braid(schemars, constraints)]
+pub struct Email;
+
+#[braid(schemars)]
+pub struct Id<T>;
+
+impl Constraints for Email {
+    const FORMAT: Option<&'static str> = Some("email");
+}
+
+#[test]
+fn unconstrained() {
+    let schema = schema_for!(Plain);
+    assert_eq!(schema.get("title"), Some(&json!("Plain")));
+    assert_eq!(schema.get("type"), Some(&json!("string")));
+    assert_eq!(
+        schema.get("description"),
+        Some(&json!("A plain string\n\nWith a second paragraph"))
+    );
+    assert_eq!(schema.get("pattern"), None);
+    assert_eq!(Plain::schema_name(), "Plain");
+    assert_eq!(PlainRef::schema_name(), "PlainRef");
+}
+
+#[test]
+fn validator_constraints() {
+    let schema = schema_for!(Username);
+    assert_eq!(
+        schema.get("description"),
+        Some(&json!("A user's login name"))
+    );
+    assert_eq!(schema.get("pattern"), Some(&json!("^[a-z]+$")));
+    assert_eq!(schema.get("minLength"), Some(&json!(3)));
+    assert_eq!(schema.get("maxLength"), Some(&json!(16)));
+    assert_eq!(schema.get("examples"), Some(&json!(["alice", "bob"])));
+
+    let schema = schema_for!(UsernameRef);
+    assert_eq!(
+        schema.get("description"),
+        Some(&json!("A borrowed login name"))
+    );
+    assert_eq!(schema.get("pattern"), Some(&json!("^[a-z]+$")));
+}
+
+#[test]
+fn explicit_constraints() {
+    let schema = schema_for!(ColourRef);
+    assert_eq!(schema.get("description"), Some(&json!("A colour")));
+    assert_eq!(schema.get("enum"), Some(&json!(["red", "green", "blue"])));
+
+    let schema = schema_for!(Email);
+    assert_eq!(schema.get("format"), Some(&json!("email")));
+}
+
+#[test]
+fn referenced_from_other_schemas() {
+    #[derive(JsonSchema)]
+    #[allow(dead_code)]
+    struct Login<'a> {
+        owned: Username,
+        borrowed: &'a UsernameRef,
+    }
+
+    let schema = schema_for!(Login<'static>);
+    let defs = schema.get("$defs").unwrap();
+    assert!(defs.get("Username").is_some());
+    assert!(defs.get("UsernameRef").is_some());
+}
+
+#[test]
+fn generic_braids_share_a_schema() {
+    struct User;
+    struct Org;
+
+    assert_eq!(Id::<User>::schema_name(), "Id");
+    assert_eq!(Id::<User>::schema_id(), Id::<Org>::schema_id());
+    assert_eq!(schema_for!(Id<User>), schema_for!(Id<Org>));
+}
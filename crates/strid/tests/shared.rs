@@ -0,0 +1,154 @@
+use std::{
+    borrow::{Borrow, Cow},
+    collections::HashSet,
+    rc::Rc,
+    sync::Arc,
+};
+
+use static_assertions::{assert_impl_all, assert_not_impl_any};
+use strid::braid;
+
+#[path = "common/validators.rs"]
+mod validators;
+
+/// An identifier that is cloned into many tasks
+#[braid(serde)]
+pub struct TaskId(Arc<str>);
+
+/// A name for a worker, which can't be empty
+#[braid(serde, validator = "validators::NonEmpty")]
+pub struct WorkerName(std::sync::Arc<str>);
+
+/// A queue name, trimmed of surrounding whitespace
+#[braid(normalizer)]
+pub struct QueueName(Arc<str>);
+
+impl strid::Validator for QueueName {
+    type Error = validators::Invalid;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.is_empty() || raw.trim() != raw {
+            Err(validators::Invalid)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl strid::Normalizer for QueueName {
+    fn normalize(raw: &str) -> Result<Cow<'_, str>, Self::Error> {
+        let trimmed = raw.trim();
+        if trimmed.is_empty() {
+            Err(validators::Invalid)
+        } else {
+            Ok(Cow::Borrowed(trimmed))
+        }
+    }
+}
+
+/// A label that stays on one thread
+#[braid]
+pub struct LocalLabel(Rc<str>);
+
+/// An identifier scoped to a kind of record
+#[braid]
+pub struct ScopedId<T>(Arc<str>);
+
+pub struct Job;
+
+assert_impl_all!(TaskId: Clone, Send, Sync, Borrow<TaskIdRef>, Borrow<str>, From<&'static TaskIdRef>, From<Arc<TaskIdRef>>, Into<Arc<TaskIdRef>>, Into<String>, Into<Box<TaskIdRef>>);
+assert_impl_all!(WorkerName: TryFrom<String>, From<Arc<WorkerNameRef>>, Into<Arc<WorkerNameRef>>);
+assert_not_impl_any!(WorkerName: From<String>);
+assert_impl_all!(LocalLabel: Clone, From<Rc<LocalLabelRef>>, Into<Rc<LocalLabelRef>>);
+assert_not_impl_any!(LocalLabel: Send, Sync, From<Arc<LocalLabelRef>>);
+
+#[test]
+fn clones_share_the_allocation() {
+    let id = TaskId::from_static("build");
+    let clone = id.clone();
+    assert_eq!(clone.as_str().as_ptr(), id.as_str().as_ptr());
+    assert_eq!(Arc::strong_count(&clone.take()), 2);
+}
+
+#[test]
+fn converts_to_a_shared_ref_without_copying() {
+    let id = TaskId::from_static("build");
+    let ptr = id.as_str().as_ptr();
+
+    let shared: Arc<TaskIdRef> = id.clone().into_arc_ref();
+    assert_eq!(shared.as_str().as_ptr(), ptr);
+    assert_eq!(&*shared, &*id);
+
+    let back = TaskId::from(shared);
+    assert_eq!(back.as_str().as_ptr(), ptr);
+    assert_eq!(back, id);
+
+    let label = LocalLabel::from_static("main");
+    let ptr = label.as_str().as_ptr();
+    let shared: Rc<LocalLabelRef> = label.into();
+    assert_eq!(shared.as_str().as_ptr(), ptr);
+    assert_eq!(LocalLabel::from(shared).as_str(), "main");
+}
+
+#[test]
+fn round_trips_between_forms() {
+    let borrowed = TaskIdRef::from_static("build");
+    let owned = TaskId::from(borrowed);
+    assert_eq!(owned, borrowed);
+    assert_eq!(borrowed.to_owned(), owned);
+
+    let boxed: Box<TaskIdRef> = owned.clone().into_boxed_ref();
+    assert_eq!(&*boxed, borrowed);
+    assert_ne!(boxed.as_str().as_ptr(), owned.as_str().as_ptr());
+
+    assert_eq!(String::from(owned.clone()), "build");
+    assert_eq!(TaskId::from(String::from("build")), owned);
+    assert_eq!(TaskId::from(Cow::Borrowed(borrowed)), owned);
+}
+
+#[test]
+fn looks_up_by_ref() {
+    let ids: HashSet<TaskId> = ["a", "b"].map(TaskId::from_static).into();
+    assert!(ids.contains(TaskIdRef::from_static("a")));
+    assert!(ids.contains("b"));
+    assert!(!ids.contains("c"));
+}
+
+#[test]
+fn checks_values() {
+    assert_eq!(
+        WorkerName::new(Arc::from("")).unwrap_err(),
+        validators::Invalid
+    );
+    assert_eq!(WorkerName::try_from("w1").unwrap().as_str(), "w1");
+
+    let name = WorkerName::from_static("w1");
+    let shared: Arc<WorkerNameRef> = name.clone().into();
+    assert_eq!(WorkerName::from(shared), name);
+
+    assert_eq!(
+        QueueName::new(Arc::from(" jobs ")).unwrap().as_str(),
+        "jobs"
+    );
+    assert_eq!(
+        QueueName::new(Arc::from("  ")).unwrap_err(),
+        validators::Invalid
+    );
+}
+
+#[test]
+fn generic_markers() {
+    let id = ScopedId::<Job>::from_static("42");
+    let shared: Arc<ScopedIdRef<Job>> = id.clone().into_arc_ref();
+    assert_eq!(&*shared, &*id);
+    assert_eq!(ScopedId::from(shared), id);
+}
+
+#[test]
+fn serializes_as_a_string() {
+    let id = TaskId::from_static("build");
+    let json = serde_json::to_string(&id).unwrap();
+    assert_eq!(json, r#""build""#);
+    assert_eq!(serde_json::from_str::<TaskId>(&json).unwrap(), id);
+    assert!(serde_json::from_str::<WorkerName>(r#""""#).is_err());
+}
@@ -0,0 +1,81 @@
+use strid::{braid, braid_ref};
+use ts_rs::TS;
+
+/// A unique identifier for a user
+#[braid(
+    ts_rs,
+    ref_doc = "A borrowed reference to a unique identifier for a user"
+)]
+pub struct UserId;
+
+/// A label
+#[braid_ref(ts_rs)]
+pub struct LabelRef(str);
+
+#[derive(TS)]
+#[allow(dead_code)]
+struct Session<'a> {
+    owner: UserId,
+    viewer: &'a UserIdRef,
+    label: &'a LabelRef,
+}
+
+const NOTE: &str = "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). \
+                    Do not edit this file manually.\n";
+
+#[test]
+fn branded_declaration() {
+    assert_eq!(UserId::name(), "UserId");
+    assert_eq!(
+        UserId::inline(),
+        r#"string & { readonly __brand: "UserId" }"#
+    );
+    assert_eq!(
+        UserId::export_to_string().unwrap(),
+        format!(
+            "{NOTE}\n/**\n * A unique identifier for a user\n */\nexport type UserId = string & \
+             {{ readonly __brand: \"UserId\" }};\n"
+        )
+    );
+}
+
+#[test]
+fn borrowed_shares_owned_declaration() {
+    assert_eq!(UserIdRef::name(), UserId::name());
+    assert_eq!(UserIdRef::decl(), UserId::decl());
+    assert_eq!(UserIdRef::docs(), UserId::docs());
+    assert_eq!(UserIdRef::output_path(), UserId::output_path());
+    assert_eq!(
+        UserIdRef::export_to_string().unwrap(),
+        UserId::export_to_string().unwrap()
+    );
+}
+
+#[test]
+fn ref_only_declaration() {
+    assert_eq!(
+        LabelRef::export_to_string().unwrap(),
+        format!(
+            "{NOTE}\n/**\n * A label\n */\nexport type LabelRef = string & {{ readonly __brand: \
+             \"LabelRef\" }};\n"
+        )
+    );
+}
+
+#[test]
+fn referenced_from_other_types() {
+    let decl = Session::decl();
+    assert!(decl.contains("owner: UserId"), "{decl}");
+    assert!(decl.contains("viewer: UserId"), "{decl}");
+    assert!(decl.contains("label: LabelRef"), "{decl}");
+
+    let exported = Session::export_to_string().unwrap();
+    assert!(
+        exported.contains(r#"import type { UserId } from "./UserId";"#),
+        "{exported}"
+    );
+    assert!(
+        exported.contains(r#"import type { LabelRef } from "./LabelRef";"#),
+        "{exported}"
+    );
+}
@@ -0,0 +1,6 @@
+use strid::braid_ref;
+
+#[braid_ref(no_std)]
+pub struct ConfigDir(std::path::Path);
+
+fn main() {}
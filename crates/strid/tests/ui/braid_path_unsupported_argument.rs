@@ -0,0 +1,6 @@
+use strid::braid_path;
+
+#[braid_path(no_std)]
+pub struct ConfigDirBuf;
+
+fn main() {}
@@ -0,0 +1,6 @@
+use strid::braid_ref;
+
+#[braid_ref(normalizer)]
+pub struct Username(str);
+
+fn main() {}
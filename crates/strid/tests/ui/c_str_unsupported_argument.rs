@@ -0,0 +1,6 @@
+use strid::braid_ref;
+
+#[braid_ref(display = "omit")]
+pub struct Label(core::ffi::CStr);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use strid::braid;
+
+#[braid(validator, normalizer)]
+pub struct Username;
+
+fn main() {}
@@ -0,0 +1,6 @@
+use strid::braid;
+
+#[braid]
+pub struct Fixed<const N: usize>;
+
+fn main() {}
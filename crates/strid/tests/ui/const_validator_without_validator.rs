@@ -0,0 +1,10 @@
+use strid::braid;
+
+#[braid(const_validator = "is_valid")]
+pub struct Username;
+
+const fn is_valid(s: &str) -> bool {
+    !s.is_empty()
+}
+
+fn main() {}
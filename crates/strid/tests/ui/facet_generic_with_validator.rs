@@ -0,0 +1,9 @@
+use strid::{braid, braid_ref};
+
+#[braid(validator = "NonEmpty")]
+pub struct Id<T>;
+
+#[braid_ref(validator = "NonEmpty")]
+pub struct LabelRef<T>(str);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use strid::braid;
+
+#[braid(debug = "owend")]
+pub struct Username;
+
+fn main() {}
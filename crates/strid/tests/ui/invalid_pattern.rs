@@ -0,0 +1,6 @@
+use strid::braid;
+
+#[braid(pattern = "^[a-z+$")]
+pub struct Username;
+
+fn main() {}
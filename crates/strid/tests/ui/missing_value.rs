@@ -0,0 +1,6 @@
+use strid::braid;
+
+#[braid(ref_name)]
+pub struct Username;
+
+fn main() {}
@@ -0,0 +1,6 @@
+use strid::braid;
+
+#[braid(serde, colour = "red", ord = "sorted")]
+pub struct Username;
+
+fn main() {}
@@ -0,0 +1,8 @@
+use strid::braid;
+
+#[braid]
+pub enum Color {
+    Red,
+}
+
+fn main() {}
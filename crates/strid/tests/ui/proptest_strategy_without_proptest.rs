@@ -0,0 +1,10 @@
+use strid::braid;
+
+#[braid(proptest_strategy = "username")]
+pub struct Username;
+
+fn username() -> &'static str {
+    "[a-z]{1,16}"
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use strid::braid;
+
+#[braid(quickcheck_generator = "username")]
+pub struct Username;
+
+fn username(_: &mut quickcheck::Gen) -> String {
+    "user".to_owned()
+}
+
+fn main() {}
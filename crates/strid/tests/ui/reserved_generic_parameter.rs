@@ -0,0 +1,6 @@
+use strid::braid;
+
+#[braid]
+pub struct Scoped<'a, H>;
+
+fn main() {}
@@ -0,0 +1,6 @@
+use strid::braid;
+
+#[braid]
+pub struct Pair(String, String);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use strid::braid;
+
+#[braid(clone = omit)]
+pub struct Username;
+
+#[braid(clone = 1)]
+pub struct Password;
+
+fn main() {}
@@ -0,0 +1,86 @@
+use serde_json::{Value, json};
+use strid::{Constraints, braid, braid_ref};
+use utoipa::{PartialSchema, ToSchema};
+
+#[path = "common/validators.rs"]
+mod validators;
+
+/// A plain string
+///
+/// With a second paragraph
+#[braid(utoipa)]
+pub struct Plain;
+
+/// A user's login name
+#[braid(
+    utoipa,
+    validator = "validators::Login",
+    constraints,
+    ref_doc = "A borrowed login name"
+)]
+pub struct Username;
+
+pub struct Colours;
+
+impl Constraints for Colours {
+    const ENUM: Option<&'static [&'static str]> = Some(&["red", "green", "blue"]);
+}
+
+/// A colour
+#[braid_ref(utoipa, constraints = "Colours")]
+pub struct ColourRef(str);
+
+#[braid(utoipa, constraints)]
+pub struct Email;
+
+impl Constraints for Email {
+    const FORMAT: Option<&'static str> = Some("email");
+}
+
+fn schema_of<T: PartialSchema + ?Sized>() -> Value {
+    serde_json::to_value(T::schema()).unwrap()
+}
+
+#[test]
+fn unconstrained() {
+    let schema = schema_of::<Plain>();
+    assert_eq!(schema.get("type"), Some(&json!("string")));
+    assert_eq!(
+        schema.get("description"),
+        Some(&json!("A plain string\n\nWith a second paragraph"))
+    );
+    assert_eq!(schema.get("pattern"), None);
+    assert_eq!(schema.get("examples"), None);
+    assert_eq!(Plain::name(), "Plain");
+    assert_eq!(PlainRef::name(), "PlainRef");
+}
+
+#[test]
+fn validator_constraints() {
+    let schema = schema_of::<Username>();
+    assert_eq!(
+        schema.get("description"),
+        Some(&json!("A user's login name"))
+    );
+    assert_eq!(schema.get("pattern"), Some(&json!("^[a-z]+$")));
+    assert_eq!(schema.get("minLength"), Some(&json!(3)));
+    assert_eq!(schema.get("maxLength"), Some(&json!(16)));
+    assert_eq!(schema.get("examples"), Some(&json!(["alice", "bob"])));
+
+    let schema = schema_of::<UsernameRef>();
+    assert_eq!(
+        schema.get("description"),
+        Some(&json!("A borrowed login name"))
+    );
+    assert_eq!(schema.get("pattern"), Some(&json!("^[a-z]+$")));
+}
+
+#[test]
+fn explicit_constraints() {
+    let schema = schema_of::<ColourRef>();
+    assert_eq!(schema.get("description"), Some(&json!("A colour")));
+    assert_eq!(schema.get("enum"), Some(&json!(["red", "green", "blue"])));
+
+    let schema = schema_of::<Email>();
+    assert_eq!(schema.get("format"), Some(&json!("email")));
+}
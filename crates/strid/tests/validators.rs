@@ -0,0 +1,117 @@
+use strid::{
+    Validator, braid,
+    validators::{
+        And, Ascii, AsciiAlphanumeric, AsciiDigit, AsciiLowercase, Char, CharRange, CharSet,
+        MaxLen, MinLen, NonEmpty, Not, Or, ValidationError,
+    },
+};
+
+type SlugRules = And<NonEmpty, And<MaxLen<8>, CharSet<(AsciiAlphanumeric, Char<'-'>, Char<'_'>)>>>;
+
+/// A short identifier used in URLs
+#[braid(validator = "SlugRules")]
+pub struct Slug;
+
+/// A numeric code that isn't all zeroes
+#[braid(validator = "And<CharSet<AsciiDigit>, Not<CharSet<Char<'0'>>>>")]
+pub struct Code;
+
+#[test]
+fn non_empty() {
+    assert_eq!(NonEmpty::validate(""), Err(ValidationError::Empty));
+    assert_eq!(NonEmpty::validate(" "), Ok(()));
+}
+
+#[test]
+fn lengths_count_characters() {
+    assert_eq!(MaxLen::<3>::validate("äöü"), Ok(()));
+    assert_eq!(
+        MaxLen::<3>::validate("äöüß"),
+        Err(ValidationError::TooLong { max: 3, actual: 4 })
+    );
+    assert_eq!(MinLen::<3>::validate("äöü"), Ok(()));
+    assert_eq!(
+        MinLen::<3>::validate("äö"),
+        Err(ValidationError::TooShort { min: 3, actual: 2 })
+    );
+}
+
+#[test]
+fn ascii() {
+    assert_eq!(Ascii::validate("plain text"), Ok(()));
+    assert_eq!(
+        Ascii::validate("naïve"),
+        Err(ValidationError::NonAscii { position: 2 })
+    );
+}
+
+#[test]
+fn char_sets() {
+    type Hex = CharSet<(AsciiDigit, CharRange<'a', 'f'>)>;
+
+    assert_eq!(Hex::validate("0123abcdef"), Ok(()));
+    assert_eq!(
+        Hex::validate("12g4"),
+        Err(ValidationError::InvalidCharacter {
+            position: 2,
+            character: 'g',
+        })
+    );
+    assert_eq!(CharSet::<AsciiLowercase>::validate(""), Ok(()));
+}
+
+#[test]
+fn combinators() {
+    type ShortOrNumeric = Or<MaxLen<2>, CharSet<AsciiDigit>>;
+
+    assert_eq!(ShortOrNumeric::validate("ab"), Ok(()));
+    assert_eq!(ShortOrNumeric::validate("12345"), Ok(()));
+    assert_eq!(
+        ShortOrNumeric::validate("abc"),
+        Err(ValidationError::InvalidCharacter {
+            position: 0,
+            character: 'a',
+        })
+    );
+
+    assert_eq!(Not::<NonEmpty>::validate(""), Ok(()));
+    assert_eq!(
+        Not::<NonEmpty>::validate("a"),
+        Err(ValidationError::Excluded)
+    );
+
+    assert_eq!(
+        And::<NonEmpty, MaxLen<1>>::validate(""),
+        Err(ValidationError::Empty)
+    );
+}
+
+#[test]
+fn braids() {
+    assert_eq!(Slug::from_static("a-b_c").as_str(), "a-b_c");
+    assert_eq!(SlugRef::from_str(""), Err(ValidationError::Empty));
+    assert_eq!(
+        SlugRef::from_str("too-long-for-a-slug"),
+        Err(ValidationError::TooLong { max: 8, actual: 19 })
+    );
+    assert!(Slug::new("a b".to_owned()).is_err());
+
+    assert!(CodeRef::from_str("0042").is_ok());
+    assert_eq!(CodeRef::from_str("0000"), Err(ValidationError::Excluded));
+}
+
+#[test]
+fn errors_are_displayed() {
+    assert_eq!(
+        ValidationError::InvalidCharacter {
+            position: 1,
+            character: ' ',
+        }
+        .to_string(),
+        "invalid character ' ' at position 1"
+    );
+    assert_eq!(
+        ValidationError::TooLong { max: 8, actual: 9 }.to_string(),
+        "string must have at most 8 characters, but has 9"
+    );
+}
@@ -0,0 +1 @@
+//! Tests for the `sqlx` integration of `strid`, which live in `tests/`
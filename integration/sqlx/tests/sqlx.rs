@@ -0,0 +1,79 @@
+use sqlx::{Connection, Row, SqliteConnection};
+use strid::braid;
+
+#[path = "../../../crates/strid/tests/common/validators.rs"]
+mod validators;
+
+#[braid(sqlx)]
+pub struct Plain;
+
+#[braid(sqlx, validator = "validators::NonEmpty")]
+pub struct Checked;
+
+#[braid(sqlx, normalizer = "validators::Lowercase")]
+pub struct Lower;
+
+async fn connection(values: &[&str]) -> SqliteConnection {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+    sqlx::query("CREATE TABLE t (value TEXT NOT NULL)")
+        .execute(&mut conn)
+        .await
+        .unwrap();
+    for value in values {
+        sqlx::query("INSERT INTO t VALUES (?)")
+            .bind(*value)
+            .execute(&mut conn)
+            .await
+            .unwrap();
+    }
+    conn
+}
+
+#[tokio::test]
+async fn round_trip() {
+    let mut conn = connection(&[]).await;
+    sqlx::query("INSERT INTO t VALUES (?), (?)")
+        .bind(Plain::from_static("value"))
+        .bind(PlainRef::from_str("other"))
+        .execute(&mut conn)
+        .await
+        .unwrap();
+
+    let values: Vec<Plain> = sqlx::query_scalar("SELECT value FROM t")
+        .fetch_all(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(
+        values,
+        [Plain::from_static("value"), Plain::from_static("other")]
+    );
+}
+
+#[tokio::test]
+async fn decoding_validates() {
+    let mut conn = connection(&["", "value"]).await;
+    let rows = sqlx::query("SELECT value FROM t")
+        .fetch_all(&mut conn)
+        .await
+        .unwrap();
+
+    assert!(rows[0].try_get::<Checked, _>(0).is_err());
+    assert!(rows[0].try_get::<&CheckedRef, _>(0).is_err());
+    assert_eq!(rows[1].try_get::<Checked, _>(0).unwrap().as_str(), "value");
+    assert_eq!(
+        rows[1].try_get::<&CheckedRef, _>(0).unwrap().as_str(),
+        "value"
+    );
+}
+
+#[tokio::test]
+async fn decoding_normalizes_owned_values() {
+    let mut conn = connection(&["Value"]).await;
+    let rows = sqlx::query("SELECT value FROM t")
+        .fetch_all(&mut conn)
+        .await
+        .unwrap();
+
+    assert_eq!(rows[0].try_get::<Lower, _>(0).unwrap().as_str(), "value");
+    assert!(rows[0].try_get::<&LowerRef, _>(0).is_err());
+}